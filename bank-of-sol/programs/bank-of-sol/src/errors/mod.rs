@@ -63,6 +63,240 @@ pub enum ErrorCode {
 
     #[msg("Invalid account status")]
     InvalidAccountStatus,
+
+    #[msg("Invalid stake record")]
+    InvalidStakeRecord,
+
+    #[msg("Duplicate stake record")]
+    DuplicateStakeRecord,
+
+    #[msg("Invalid interest rate curve")]
+    InvalidRateCurve,
+
+    #[msg("Invalid monetary policy parameters")]
+    InvalidPolicyParams,
+
+    #[msg("Not enough bonds available this epoch")]
+    InsufficientAvailableBonds,
+
+    #[msg("Purchase would exceed the debt ceiling")]
+    DebtCeilingExceeded,
+
+    #[msg("Invalid bond terms")]
+    InvalidBondTerms,
+
+    #[msg("Invalid redemption premium")]
+    InvalidRedemptionPremium,
+
+    #[msg("Invalid bond auction")]
+    InvalidBondAuction,
+
+    #[msg("Bond token mode enabled")]
+    BondTokenModeEnabled,
+
+    #[msg("Bond token mode disabled")]
+    BondTokenModeDisabled,
+
+    #[msg("Bond mint already created")]
+    BondMintAlreadyCreated,
+
+    #[msg("Bond mint not created")]
+    BondMintNotCreated,
+
+    #[msg("Bond account is tokenized")]
+    BondAccountTokenized,
+
+    #[msg("Invalid split amount")]
+    InvalidSplitAmount,
+
+    #[msg("Bond accounts have different terms")]
+    BondAccountsNotMergeable,
+
+    #[msg("Merged bond account must be the oldest or newest")]
+    InvalidMergeSource,
+
+    #[msg("Bonds not yet mature")]
+    BondsNotMature,
+
+    #[msg("Bonds expired")]
+    BondsExpired,
+
+    #[msg("Bonds not expired")]
+    BondsNotExpired,
+
+    #[msg("Not enough redeemable bonds")]
+    InsufficientRedeemableBonds,
+
+    #[msg("Insufficient bond balance")]
+    InsufficientBondBalance,
+
+    #[msg("Swap returned fewer Units than the minimum")]
+    SlippageExceeded,
+
+    #[msg("Invalid POL seigniorage share")]
+    InvalidPolShare,
+
+    #[msg("Not enough Units available for POL")]
+    InsufficientPolUnits,
+
+    #[msg("Not enough protocol-owned liquidity")]
+    InsufficientPolLiquidity,
+
+    #[msg("Treasury split must add up to 100%")]
+    InvalidTreasurySplit,
+
+    #[msg("Treasury spend exceeds the epoch limit")]
+    TreasurySpendLimitExceeded,
+
+    #[msg("Invalid vesting terms")]
+    InvalidVestingTerms,
+
+    #[msg("Vesting schedule already revoked")]
+    VestingRevoked,
+
+    #[msg("Invalid share emission")]
+    InvalidShareEmission,
+
+    #[msg("Boardroom deposit token can only change while the boardroom is empty")]
+    BoardroomNotEmpty,
+
+    #[msg("Rewards must be paid in Units or shares")]
+    InvalidRewardMint,
+
+    #[msg("Insufficient farm stake")]
+    InsufficientFarmStake,
+
+    #[msg("Position does not belong to the pool")]
+    InvalidPosition,
+
+    #[msg("Position liquidity too large")]
+    PositionLiquidityTooLarge,
+
+    #[msg("Locks must be between 1 and 208 weeks")]
+    InvalidLockDuration,
+
+    #[msg("Lock expired")]
+    LockExpired,
+
+    #[msg("Lock not expired")]
+    LockNotExpired,
+
+    #[msg("Lock can only be extended")]
+    LockNotExtended,
+
+    #[msg("Lock changed too often since the checkpoint")]
+    LockHistoryExhausted,
+
+    #[msg("Vote weight exceeds the voter's remaining weight")]
+    InvalidVoteWeight,
+
+    #[msg("Gauge target doesn't match the reward pool")]
+    InvalidGaugeTarget,
+
+    #[msg("Share farm rates are set by streamed emissions")]
+    ShareFarmRate,
+
+    #[msg("Invalid governance params")]
+    InvalidGovernanceParams,
+
+    #[msg("Deposited into the boardroom after the proposal's snapshot")]
+    DepositedAfterSnapshot,
+
+    #[msg("No voting power")]
+    NoVotingPower,
+
+    #[msg("Voting has ended")]
+    VotingEnded,
+
+    #[msg("Voting has not ended")]
+    VotingNotEnded,
+
+    #[msg("Proposal has already been executed")]
+    ProposalExecuted,
+
+    #[msg("Proposal did not pass")]
+    ProposalNotPassed,
+
+    #[msg("Invalid timelock delay")]
+    InvalidTimelockDelay,
+
+    #[msg("Action can't be executed before its ETA")]
+    ActionNotReady,
+
+    #[msg("Signer doesn't hold the required role")]
+    MissingRole,
+
+    #[msg("Invalid authority")]
+    InvalidAuthority,
+
+    #[msg("Signer is not the pending authority")]
+    NotPendingAuthority,
+
+    #[msg("No pending authority")]
+    NoPendingAuthority,
+
+    #[msg("Paused")]
+    SubsystemPaused,
+
+    #[msg("Emergency shutdown")]
+    EmergencyShutdown,
+
+    #[msg("No price observations in the TWAP window")]
+    EmptyTwapWindow,
+
+    #[msg("Invalid debt ceiling")]
+    InvalidDebtCeiling,
+
+    #[msg("Bonds haven't been reached in the redemption queue")]
+    BondsNotFunded,
+
+    #[msg("Bonds can only be added to the latest purchase")]
+    BondQueuePositionTaken,
+
+    #[msg("Bond tokens can't be sold while bond terms are active")]
+    BondTermsActive,
+
+    #[msg("Bonds of the current mode are outstanding")]
+    BondsOutstanding,
+
+    #[msg("Merged bond accounts must be adjacent in the queue")]
+    BondAccountsNotAdjacent,
+
+    #[msg("Protocol-owned liquidity position already opened")]
+    PolPositionOpened,
+
+    #[msg("Only Units and shares can be vested")]
+    InvalidVestingMint,
+
+    #[msg("Treasury fund vault not provided")]
+    MissingFundVault,
+
+    #[msg("Share max supply exceeded")]
+    ShareMaxSupplyExceeded,
+
+    #[msg("Shares are budgeted for gauges")]
+    SharesBudgeted,
+
+    #[msg("Farms only stake fungible tokens")]
+    InvalidStakeMint,
+
+    #[msg("Pending rewards can't be paid out")]
+    RewardsNotCovered,
+
+    #[msg("Action can't be executed after its grace period")]
+    ActionExpired,
+
+    #[msg("Action can't be cancelled while it can be executed")]
+    ActionExecutable,
+
+    #[msg("Account required by the action not provided")]
+    MissingActionAccount,
+
+    #[msg("Price observed too soon after the latest observation")]
+    ObservationTooSoon,
+
+    #[msg("Action was queued before the authority was renounced")]
+    ActionInvalidated,
 }
 
 pub type ProgramResult<T = ()> = std::result::Result<T, ErrorCode>;
\ No newline at end of file
@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+
+/// Emitted when the authority proposes a new authority
+#[event]
+pub struct AuthorityProposed {
+    pub authority: Pubkey,
+    pub pending_authority: Pubkey,
+}
+
+/// Emitted when the authority cancels a proposed transfer
+#[event]
+pub struct AuthorityTransferCancelled {
+    pub authority: Pubkey,
+    pub pending_authority: Pubkey,
+}
+
+/// Emitted when the pending authority accepts the transfer
+#[event]
+pub struct AuthorityTransferred {
+    pub previous_authority: Pubkey,
+    pub new_authority: Pubkey,
+}
+
+/// Emitted when the authority is renounced, after which privileged instructions are disabled for good
+#[event]
+pub struct AuthorityRenounced {
+    pub previous_authority: Pubkey,
+}
+
+/// Emitted when a subsystem is paused or unpaused, or the emergency shutdown is toggled
+#[event]
+pub struct PauseStateChanged {
+    pub paused: u16,
+    pub shutdown: bool,
+}
\ No newline at end of file
@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+
+use crate::state::singleton::Singleton;
+use crate::events::AuthorityTransferred;
+use crate::errors::ErrorCode;
+
+#[derive(Accounts)]
+pub struct AcceptAuthority<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"singleton"
+        ],
+        bump = singleton.bump,
+        constraint = singleton.pending_authority == pending_authority.key() @ ErrorCode::NotPendingAuthority
+    )]
+    pub singleton: Account<'info, Singleton>,
+
+    pub pending_authority: Signer<'info>,
+}
+
+pub fn accept_authority_handler(ctx: Context<AcceptAuthority>) -> Result<()> {
+    let singleton = &mut ctx.accounts.singleton;
+    let previous_authority = singleton.authority;
+
+    singleton.authority = singleton.pending_authority;
+    singleton.pending_authority = Pubkey::default();
+
+    emit!(AuthorityTransferred {
+        previous_authority,
+        new_authority: singleton.authority,
+    });
+
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,129 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token::{Mint, Token, TokenAccount, MintTo, mint_to},
+    associated_token::AssociatedToken,
+};
+use raydium_amm_v3::states::PoolState;
+
+use crate::state::{singleton::Singleton, treasury::Treasury};
+use crate::types::oracle::update_observations;
+use crate::types::pause::Subsystem;
+use crate::utils::account_deserialize;
+use crate::errors::ErrorCode;
+
+#[derive(Accounts)]
+pub struct AdvanceEpoch<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"singleton"
+        ],
+        bump = singleton.bump,
+        has_one = unit_mint,
+        constraint = singleton.pool == raydium_pool.key() @ ErrorCode::InvalidRaydiumPool
+    )]
+    pub singleton: Account<'info, Singleton>,
+
+    #[account(
+        seeds = [
+            b"treasury"
+        ],
+        bump = treasury.bump,
+        constraint = treasury.dao_fund.vault == dao_fund_vault.key() @ ErrorCode::UnexpectedAccount,
+        constraint = treasury.dev_fund.vault == dev_fund_vault.key() @ ErrorCode::UnexpectedAccount
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    /// CHECK: Checked against `singleton.pool` and deserialized by the oracle
+    pub raydium_pool: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        mint::authority = token_authority
+    )]
+    pub unit_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = unit_mint,
+        associated_token::authority = token_authority
+    )]
+    pub unit_custody_account: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub dao_fund_vault: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub dev_fund_vault: Account<'info, TokenAccount>,
+
+    /// CHECK: This account is not read or written
+    #[account(
+        seeds = [
+            b"token-authority"
+        ],
+        bump = singleton.unit_auth_bump
+    )]
+    pub token_authority: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+impl<'info> AdvanceEpoch<'info> {
+    pub fn mint_token_ctx(&self, to: &Account<'info, TokenAccount>) -> CpiContext<'_, '_, '_, 'info, MintTo<'info>> {
+        let cpi_accounts = MintTo {
+            mint: self.unit_mint.to_account_info(),
+            to: to.to_account_info(),
+            authority: self.token_authority.to_account_info(),
+        };
+
+        let cpi_program = self.token_program.to_account_info();
+
+        CpiContext::new(cpi_program, cpi_accounts)
+    }
+}
+
+pub fn advance_epoch_handler(ctx: Context<AdvanceEpoch>) -> Result<()> {
+    ctx.accounts.singleton.pause.require_active(Subsystem::EpochAdvance)?;
+
+    let clock = Clock::get()?;
+    let raydium_pool = ctx.accounts.raydium_pool.to_account_info();
+    let circulating_supply = ctx.accounts.unit_mint.supply - ctx.accounts.unit_custody_account.amount;
+    let singleton = &mut ctx.accounts.singleton;
+    let unit_auth_bump = singleton.unit_auth_bump;
+
+    let pool_state: PoolState = account_deserialize(&raydium_pool)?;
+    let a_to_b = pool_state.token_mint_0 == singleton.unit_mint;
+
+    update_observations(&clock, a_to_b, &raydium_pool, &mut singleton.price_observations)?;
+
+    let decision = singleton.advance(circulating_supply)?;
+
+    // Seigniorage repays bond holders first, POL takes its share of the remainder and the rest is split
+    // between the treasury funds and the boardroom
+    let funded_bonds = singleton.bonds.fund_redemptions(decision.seigniorage);
+    let remainder = decision.seigniorage - funded_bonds;
+    let pol_units = singleton.pol.allocate_seigniorage(remainder);
+    let (dao_fund, dev_fund, boardroom_reward) = ctx.accounts.treasury.split.split(remainder - pol_units);
+
+    // Funded bonds are minted into custody as the reserve their redemptions are paid from
+    let mut custodied = funded_bonds + pol_units;
+
+    if boardroom_reward > 0 && singleton.distribute_boardroom_reward(boardroom_reward) {
+        custodied += boardroom_reward;
+    }
+
+    let unit_auth_seed = &[&b"token-authority"[..], &[unit_auth_bump]];
+
+    for (to, amount) in [
+        (&ctx.accounts.unit_custody_account, custodied),
+        (&ctx.accounts.dao_fund_vault, dao_fund),
+        (&ctx.accounts.dev_fund_vault, dev_fund),
+    ] {
+        if amount > 0 {
+            mint_to(ctx.accounts.mint_token_ctx(to).with_signer(&[&unit_auth_seed[..]]), amount)?;
+        }
+    }
+
+    Ok(())
+}
\ No newline at end of file
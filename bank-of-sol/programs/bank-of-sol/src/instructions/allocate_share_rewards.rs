@@ -0,0 +1,99 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount, Transfer, transfer};
+
+use crate::state::{singleton::Singleton, farm::Farm, gauge::GaugeController, roles::RoleMember};
+use crate::types::roles::Role;
+use crate::errors::ErrorCode;
+
+#[derive(Accounts)]
+pub struct AllocateShareRewards<'info> {
+    #[account(
+        seeds = [
+            b"singleton"
+        ],
+        bump = singleton.bump,
+        constraint = singleton.share.reward_vault == share_reward_vault.key() @ ErrorCode::UnexpectedAccount,
+        constraint = singleton.share.mint == farm.reward_mint @ ErrorCode::InvalidRewardMint
+    )]
+    pub singleton: Account<'info, Singleton>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"farm",
+            farm.stake_mint.as_ref()
+        ],
+        bump = farm.bump,
+        has_one = reward_vault
+    )]
+    pub farm: Account<'info, Farm>,
+
+    #[account(
+        seeds = [
+            b"gauge-controller"
+        ],
+        bump = gauge_controller.bump
+    )]
+    pub gauge_controller: Account<'info, GaugeController>,
+
+    #[account(mut)]
+    pub share_reward_vault: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub reward_vault: Account<'info, TokenAccount>,
+
+    /// CHECK: This account is not read or written
+    #[account(
+        seeds = [
+            b"token-authority"
+        ],
+        bump = singleton.unit_auth_bump
+    )]
+    pub token_authority: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [
+            b"role-member",
+            authority.key().as_ref()
+        ],
+        bump = role_member.bump,
+        constraint = singleton.has_role(&role_member, Role::Admin) @ ErrorCode::MissingRole
+    )]
+    pub role_member: Account<'info, RoleMember>,
+
+    pub authority: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> AllocateShareRewards<'info> {
+    pub fn transfer_ctx(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        let cpi_accounts = Transfer {
+            from: self.share_reward_vault.to_account_info(),
+            to: self.reward_vault.to_account_info(),
+            authority: self.token_authority.to_account_info(),
+        };
+
+        let cpi_program = self.token_program.to_account_info();
+
+        CpiContext::new(cpi_program, cpi_accounts)
+    }
+}
+
+/// Moves `amount` of the shares in the reward vault that aren't budgeted for gauges into a share-paying farm,
+/// which streams them to its stakers
+pub fn allocate_share_rewards_handler(ctx: Context<AllocateShareRewards>, amount: u64) -> Result<()> {
+    ctx.accounts.singleton.pause.require_not_shutdown()?;
+
+    let unbudgeted = ctx.accounts.share_reward_vault.amount.saturating_sub(ctx.accounts.gauge_controller.budget.reserved());
+
+    require_gte!(unbudgeted, amount, ErrorCode::SharesBudgeted);
+
+    let unit_auth_seed = &[&b"token-authority"[..], &[ctx.accounts.singleton.unit_auth_bump]];
+
+    transfer(ctx.accounts.transfer_ctx().with_signer(&[&unit_auth_seed[..]]), amount)?;
+
+    ctx.accounts.farm.rewards.notify(amount, Clock::get()?.unix_timestamp);
+
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,103 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token::{Token, TokenAccount, Mint, Transfer, transfer},
+    associated_token::AssociatedToken,
+};
+
+use crate::state::{singleton::Singleton, boardroom::BoardroomAccount};
+use crate::types::pause::Subsystem;
+use crate::errors::ErrorCode;
+
+#[derive(Accounts)]
+pub struct BoardroomClaimShares<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"singleton"
+        ],
+        bump = singleton.bump,
+        constraint = singleton.share.mint == share_mint.key() @ ErrorCode::UnexpectedAccount
+    )]
+    pub singleton: Box<Account<'info, Singleton>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"boardroom-account",
+            signer.key().as_ref()
+        ],
+        bump = boardroom_account.bump
+    )]
+    pub boardroom_account: Account<'info, BoardroomAccount>,
+
+    pub share_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"boardroom-share-rewards"
+        ],
+        bump
+    )]
+    pub boardroom_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = signer,
+        associated_token::mint = share_mint,
+        associated_token::authority = signer
+    )]
+    pub share_receiver_account: Account<'info, TokenAccount>,
+
+    /// CHECK: This account is not read or written
+    #[account(
+        seeds = [
+            b"token-authority"
+        ],
+        bump = singleton.unit_auth_bump
+    )]
+    pub token_authority: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> BoardroomClaimShares<'info> {
+    pub fn transfer_tokens_ctx(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        let cpi_accounts = Transfer {
+            from: self.boardroom_vault.to_account_info(),
+            to: self.share_receiver_account.to_account_info(),
+            authority: self.token_authority.to_account_info(),
+        };
+
+        let cpi_program = self.token_program.to_account_info();
+
+        CpiContext::new(cpi_program, cpi_accounts)
+    }
+}
+
+/// Claims the share rewards distributed to the boardroom by its gauge
+pub fn boardroom_claim_shares_handler(ctx: Context<BoardroomClaimShares>) -> Result<()> {
+    ctx.accounts.singleton.pause.require_active(Subsystem::Claims)?;
+
+    let singleton = &mut ctx.accounts.singleton;
+    let boardroom_account = &mut ctx.accounts.boardroom_account;
+    let unit_auth_bump = singleton.unit_auth_bump;
+
+    singleton.sync_boardroom_account(boardroom_account);
+
+    let reward = boardroom_account.pending_share_rewards;
+    boardroom_account.pending_share_rewards = 0;
+
+    if reward > 0 {
+        let unit_auth_seed = &[&b"token-authority"[..], &[unit_auth_bump]];
+
+        transfer(ctx.accounts.transfer_tokens_ctx().with_signer(&[&unit_auth_seed[..]]), reward)?;
+    }
+
+    Ok(())
+}
\ No newline at end of file
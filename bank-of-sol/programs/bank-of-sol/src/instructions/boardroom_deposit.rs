@@ -5,6 +5,7 @@ use anchor_spl::{
 };
 
 use crate::state::{singleton::Singleton, boardroom::BoardroomAccount};
+use crate::types::pause::Subsystem;
 use crate::errors::ErrorCode;
 
 #[derive(Accounts)]
@@ -14,29 +15,26 @@ pub struct BoardroomDeposit<'info> {
         seeds = [
             b"singleton"
         ],
-        bump = singleton.bump
+        bump = singleton.bump,
+        constraint = singleton.boardroom_deposit_mint() == deposit_mint.key() @ ErrorCode::UnexpectedAccount
     )]
     pub singleton: Account<'info, Singleton>,
 
-    #[account(
-        mut,
-        mint::authority = token_authority
-    )]
-    pub unit_mint: Account<'info, Mint>,
+    pub deposit_mint: Account<'info, Mint>,
 
     #[account(
         mut,
-        associated_token::mint = unit_mint,
+        associated_token::mint = deposit_mint,
         associated_token::authority = signer
     )]
-    pub unit_payer_account: Account<'info, TokenAccount>,
+    pub payer_account: Account<'info, TokenAccount>,
 
     #[account(
         mut,
-        associated_token::mint = unit_mint,
+        associated_token::mint = deposit_mint,
         associated_token::authority = token_authority
     )]
-    pub unit_custody_account: Account<'info, TokenAccount>,
+    pub custody_account: Account<'info, TokenAccount>,
 
     /// CHECK: This account is not read or written
     #[account(
@@ -66,9 +64,9 @@ pub struct BoardroomDeposit<'info> {
 impl<'info> BoardroomDeposit<'info> {
     pub fn transfer_tokens_ctx(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
         let cpi_accounts = Transfer {
-            from: self.unit_payer_account.to_account_info(),
-            to: self.unit_custody_account.to_account_info(),
-            authority: self.token_authority.to_account_info(),
+            from: self.payer_account.to_account_info(),
+            to: self.custody_account.to_account_info(),
+            authority: self.signer.to_account_info(),
         };
 
         let cpi_program = self.token_program.to_account_info();
@@ -78,6 +76,8 @@ impl<'info> BoardroomDeposit<'info> {
 }
 
 pub fn boardroom_deposit_handler(ctx: Context<BoardroomDeposit>, amount: u64) -> Result<()> {
+    ctx.accounts.singleton.pause.require_active(Subsystem::BoardroomDeposit)?;
+
     let singleton = &mut ctx.accounts.singleton;
     let boardroom_account = &mut ctx.accounts.boardroom_account;
 
@@ -86,7 +86,10 @@ pub fn boardroom_deposit_handler(ctx: Context<BoardroomDeposit>, amount: u64) ->
     }
 
     boardroom_account.increment_balance_of_staged(amount);
+    boardroom_account.last_deposited_timestamp = Clock::get()?.unix_timestamp;
+    boardroom_account.epoch_last_deposited = singleton.epoch();
     singleton.increment_total_balance_of_staged(amount);
+    singleton.sync_boardroom_account(boardroom_account);
     
     transfer(ctx.accounts.transfer_tokens_ctx(), amount)?;
 
@@ -4,6 +4,7 @@ use anchor_spl::{
     associated_token::AssociatedToken,
 };
 use crate::state::{singleton::Singleton, boardroom::BoardroomAccount};
+use crate::types::pause::Subsystem;
 use crate::errors::ErrorCode;
 
 #[derive(Accounts)]
@@ -13,7 +14,8 @@ pub struct BoardroomWithdraw<'info> {
         seeds = [
             b"singleton"
         ],
-        bump = singleton.bump
+        bump = singleton.bump,
+        constraint = singleton.boardroom_deposit_mint() == deposit_mint.key() @ ErrorCode::UnexpectedAccount
     )]
     pub singleton: Account<'info, Singleton>,
 
@@ -27,25 +29,21 @@ pub struct BoardroomWithdraw<'info> {
     )]
     pub boardroom_account: Account<'info, BoardroomAccount>,
 
-    #[account(
-        mut,
-        mint::authority = token_authority
-    )]
-    pub unit_mint: Account<'info, Mint>,
+    pub deposit_mint: Account<'info, Mint>,
 
     #[account(
         mut,
-        associated_token::mint = unit_mint,
+        associated_token::mint = deposit_mint,
         associated_token::authority = token_authority
     )]
-    pub unit_custody_account: Account<'info, TokenAccount>,
+    pub custody_account: Account<'info, TokenAccount>,
 
     #[account(
         mut,
-        associated_token::mint = unit_mint,
+        associated_token::mint = deposit_mint,
         associated_token::authority = signer
     )]
-    pub unit_receiver_account: Account<'info, TokenAccount>,
+    pub receiver_account: Account<'info, TokenAccount>,
 
     /// CHECK: This account is not read or written
     #[account(
@@ -65,8 +63,8 @@ pub struct BoardroomWithdraw<'info> {
 impl<'info> BoardroomWithdraw<'info> {
     pub fn transfer_tokens_ctx(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
         let cpi_accounts = Transfer {
-            from: self.unit_custody_account.to_account_info(),
-            to: self.unit_receiver_account.to_account_info(),
+            from: self.custody_account.to_account_info(),
+            to: self.receiver_account.to_account_info(),
             authority: self.token_authority.to_account_info(),
         };
 
@@ -77,6 +75,8 @@ impl<'info> BoardroomWithdraw<'info> {
 }
 
 pub fn boardroom_withdraw_handler(ctx: Context<BoardroomWithdraw>, amount: u64) -> Result<()> {
+    ctx.accounts.singleton.pause.require_active(Subsystem::BoardroomWithdraw)?;
+
     let singleton = &mut ctx.accounts.singleton;
     let boardroom_account = &mut ctx.accounts.boardroom_account;
     let unit_auth_bump = singleton.unit_auth_bump;
@@ -87,6 +87,7 @@ pub fn boardroom_withdraw_handler(ctx: Context<BoardroomWithdraw>, amount: u64)
 
     boardroom_account.decrement_balance_of_staged(amount);
     singleton.decrement_total_balance_of_staged(amount);
+    singleton.sync_boardroom_account(boardroom_account);
 
     let unit_auth_seed = &[&b"token-authority"[..], &[unit_auth_bump]];
 
@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{singleton::Singleton, timelock::{Timelock, PendingAction}, roles::RoleMember};
+use crate::types::roles::Role;
+use crate::errors::ErrorCode;
+
+#[derive(Accounts)]
+pub struct CancelAction<'info> {
+    #[account(
+        seeds = [
+            b"singleton"
+        ],
+        bump = singleton.bump
+    )]
+    pub singleton: Box<Account<'info, Singleton>>,
+
+    #[account(
+        seeds = [
+            b"timelock"
+        ],
+        bump = timelock.bump
+    )]
+    pub timelock: Account<'info, Timelock>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"pending-action",
+            pending_action.index.to_be_bytes().as_ref()
+        ],
+        bump = pending_action.bump,
+        has_one = queued_by,
+        close = queued_by
+    )]
+    pub pending_action: Box<Account<'info, PendingAction>>,
+
+    #[account(mut)]
+    pub queued_by: SystemAccount<'info>,
+
+    /// Required unless the action is closable
+    #[account(
+        seeds = [
+            b"role-member",
+            authority.key().as_ref()
+        ],
+        bump = role_member.bump
+    )]
+    pub role_member: Option<Account<'info, RoleMember>>,
+
+    pub authority: Signer<'info>,
+}
+
+/// Cancels a queued action before its ETA, see `PendingAction::cancellable`. Actions that expired or
+/// were invalidated can be closed by anyone
+pub fn cancel_action_handler(ctx: Context<CancelAction>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let pending_action = &ctx.accounts.pending_action;
+
+    if pending_action.closable(&ctx.accounts.timelock, now) {
+        return Ok(());
+    }
+
+    let role_member = ctx.accounts.role_member.as_ref().ok_or(ErrorCode::MissingRole)?;
+    require!(ctx.accounts.singleton.has_role(role_member, Role::Admin), ErrorCode::MissingRole);
+
+    pending_action.cancellable(&ctx.accounts.timelock, now)
+}
\ No newline at end of file
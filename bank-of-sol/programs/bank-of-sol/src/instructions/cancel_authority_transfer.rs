@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+use crate::state::singleton::Singleton;
+use crate::events::AuthorityTransferCancelled;
+use crate::errors::ErrorCode;
+
+#[derive(Accounts)]
+pub struct CancelAuthorityTransfer<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"singleton"
+        ],
+        bump = singleton.bump,
+        has_one = authority
+    )]
+    pub singleton: Account<'info, Singleton>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn cancel_authority_transfer_handler(ctx: Context<CancelAuthorityTransfer>) -> Result<()> {
+    let singleton = &mut ctx.accounts.singleton;
+    let pending_authority = singleton.pending_authority;
+
+    require_keys_neq!(pending_authority, Pubkey::default(), ErrorCode::NoPendingAuthority);
+
+    singleton.pending_authority = Pubkey::default();
+
+    emit!(AuthorityTransferCancelled {
+        authority: singleton.authority,
+        pending_authority,
+    });
+
+    Ok(())
+}
\ No newline at end of file
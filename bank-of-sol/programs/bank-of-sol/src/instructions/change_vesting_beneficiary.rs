@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+
+use crate::state::vesting_schedule::VestingSchedule;
+
+#[derive(Accounts)]
+pub struct ChangeVestingBeneficiary<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"vesting-schedule",
+            vesting_schedule.mint.as_ref(),
+            vesting_schedule.seed.as_ref()
+        ],
+        bump = vesting_schedule.bump,
+        has_one = beneficiary
+    )]
+    pub vesting_schedule: Account<'info, VestingSchedule>,
+
+    /// CHECK: Only used as the new beneficiary of the schedule
+    pub new_beneficiary: UncheckedAccount<'info>,
+
+    pub beneficiary: Signer<'info>,
+}
+
+/// Hands the schedule, including anything vested but unclaimed, over to a new beneficiary
+pub fn change_vesting_beneficiary_handler(ctx: Context<ChangeVestingBeneficiary>) -> Result<()> {
+    ctx.accounts.vesting_schedule.beneficiary = ctx.accounts.new_beneficiary.key();
+
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{singleton::Singleton, ve::{VeSupply, VeCheckpoint}};
+
+#[derive(Accounts)]
+pub struct CheckpointVeSupply<'info> {
+    #[account(
+        seeds = [
+            b"singleton"
+        ],
+        bump = singleton.bump
+    )]
+    pub singleton: Box<Account<'info, Singleton>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"ve-supply"
+        ],
+        bump = ve_supply.bump
+    )]
+    pub ve_supply: Box<Account<'info, VeSupply>>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = VeCheckpoint::LEN,
+        seeds = [
+            b"ve-checkpoint",
+            singleton.epoch().to_be_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub ve_checkpoint: Account<'info, VeCheckpoint>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Records the total voting power for the current epoch, callable by anyone once per epoch before the first vote
+pub fn checkpoint_ve_supply_handler(ctx: Context<CheckpointVeSupply>) -> Result<()> {
+    *ctx.accounts.ve_checkpoint = VeCheckpoint::record(
+        &mut ctx.accounts.ve_supply,
+        ctx.accounts.singleton.epoch(),
+        Clock::get()?.unix_timestamp,
+        ctx.bumps.ve_checkpoint
+    );
+
+    Ok(())
+}
\ No newline at end of file
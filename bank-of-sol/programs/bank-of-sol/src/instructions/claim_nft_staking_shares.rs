@@ -0,0 +1,100 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token::{Token, TokenAccount, Mint, Transfer, transfer},
+    associated_token::AssociatedToken,
+};
+
+use crate::state::{singleton::Singleton, nft_stake_record::StakeRecordIndex};
+use crate::types::pause::Subsystem;
+use crate::errors::ErrorCode;
+
+#[derive(Accounts)]
+pub struct ClaimNftStakingShares<'info> {
+    #[account(
+        seeds = [b"singleton"],
+        bump = singleton.bump,
+        constraint = singleton.share.mint == share_mint.key() @ ErrorCode::UnexpectedAccount
+    )]
+    pub singleton: Box<Account<'info, Singleton>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"stake-record-index",
+            signer.key().as_ref()
+        ],
+        bump = stake_record_index.bump
+    )]
+    pub stake_record_index: Account<'info, StakeRecordIndex>,
+
+    pub share_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"nft-staking-share-rewards"
+        ],
+        bump
+    )]
+    pub nft_staking_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = signer,
+        associated_token::mint = share_mint,
+        associated_token::authority = signer
+    )]
+    pub share_receiver_account: Account<'info, TokenAccount>,
+
+    /// CHECK: This account is not read or written
+    #[account(
+        seeds = [
+            b"token-authority"
+        ],
+        bump = singleton.unit_auth_bump
+    )]
+    pub token_authority: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ClaimNftStakingShares<'info> {
+    pub fn transfer_tokens_ctx(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        let cpi_accounts = Transfer {
+            from: self.nft_staking_vault.to_account_info(),
+            to: self.share_receiver_account.to_account_info(),
+            authority: self.token_authority.to_account_info(),
+        };
+
+        let cpi_program = self.token_program.to_account_info();
+
+        CpiContext::new(cpi_program, cpi_accounts)
+    }
+}
+
+/// Claims the share rewards distributed to NFT staking by its gauge, including those of unstaked NFTs
+pub fn claim_nft_staking_shares_handler(ctx: Context<ClaimNftStakingShares>) -> Result<()> {
+    ctx.accounts.singleton.pause.require_active(Subsystem::Claims)?;
+
+    let unit_auth_bump = ctx.accounts.singleton.unit_auth_bump;
+    let share_reward_per_nft = ctx.accounts.singleton.nft_staking.share_reward_per_nft;
+    let stake_record_index = &mut ctx.accounts.stake_record_index;
+
+    stake_record_index.settle_share_rewards(share_reward_per_nft);
+
+    let reward = stake_record_index.pending_share_rewards;
+    stake_record_index.pending_share_rewards = 0;
+
+    if reward > 0 {
+        let unit_auth_seed = &[&b"token-authority"[..], &[unit_auth_bump]];
+
+        transfer(ctx.accounts.transfer_tokens_ctx().with_signer(&[&unit_auth_seed[..]]), reward)?;
+    }
+
+    Ok(())
+}
\ No newline at end of file
@@ -5,6 +5,7 @@ use anchor_spl::{
 };
 
 use crate::state::{singleton::Singleton, nft_stake_record::{StakeRecord, StakeRecordIndex}};
+use crate::types::pause::Subsystem;
 use crate::errors::ErrorCode;
 
 #[derive(Accounts)]
@@ -85,6 +86,8 @@ impl<'info> ClaimStakingReward<'info> {
 }
 
 pub fn claim_reward_handler(ctx: Context<ClaimStakingReward>, _seed_index: u64) -> Result<()> {
+    ctx.accounts.singleton.pause.require_active(Subsystem::Claims)?;
+
     let singleton = &ctx.accounts.singleton;
 
     let staked_at = ctx.accounts.stake_record.staked_at;
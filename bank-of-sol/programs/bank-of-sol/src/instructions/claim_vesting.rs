@@ -0,0 +1,85 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount, Transfer, transfer};
+
+use crate::state::{singleton::Singleton, vesting_schedule::VestingSchedule};
+use crate::types::pause::Subsystem;
+
+#[derive(Accounts)]
+pub struct ClaimVesting<'info> {
+    #[account(
+        seeds = [
+            b"singleton"
+        ],
+        bump = singleton.bump
+    )]
+    pub singleton: Account<'info, Singleton>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"vesting-schedule",
+            vesting_schedule.mint.as_ref(),
+            vesting_schedule.seed.as_ref()
+        ],
+        bump = vesting_schedule.bump,
+        has_one = beneficiary,
+        has_one = vault
+    )]
+    pub vesting_schedule: Account<'info, VestingSchedule>,
+
+    #[account(mut)]
+    pub vault: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        token::mint = vesting_schedule.mint,
+        token::authority = beneficiary
+    )]
+    pub beneficiary_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: This account is not read or written
+    #[account(
+        seeds = [
+            b"token-authority"
+        ],
+        bump = singleton.unit_auth_bump
+    )]
+    pub token_authority: UncheckedAccount<'info>,
+
+    pub beneficiary: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> ClaimVesting<'info> {
+    pub fn transfer_ctx(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        let cpi_accounts = Transfer {
+            from: self.vault.to_account_info(),
+            to: self.beneficiary_token_account.to_account_info(),
+            authority: self.token_authority.to_account_info(),
+        };
+
+        let cpi_program = self.token_program.to_account_info();
+
+        CpiContext::new(cpi_program, cpi_accounts)
+    }
+}
+
+/// Pays out everything vested and not yet claimed to the beneficiary
+pub fn claim_vesting_handler(ctx: Context<ClaimVesting>) -> Result<()> {
+    ctx.accounts.singleton.pause.require_active(Subsystem::Claims)?;
+
+    let unit_auth_bump = ctx.accounts.singleton.unit_auth_bump;
+    let vesting_schedule = &mut ctx.accounts.vesting_schedule;
+
+    let amount = vesting_schedule.claimable(Clock::get()?.unix_timestamp);
+    vesting_schedule.claimed += amount;
+
+    if amount > 0 {
+        let unit_auth_seed = &[&b"token-authority"[..], &[unit_auth_bump]];
+
+        transfer(ctx.accounts.transfer_ctx().with_signer(&[&unit_auth_seed[..]]), amount)?;
+    }
+
+    Ok(())
+}
\ No newline at end of file
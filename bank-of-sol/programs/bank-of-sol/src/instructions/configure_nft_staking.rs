@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+
+use crate::state::{singleton::Singleton, roles::RoleMember};
+use crate::types::roles::Role;
+use crate::errors::ErrorCode;
+
+#[derive(Accounts)]
+pub struct ConfigureNftStaking<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"singleton"
+        ],
+        bump = singleton.bump
+    )]
+    pub singleton: Account<'info, Singleton>,
+
+    pub collection: Account<'info, Mint>,
+
+    #[account(
+        seeds = [
+            b"role-member",
+            authority.key().as_ref()
+        ],
+        bump = role_member.bump,
+        constraint = singleton.has_role(&role_member, Role::StakingManager) @ ErrorCode::MissingRole
+    )]
+    pub role_member: Account<'info, RoleMember>,
+
+    pub authority: Signer<'info>,
+}
+
+/// Sets the verified collection stakeable NFTs belong to and the minimum stake period in epochs
+pub fn configure_nft_staking_handler(ctx: Context<ConfigureNftStaking>, minimum_period: u64) -> Result<()> {
+    let nft_staking = &mut ctx.accounts.singleton.nft_staking;
+
+    nft_staking.collection = ctx.accounts.collection.key();
+    nft_staking.minimum_period = minimum_period;
+
+    Ok(())
+}
\ No newline at end of file
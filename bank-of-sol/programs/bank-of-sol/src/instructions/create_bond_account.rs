@@ -12,7 +12,7 @@ pub struct CreateBondAccount<'info> {
         seeds = [
             b"bond-account",
             signer.key().as_ref(),
-            (bond_accounts_store.number_of_accounts + 1).to_be_bytes().as_ref()
+            (bond_accounts_store.get_index_of_accounts() + 1).to_be_bytes().as_ref()
         ],
         bump
     )]
@@ -42,7 +42,7 @@ pub struct CreateBondAccount<'info> {
     pub system_program: Program<'info, System>,
 }
 
-pub fn create_bond_account_handler(ctx: Context<CreateBondAccount>, amount: u64) -> Result<()> {
+pub fn create_bond_account_handler(ctx: Context<CreateBondAccount>) -> Result<()> {
     let bond_account = &mut ctx.accounts.bond_account;
     let bond_accounts_store = &mut ctx.accounts.bond_accounts_store;
     let singleton = &ctx.accounts.singleton;
@@ -51,7 +51,7 @@ pub fn create_bond_account_handler(ctx: Context<CreateBondAccount>, amount: u64)
     **bond_account = BondAccount::init(
         ctx.accounts.signer.key(),
         bump,
-        amount,
+        0,
         singleton.epoch.index,
         singleton.interest_rate(),
         singleton.bonds.total_bonds_purchased,
@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token};
+
+use crate::state::{singleton::Singleton, roles::RoleMember};
+use crate::types::roles::Role;
+use crate::errors::ErrorCode;
+
+#[derive(Accounts)]
+pub struct CreateBondMint<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"singleton"
+        ],
+        bump = singleton.bump,
+        has_one = unit_mint,
+        constraint = singleton.bonds.bond_mint == Pubkey::default() @ ErrorCode::BondMintAlreadyCreated
+    )]
+    pub singleton: Account<'info, Singleton>,
+
+    pub unit_mint: Account<'info, Mint>,
+
+    #[account(
+        init,
+        payer = authority,
+        seeds = [
+            b"bond-mint"
+        ],
+        bump,
+        mint::decimals = unit_mint.decimals,
+        mint::authority = token_authority
+    )]
+    pub bond_mint: Account<'info, Mint>,
+
+    /// CHECK: This account is not read or written
+    #[account(
+        seeds = [
+            b"token-authority"
+        ],
+        bump = singleton.unit_auth_bump
+    )]
+    pub token_authority: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [
+            b"role-member",
+            authority.key().as_ref()
+        ],
+        bump = role_member.bump,
+        constraint = singleton.has_role(&role_member, Role::Admin) @ ErrorCode::MissingRole
+    )]
+    pub role_member: Account<'info, RoleMember>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn create_bond_mint_handler(ctx: Context<CreateBondMint>) -> Result<()> {
+    ctx.accounts.singleton.bonds.bond_mint = ctx.accounts.bond_mint.key();
+
+    Ok(())
+}
\ No newline at end of file
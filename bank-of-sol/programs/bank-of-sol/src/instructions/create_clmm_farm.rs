@@ -0,0 +1,90 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+use crate::state::{singleton::Singleton, farm::ClmmFarm, roles::RoleMember};
+use crate::types::farm::FarmRewards;
+use crate::types::roles::Role;
+use crate::errors::ErrorCode;
+
+#[derive(Accounts)]
+pub struct CreateClmmFarm<'info> {
+    #[account(
+        seeds = [
+            b"singleton"
+        ],
+        bump = singleton.bump,
+        constraint = reward_mint.key() == singleton.unit_mint || reward_mint.key() == singleton.share.mint @ ErrorCode::InvalidRewardMint
+    )]
+    pub singleton: Box<Account<'info, Singleton>>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = ClmmFarm::LEN,
+        seeds = [
+            b"clmm-farm",
+            singleton.pool.as_ref()
+        ],
+        bump
+    )]
+    pub clmm_farm: Box<Account<'info, ClmmFarm>>,
+
+    pub reward_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        init,
+        payer = authority,
+        seeds = [
+            b"farm-rewards",
+            clmm_farm.key().as_ref()
+        ],
+        bump,
+        token::mint = reward_mint,
+        token::authority = token_authority
+    )]
+    pub reward_vault: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK: This account is not read or written
+    #[account(
+        seeds = [
+            b"token-authority"
+        ],
+        bump = singleton.unit_auth_bump
+    )]
+    pub token_authority: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [
+            b"role-member",
+            authority.key().as_ref()
+        ],
+        bump = role_member.bump,
+        constraint = singleton.has_role(&role_member, Role::Admin) @ ErrorCode::MissingRole
+    )]
+    pub role_member: Account<'info, RoleMember>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/// Creates a farm of positions in the oracle pool, paying `rate` Units per second or streaming shares once they are
+/// distributed, over in range liquidity
+pub fn create_clmm_farm_handler(ctx: Context<CreateClmmFarm>, rate: u64) -> Result<()> {
+    require!(rate == 0 || ctx.accounts.reward_mint.key() != ctx.accounts.singleton.share.mint, ErrorCode::ShareFarmRate);
+
+    let clmm_farm = &mut ctx.accounts.clmm_farm;
+
+    ***clmm_farm = ClmmFarm {
+        pool: ctx.accounts.singleton.pool,
+        reward_mint: ctx.accounts.reward_mint.key(),
+        reward_vault: ctx.accounts.reward_vault.key(),
+        rewards: FarmRewards::init(rate, Clock::get()?.unix_timestamp),
+        bump: ctx.bumps.clmm_farm,
+    };
+
+    Ok(())
+}
\ No newline at end of file
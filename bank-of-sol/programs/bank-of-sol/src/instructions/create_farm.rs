@@ -0,0 +1,110 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+use crate::state::{singleton::Singleton, farm::Farm, roles::RoleMember};
+use crate::types::farm::FarmRewards;
+use crate::types::roles::Role;
+use crate::errors::ErrorCode;
+
+#[derive(Accounts)]
+pub struct CreateFarm<'info> {
+    #[account(
+        seeds = [
+            b"singleton"
+        ],
+        bump = singleton.bump,
+        constraint = reward_mint.key() == singleton.unit_mint || reward_mint.key() == singleton.share.mint @ ErrorCode::InvalidRewardMint
+    )]
+    pub singleton: Box<Account<'info, Singleton>>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = Farm::LEN,
+        seeds = [
+            b"farm",
+            stake_mint.key().as_ref()
+        ],
+        bump
+    )]
+    pub farm: Box<Account<'info, Farm>>,
+
+    #[account(
+        constraint = stake_mint.decimals > 0 @ ErrorCode::InvalidStakeMint
+    )]
+    pub stake_mint: Box<Account<'info, Mint>>,
+
+    pub reward_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        init,
+        payer = authority,
+        seeds = [
+            b"farm-stake",
+            farm.key().as_ref()
+        ],
+        bump,
+        token::mint = stake_mint,
+        token::authority = token_authority
+    )]
+    pub stake_vault: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        init,
+        payer = authority,
+        seeds = [
+            b"farm-rewards",
+            farm.key().as_ref()
+        ],
+        bump,
+        token::mint = reward_mint,
+        token::authority = token_authority
+    )]
+    pub reward_vault: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK: This account is not read or written
+    #[account(
+        seeds = [
+            b"token-authority"
+        ],
+        bump = singleton.unit_auth_bump
+    )]
+    pub token_authority: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [
+            b"role-member",
+            authority.key().as_ref()
+        ],
+        bump = role_member.bump,
+        constraint = singleton.has_role(&role_member, Role::Admin) @ ErrorCode::MissingRole
+    )]
+    pub role_member: Account<'info, RoleMember>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/// Creates a farm staking a fungible token such as a Unit/USDC LP token, paying `rate` Units per second or streaming
+/// shares once they are distributed or allocated.
+/// Raydium CLMM positions are NFTs and are staked in a `ClmmFarm` instead
+pub fn create_farm_handler(ctx: Context<CreateFarm>, rate: u64) -> Result<()> {
+    require!(rate == 0 || ctx.accounts.reward_mint.key() != ctx.accounts.singleton.share.mint, ErrorCode::ShareFarmRate);
+
+    let farm = &mut ctx.accounts.farm;
+
+    ***farm = Farm {
+        stake_mint: ctx.accounts.stake_mint.key(),
+        reward_mint: ctx.accounts.reward_mint.key(),
+        stake_vault: ctx.accounts.stake_vault.key(),
+        reward_vault: ctx.accounts.reward_vault.key(),
+        rewards: FarmRewards::init(rate, Clock::get()?.unix_timestamp),
+        bump: ctx.bumps.farm,
+    };
+
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+
+use crate::state::farm::{Farm, FarmPosition};
+
+#[derive(Accounts)]
+pub struct CreateFarmPosition<'info> {
+    #[account(
+        init,
+        payer = signer,
+        space = FarmPosition::LEN,
+        seeds = [
+            b"farm-position",
+            farm.key().as_ref(),
+            signer.key().as_ref()
+        ],
+        bump
+    )]
+    pub farm_position: Account<'info, FarmPosition>,
+
+    #[account(
+        seeds = [
+            b"farm",
+            farm.stake_mint.as_ref()
+        ],
+        bump = farm.bump
+    )]
+    pub farm: Account<'info, Farm>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn create_farm_position_handler(ctx: Context<CreateFarmPosition>) -> Result<()> {
+    let farm_position = &mut ctx.accounts.farm_position;
+    let farm = &ctx.accounts.farm;
+
+    **farm_position = FarmPosition::init(
+        farm.key(),
+        ctx.accounts.signer.key(),
+        farm.rewards.reward_per_token,
+        ctx.bumps.farm_position
+    );
+
+    Ok(())
+}
\ No newline at end of file
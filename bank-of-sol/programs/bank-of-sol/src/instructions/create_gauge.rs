@@ -0,0 +1,115 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+
+use crate::state::{singleton::Singleton, gauge::{GaugeController, Gauge}, farm::{Farm, ClmmFarm}, roles::RoleMember};
+use crate::types::gauge::GaugeTarget;
+use crate::types::roles::Role;
+use crate::errors::ErrorCode;
+
+#[derive(Accounts)]
+pub struct CreateGauge<'info> {
+    #[account(
+        seeds = [
+            b"singleton"
+        ],
+        bump = singleton.bump
+    )]
+    pub singleton: Account<'info, Singleton>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"gauge-controller"
+        ],
+        bump = gauge_controller.bump
+    )]
+    pub gauge_controller: Account<'info, GaugeController>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = Gauge::LEN,
+        seeds = [
+            b"gauge",
+            target_vault.key().as_ref()
+        ],
+        bump
+    )]
+    pub gauge: Account<'info, Gauge>,
+
+    #[account(
+        token::mint = singleton.share.mint,
+        token::authority = token_authority
+    )]
+    pub target_vault: Account<'info, TokenAccount>,
+
+    /// The farm of a `GaugeTarget::Farm` gauge
+    pub farm: Option<Box<Account<'info, Farm>>>,
+
+    /// The CLMM farm of a `GaugeTarget::ClmmFarm` gauge
+    pub clmm_farm: Option<Box<Account<'info, ClmmFarm>>>,
+
+    /// CHECK: This account is not read or written
+    #[account(
+        seeds = [
+            b"token-authority"
+        ],
+        bump = singleton.unit_auth_bump
+    )]
+    pub token_authority: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [
+            b"role-member",
+            authority.key().as_ref()
+        ],
+        bump = role_member.bump,
+        constraint = singleton.has_role(&role_member, Role::Admin) @ ErrorCode::MissingRole
+    )]
+    pub role_member: Account<'info, RoleMember>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Creates a gauge for a share-paying reward pool, `target_vault` has to be the reward vault of the `target` pool
+pub fn create_gauge_handler(ctx: Context<CreateGauge>, target: GaugeTarget) -> Result<()> {
+    let target_vault = ctx.accounts.target_vault.key();
+    let gauge_controller = &ctx.accounts.gauge_controller;
+
+    let (pool, reward_vault) = match target {
+        GaugeTarget::Farm => {
+            let farm = ctx.accounts.farm.as_ref().ok_or(ErrorCode::InvalidGaugeTarget)?;
+            (farm.key(), farm.reward_vault)
+        }
+        GaugeTarget::ClmmFarm => {
+            let clmm_farm = ctx.accounts.clmm_farm.as_ref().ok_or(ErrorCode::InvalidGaugeTarget)?;
+            (clmm_farm.key(), clmm_farm.reward_vault)
+        }
+        GaugeTarget::Boardroom => (Pubkey::default(), gauge_controller.boardroom_vault),
+        GaugeTarget::NftStaking => (Pubkey::default(), gauge_controller.nft_staking_vault),
+    };
+
+    require_keys_eq!(reward_vault, target_vault, ErrorCode::InvalidGaugeTarget);
+
+    let epoch = ctx.accounts.singleton.epoch();
+    let gauge = &mut ctx.accounts.gauge;
+
+    **gauge = Gauge {
+        target,
+        pool,
+        target_vault,
+        voting_epoch: epoch,
+        votes: 0,
+        emission_share: 0,
+        share_epoch: epoch,
+        distributed_epoch: 0,
+        bump: ctx.bumps.gauge,
+    };
+
+    ctx.accounts.gauge_controller.gauge_count += 1;
+
+    Ok(())
+}
\ No newline at end of file
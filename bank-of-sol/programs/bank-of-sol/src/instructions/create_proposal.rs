@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{singleton::Singleton, governance::{Governance, Proposal}, boardroom::BoardroomAccount};
+use crate::types::governance::ConfigChange;
+use crate::errors::ErrorCode;
+
+#[derive(Accounts)]
+pub struct CreateProposal<'info> {
+    #[account(
+        seeds = [
+            b"singleton"
+        ],
+        bump = singleton.bump
+    )]
+    pub singleton: Box<Account<'info, Singleton>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"governance"
+        ],
+        bump = governance.bump
+    )]
+    pub governance: Account<'info, Governance>,
+
+    #[account(
+        init,
+        payer = proposer,
+        space = Proposal::LEN,
+        seeds = [
+            b"proposal",
+            governance.proposal_count.to_be_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub proposal: Box<Account<'info, Proposal>>,
+
+    #[account(
+        seeds = [
+            b"boardroom-account",
+            proposer.key().as_ref()
+        ],
+        bump = boardroom_account.bump
+    )]
+    pub boardroom_account: Account<'info, BoardroomAccount>,
+
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Proposes a parameter change, the proposer must hold boardroom deposits
+pub fn create_proposal_handler(ctx: Context<CreateProposal>, change: ConfigChange) -> Result<()> {
+    let clock = Clock::get()?;
+    let singleton = &ctx.accounts.singleton;
+    let governance = &mut ctx.accounts.governance;
+    let proposal = &mut ctx.accounts.proposal;
+
+    require_gt!(ctx.accounts.boardroom_account.balance(), 0, ErrorCode::NoVotingPower);
+
+    ***proposal = Proposal {
+        index: governance.proposal_count,
+        proposer: ctx.accounts.proposer.key(),
+        change,
+        params: governance.params,
+        snapshot_epoch: singleton.epoch(),
+        snapshot_supply: singleton.boardroom_deposits.total_deposited_units,
+        voting_ends: clock.unix_timestamp + governance.params.voting_period,
+        votes_for: 0,
+        votes_against: 0,
+        executed: false,
+        bump: ctx.bumps.proposal,
+    };
+
+    governance.proposal_count += 1;
+
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,94 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount, Transfer, transfer};
+
+use crate::state::{singleton::Singleton, ve::{VeSupply, VeLock}};
+use crate::types::ve::{VePoint, LockHistory, lock_end};
+
+#[derive(Accounts)]
+pub struct CreateVeLock<'info> {
+    #[account(
+        seeds = [
+            b"singleton"
+        ],
+        bump = singleton.bump
+    )]
+    pub singleton: Box<Account<'info, Singleton>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"ve-supply"
+        ],
+        bump = ve_supply.bump,
+        has_one = vault
+    )]
+    pub ve_supply: Box<Account<'info, VeSupply>>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = VeLock::LEN,
+        seeds = [
+            b"ve-lock",
+            owner.key().as_ref()
+        ],
+        bump
+    )]
+    pub ve_lock: Account<'info, VeLock>,
+
+    #[account(mut)]
+    pub vault: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        token::mint = vault.mint,
+        token::authority = owner
+    )]
+    pub payer_account: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CreateVeLock<'info> {
+    pub fn transfer_tokens_ctx(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        let cpi_accounts = Transfer {
+            from: self.payer_account.to_account_info(),
+            to: self.vault.to_account_info(),
+            authority: self.owner.to_account_info(),
+        };
+
+        let cpi_program = self.token_program.to_account_info();
+
+        CpiContext::new(cpi_program, cpi_accounts)
+    }
+}
+
+/// Locks `amount` shares for `weeks`, rounded down to a whole week
+pub fn create_ve_lock_handler(ctx: Context<CreateVeLock>, amount: u64, weeks: i64) -> Result<()> {
+    ctx.accounts.singleton.pause.require_not_shutdown()?;
+
+    let now = Clock::get()?.unix_timestamp;
+    let point = VePoint { amount, end: lock_end(now, weeks)? };
+
+    let curve = &mut ctx.accounts.ve_supply.curve;
+    curve.checkpoint(now);
+    curve.update(&VePoint::default(), &point, now);
+
+    let ve_lock = &mut ctx.accounts.ve_lock;
+
+    **ve_lock = VeLock {
+        owner: ctx.accounts.owner.key(),
+        point: VePoint::default(),
+        history: LockHistory::default(),
+        bump: ctx.bumps.ve_lock,
+    };
+    ve_lock.set_point(point, now);
+
+    transfer(ctx.accounts.transfer_tokens_ctx(), amount)?;
+
+    Ok(())
+}
\ No newline at end of file
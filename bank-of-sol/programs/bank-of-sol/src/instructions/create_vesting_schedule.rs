@@ -0,0 +1,175 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount, Transfer, transfer, MintTo, mint_to};
+
+use crate::state::{singleton::Singleton, treasury::Treasury, vesting_schedule::VestingSchedule, roles::RoleMember};
+use crate::types::{treasury::FundKind, vesting::VestingTerms};
+use crate::types::roles::Role;
+use crate::errors::ErrorCode;
+
+/**
+ * Vesting Units and shares
+ *
+ * Unit schedules are paid from a treasury fund and count towards its spend limit, the fund's vault has to
+ * be passed as `fund_vault`. The treasury holds no shares, so share schedules are minted into the vesting
+ * vault instead and count towards the share max supply, `fund_vault` is left out for them.
+ */
+#[derive(Accounts)]
+#[instruction(fund: FundKind)]
+pub struct CreateVestingSchedule<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"singleton"
+        ],
+        bump = singleton.bump,
+        constraint = mint.key() == singleton.unit_mint || mint.key() == singleton.share.mint @ ErrorCode::InvalidVestingMint
+    )]
+    pub singleton: Account<'info, Singleton>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"treasury"
+        ],
+        bump = treasury.bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = VestingSchedule::LEN,
+        seeds = [
+            b"vesting-schedule",
+            mint.key().as_ref(),
+            beneficiary.key().as_ref()
+        ],
+        bump
+    )]
+    pub vesting_schedule: Account<'info, VestingSchedule>,
+
+    #[account(
+        init,
+        payer = authority,
+        seeds = [
+            b"vesting-vault",
+            vesting_schedule.key().as_ref()
+        ],
+        bump,
+        token::mint = mint,
+        token::authority = token_authority
+    )]
+    pub vesting_vault: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        constraint = treasury.fund(fund).vault == fund_vault.key() @ ErrorCode::UnexpectedAccount
+    )]
+    pub fund_vault: Option<Account<'info, TokenAccount>>,
+
+    /// CHECK: This account is not read or written
+    #[account(
+        seeds = [
+            b"token-authority"
+        ],
+        bump = singleton.unit_auth_bump
+    )]
+    pub token_authority: UncheckedAccount<'info>,
+
+    /// CHECK: Only used as the beneficiary of the schedule
+    pub beneficiary: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [
+            b"role-member",
+            authority.key().as_ref()
+        ],
+        bump = role_member.bump,
+        constraint = singleton.has_role(&role_member, Role::TreasurySpender) @ ErrorCode::MissingRole
+    )]
+    pub role_member: Account<'info, RoleMember>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+impl<'info> CreateVestingSchedule<'info> {
+    pub fn transfer_ctx(&self, fund_vault: &Account<'info, TokenAccount>) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        let cpi_accounts = Transfer {
+            from: fund_vault.to_account_info(),
+            to: self.vesting_vault.to_account_info(),
+            authority: self.token_authority.to_account_info(),
+        };
+
+        let cpi_program = self.token_program.to_account_info();
+
+        CpiContext::new(cpi_program, cpi_accounts)
+    }
+
+    pub fn mint_token_ctx(&self) -> CpiContext<'_, '_, '_, 'info, MintTo<'info>> {
+        let cpi_accounts = MintTo {
+            mint: self.mint.to_account_info(),
+            to: self.vesting_vault.to_account_info(),
+            authority: self.token_authority.to_account_info(),
+        };
+
+        let cpi_program = self.token_program.to_account_info();
+
+        CpiContext::new(cpi_program, cpi_accounts)
+    }
+}
+
+/// Vests `amount` Units from `fund` or `amount` newly minted shares to the beneficiary
+pub fn create_vesting_schedule_handler(
+    ctx: Context<CreateVestingSchedule>,
+    fund: FundKind,
+    amount: u64,
+    start: i64,
+    cliff: i64,
+    duration: i64
+) -> Result<()> {
+    let terms = VestingTerms::init(start, cliff, duration)?;
+    let epoch = ctx.accounts.singleton.epoch();
+    let unit_auth_bump = ctx.accounts.singleton.unit_auth_bump;
+    let is_share = ctx.accounts.mint.key() == ctx.accounts.singleton.share.mint;
+
+    if is_share {
+        ctx.accounts.singleton.share.allocate(amount)?;
+    } else {
+        ctx.accounts.treasury.fund_mut(fund).spend(amount, epoch)?;
+    }
+
+    let vesting_schedule = &mut ctx.accounts.vesting_schedule;
+
+    **vesting_schedule = VestingSchedule {
+        seed: ctx.accounts.beneficiary.key(),
+        beneficiary: ctx.accounts.beneficiary.key(),
+        mint: ctx.accounts.mint.key(),
+        vault: ctx.accounts.vesting_vault.key(),
+        fund,
+        total: amount,
+        claimed: 0,
+        terms,
+        revoked: false,
+        bump: ctx.bumps.vesting_schedule,
+    };
+
+    let unit_auth_seed = &[&b"token-authority"[..], &[unit_auth_bump]];
+
+    if is_share {
+        mint_to(ctx.accounts.mint_token_ctx().with_signer(&[&unit_auth_seed[..]]), amount)?;
+    } else {
+        let fund_vault = ctx.accounts.fund_vault.as_ref().ok_or(ErrorCode::MissingFundVault)?;
+
+        transfer(ctx.accounts.transfer_ctx(fund_vault).with_signer(&[&unit_auth_seed[..]]), amount)?;
+    }
+
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,132 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount, Transfer, transfer};
+
+use crate::state::{singleton::Singleton, gauge::{GaugeController, Gauge}, farm::{Farm, ClmmFarm}};
+use crate::types::gauge::GaugeTarget;
+use crate::errors::ErrorCode;
+
+#[derive(Accounts)]
+pub struct DistributeGaugeEmissions<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"singleton"
+        ],
+        bump = singleton.bump,
+        constraint = singleton.share.reward_vault == share_reward_vault.key() @ ErrorCode::UnexpectedAccount
+    )]
+    pub singleton: Box<Account<'info, Singleton>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"gauge-controller"
+        ],
+        bump = gauge_controller.bump
+    )]
+    pub gauge_controller: Account<'info, GaugeController>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"gauge",
+            target_vault.key().as_ref()
+        ],
+        bump = gauge.bump,
+        has_one = target_vault
+    )]
+    pub gauge: Account<'info, Gauge>,
+
+    /// The farm of a `GaugeTarget::Farm` gauge
+    #[account(mut)]
+    pub farm: Option<Box<Account<'info, Farm>>>,
+
+    /// The CLMM farm of a `GaugeTarget::ClmmFarm` gauge
+    #[account(mut)]
+    pub clmm_farm: Option<Box<Account<'info, ClmmFarm>>>,
+
+    #[account(mut)]
+    pub share_reward_vault: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub target_vault: Account<'info, TokenAccount>,
+
+    /// CHECK: This account is not read or written
+    #[account(
+        seeds = [
+            b"token-authority"
+        ],
+        bump = singleton.unit_auth_bump
+    )]
+    pub token_authority: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> DistributeGaugeEmissions<'info> {
+    pub fn transfer_ctx(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        let cpi_accounts = Transfer {
+            from: self.share_reward_vault.to_account_info(),
+            to: self.target_vault.to_account_info(),
+            authority: self.token_authority.to_account_info(),
+        };
+
+        let cpi_program = self.token_program.to_account_info();
+
+        CpiContext::new(cpi_program, cpi_accounts)
+    }
+}
+
+/// Pays a gauge its share of this epoch's emissions budget, callable by anyone once per gauge and epoch.
+/// Emissions of a pool with nothing staked stay in the budget
+pub fn distribute_gauge_emissions_handler(ctx: Context<DistributeGaugeEmissions>) -> Result<()> {
+    ctx.accounts.singleton.pause.require_not_shutdown()?;
+
+    let epoch = ctx.accounts.singleton.epoch();
+    let unit_auth_bump = ctx.accounts.singleton.unit_auth_bump;
+
+    let amount = ctx.accounts.gauge.distribute(&mut ctx.accounts.gauge_controller, epoch);
+
+    if amount == 0 {
+        return Ok(());
+    }
+
+    let now = Clock::get()?.unix_timestamp;
+    let pool = ctx.accounts.gauge.pool;
+
+    let credited = match ctx.accounts.gauge.target {
+        GaugeTarget::Farm => {
+            let farm = ctx.accounts.farm.as_mut().filter(|farm| farm.key() == pool).ok_or(ErrorCode::InvalidGaugeTarget)?;
+            let staked = farm.rewards.total_staked > 0;
+
+            if staked {
+                farm.rewards.notify(amount, now);
+            }
+
+            staked
+        }
+        GaugeTarget::ClmmFarm => {
+            let clmm_farm = ctx.accounts.clmm_farm.as_mut().filter(|farm| farm.key() == pool).ok_or(ErrorCode::InvalidGaugeTarget)?;
+            let staked = clmm_farm.rewards.total_staked > 0;
+
+            if staked {
+                clmm_farm.rewards.notify(amount, now);
+            }
+
+            staked
+        }
+        GaugeTarget::Boardroom => ctx.accounts.singleton.distribute_boardroom_share_reward(amount),
+        GaugeTarget::NftStaking => ctx.accounts.singleton.nft_staking.distribute_share_reward(amount),
+    };
+
+    if !credited {
+        ctx.accounts.gauge_controller.budget.refund(amount);
+        return Ok(());
+    }
+
+    let unit_auth_seed = &[&b"token-authority"[..], &[unit_auth_bump]];
+
+    transfer(ctx.accounts.transfer_ctx().with_signer(&[&unit_auth_seed[..]]), amount)?;
+
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,82 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount, MintTo, mint_to};
+
+use crate::state::{singleton::Singleton, gauge::GaugeController};
+use crate::errors::ErrorCode;
+
+#[derive(Accounts)]
+pub struct EmitShares<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"singleton"
+        ],
+        bump = singleton.bump,
+        constraint = singleton.share.mint == share_mint.key() @ ErrorCode::UnexpectedAccount,
+        constraint = singleton.share.reward_vault == share_reward_vault.key() @ ErrorCode::UnexpectedAccount
+    )]
+    pub singleton: Account<'info, Singleton>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"gauge-controller"
+        ],
+        bump = gauge_controller.bump
+    )]
+    pub gauge_controller: Account<'info, GaugeController>,
+
+    #[account(mut)]
+    pub share_mint: Account<'info, Mint>,
+
+    #[account(mut)]
+    pub share_reward_vault: Account<'info, TokenAccount>,
+
+    /// CHECK: This account is not read or written
+    #[account(
+        seeds = [
+            b"token-authority"
+        ],
+        bump = singleton.unit_auth_bump
+    )]
+    pub token_authority: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> EmitShares<'info> {
+    pub fn mint_token_ctx(&self) -> CpiContext<'_, '_, '_, 'info, MintTo<'info>> {
+        let cpi_accounts = MintTo {
+            mint: self.share_mint.to_account_info(),
+            to: self.share_reward_vault.to_account_info(),
+            authority: self.token_authority.to_account_info(),
+        };
+
+        let cpi_program = self.token_program.to_account_info();
+
+        CpiContext::new(cpi_program, cpi_accounts)
+    }
+}
+
+/// Mints the shares emitted up to now into the reward vault and adds them to the gauges' emissions budget
+pub fn emit_shares_handler(ctx: Context<EmitShares>) -> Result<()> {
+    ctx.accounts.singleton.pause.require_not_shutdown()?;
+
+    let unit_auth_bump = ctx.accounts.singleton.unit_auth_bump;
+    let amount = ctx.accounts.singleton.share.emit(Clock::get()?.unix_timestamp);
+
+    if amount > 0 {
+        let unit_auth_seed = &[&b"token-authority"[..], &[unit_auth_bump]];
+
+        mint_to(ctx.accounts.mint_token_ctx().with_signer(&[&unit_auth_seed[..]]), amount)?;
+
+        // Emissions of an epoch are only budgeted in the next one
+        let epoch = ctx.accounts.singleton.epoch();
+        let budget = &mut ctx.accounts.gauge_controller.budget;
+
+        budget.roll_over(epoch);
+        budget.add_emissions(amount);
+    }
+
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,197 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount, MintTo, mint_to};
+
+use crate::state::{singleton::Singleton, timelock::{Timelock, PendingAction}, governance::Governance, treasury::Treasury, farm::Farm, gauge::GaugeController};
+use crate::types::timelock::TimelockAction;
+use crate::types::governance::GovernanceParams;
+use crate::types::share::ShareEmission;
+use crate::types::treasury::SeigniorageSplit;
+use crate::errors::ErrorCode;
+
+#[derive(Accounts)]
+pub struct ExecuteAction<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"singleton"
+        ],
+        bump = singleton.bump
+    )]
+    pub singleton: Box<Account<'info, Singleton>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"timelock"
+        ],
+        bump = timelock.bump
+    )]
+    pub timelock: Account<'info, Timelock>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"pending-action",
+            pending_action.index.to_be_bytes().as_ref()
+        ],
+        bump = pending_action.bump,
+        has_one = queued_by,
+        close = queued_by
+    )]
+    pub pending_action: Box<Account<'info, PendingAction>>,
+
+    #[account(mut)]
+    pub queued_by: SystemAccount<'info>,
+
+    /// Required by `TimelockAction::Governance`
+    #[account(
+        mut,
+        seeds = [
+            b"governance"
+        ],
+        bump = governance.bump
+    )]
+    pub governance: Option<Box<Account<'info, Governance>>>,
+
+    /// Required by `TimelockAction::TreasurySpendLimit` and `TimelockAction::TreasurySplit`
+    #[account(
+        mut,
+        seeds = [
+            b"treasury"
+        ],
+        bump = treasury.bump
+    )]
+    pub treasury: Option<Box<Account<'info, Treasury>>>,
+
+    /// Required by `TimelockAction::FarmRate`
+    #[account(
+        mut,
+        seeds = [
+            b"farm",
+            farm.stake_mint.as_ref()
+        ],
+        bump = farm.bump
+    )]
+    pub farm: Option<Box<Account<'info, Farm>>>,
+
+    /// Required by `TimelockAction::ShareEmission`, with the share accounts below
+    #[account(
+        mut,
+        seeds = [
+            b"gauge-controller"
+        ],
+        bump = gauge_controller.bump
+    )]
+    pub gauge_controller: Option<Box<Account<'info, GaugeController>>>,
+
+    #[account(mut)]
+    pub share_mint: Option<Account<'info, Mint>>,
+
+    #[account(mut)]
+    pub share_reward_vault: Option<Account<'info, TokenAccount>>,
+
+    /// CHECK: This account is not read or written
+    #[account(
+        seeds = [
+            b"token-authority"
+        ],
+        bump = singleton.unit_auth_bump
+    )]
+    pub token_authority: Option<UncheckedAccount<'info>>,
+
+    pub token_program: Option<Program<'info, Token>>,
+}
+
+impl<'info> ExecuteAction<'info> {
+    pub fn mint_shares_ctx(&self) -> Result<CpiContext<'_, '_, '_, 'info, MintTo<'info>>> {
+        let share_mint = self.share_mint.as_ref().ok_or(ErrorCode::MissingActionAccount)?;
+        let share_reward_vault = self.share_reward_vault.as_ref().ok_or(ErrorCode::MissingActionAccount)?;
+        let token_authority = self.token_authority.as_ref().ok_or(ErrorCode::MissingActionAccount)?;
+        let token_program = self.token_program.as_ref().ok_or(ErrorCode::MissingActionAccount)?;
+
+        require_keys_eq!(share_mint.key(), self.singleton.share.mint, ErrorCode::UnexpectedAccount);
+        require_keys_eq!(share_reward_vault.key(), self.singleton.share.reward_vault, ErrorCode::UnexpectedAccount);
+
+        let cpi_accounts = MintTo {
+            mint: share_mint.to_account_info(),
+            to: share_reward_vault.to_account_info(),
+            authority: token_authority.to_account_info(),
+        };
+
+        Ok(CpiContext::new(token_program.to_account_info(), cpi_accounts))
+    }
+}
+
+/// Applies a queued action during its grace period, callable by anyone. Actions pass the accounts they
+/// change, the others are left out
+pub fn execute_action_handler(ctx: Context<ExecuteAction>) -> Result<()> {
+    ctx.accounts.singleton.pause.require_not_shutdown()?;
+
+    let now = Clock::get()?.unix_timestamp;
+
+    match ctx.accounts.pending_action.ready(&ctx.accounts.timelock, now)? {
+        TimelockAction::Config { change } => ctx.accounts.singleton.apply_config_change(change)?,
+        TimelockAction::SetDelay { delay } => ctx.accounts.timelock.delay = delay,
+        TimelockAction::BoardroomDepositToken { deposit_token } => {
+            let boardroom_deposits = &mut ctx.accounts.singleton.boardroom_deposits;
+
+            require_eq!(boardroom_deposits.total_deposited_units, 0, ErrorCode::BoardroomNotEmpty);
+
+            boardroom_deposits.deposit_token = deposit_token;
+        },
+        TimelockAction::BondTokenMode { enabled } => {
+            let bonds = &mut ctx.accounts.singleton.bonds;
+
+            if enabled {
+                require_keys_neq!(bonds.bond_mint, Pubkey::default(), ErrorCode::BondMintNotCreated);
+            }
+
+            bonds.set_token_mode(enabled)?;
+        },
+        TimelockAction::Governance { quorum, threshold, voting_period } => {
+            let governance = ctx.accounts.governance.as_mut().ok_or(ErrorCode::MissingActionAccount)?;
+
+            // Open proposals keep the rules they were created with
+            governance.params = GovernanceParams::init(quorum, threshold, voting_period)?;
+        },
+        TimelockAction::ShareEmission { start, end, rate } => {
+            // Shares emitted under the previous schedule are minted and budgeted first
+            let epoch = ctx.accounts.singleton.epoch();
+            let unit_auth_bump = ctx.accounts.singleton.unit_auth_bump;
+            let amount = ctx.accounts.singleton.share.emit(now);
+
+            ctx.accounts.singleton.share.emission = ShareEmission::init(start, end, rate, now)?;
+
+            if amount > 0 {
+                let unit_auth_seed = &[&b"token-authority"[..], &[unit_auth_bump]];
+
+                mint_to(ctx.accounts.mint_shares_ctx()?.with_signer(&[&unit_auth_seed[..]]), amount)?;
+
+                let gauge_controller = ctx.accounts.gauge_controller.as_mut().ok_or(ErrorCode::MissingActionAccount)?;
+
+                gauge_controller.budget.roll_over(epoch);
+                gauge_controller.budget.add_emissions(amount);
+            }
+        },
+        TimelockAction::TreasurySpendLimit { fund, epoch_limit } => {
+            let treasury = ctx.accounts.treasury.as_mut().ok_or(ErrorCode::MissingActionAccount)?;
+
+            treasury.fund_mut(fund).epoch_limit = epoch_limit;
+        },
+        TimelockAction::TreasurySplit { dao_fund, dev_fund, boardroom } => {
+            let treasury = ctx.accounts.treasury.as_mut().ok_or(ErrorCode::MissingActionAccount)?;
+
+            treasury.split = SeigniorageSplit::init(dao_fund, dev_fund, boardroom)?;
+        },
+        TimelockAction::FarmRate { farm, rate } => {
+            let share_mint = ctx.accounts.singleton.share.mint;
+            let farm_account = ctx.accounts.farm.as_mut().filter(|account| account.key() == farm).ok_or(ErrorCode::MissingActionAccount)?;
+
+            require_keys_neq!(farm_account.reward_mint, share_mint, ErrorCode::ShareFarmRate);
+
+            farm_account.rewards.set_rate(rate, now);
+        },
+    }
+
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{singleton::Singleton, governance::Proposal, timelock::{Timelock, PendingAction}};
+use crate::types::timelock::TimelockAction;
+
+#[derive(Accounts)]
+pub struct ExecuteProposal<'info> {
+    #[account(
+        seeds = [
+            b"singleton"
+        ],
+        bump = singleton.bump
+    )]
+    pub singleton: Box<Account<'info, Singleton>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"proposal",
+            proposal.index.to_be_bytes().as_ref()
+        ],
+        bump = proposal.bump
+    )]
+    pub proposal: Box<Account<'info, Proposal>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"timelock"
+        ],
+        bump = timelock.bump
+    )]
+    pub timelock: Account<'info, Timelock>,
+
+    #[account(
+        init,
+        payer = executor,
+        space = PendingAction::LEN,
+        seeds = [
+            b"pending-action",
+            timelock.action_count.to_be_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub pending_action: Box<Account<'info, PendingAction>>,
+
+    #[account(mut)]
+    pub executor: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Queues a passed proposal's change in the timelock once voting has ended, callable by anyone
+pub fn execute_proposal_handler(ctx: Context<ExecuteProposal>) -> Result<()> {
+    ctx.accounts.singleton.pause.require_not_shutdown()?;
+
+    let clock = Clock::get()?;
+    let change = ctx.accounts.proposal.execute(clock.unix_timestamp)?;
+    let pending_action = &mut ctx.accounts.pending_action;
+
+    ***pending_action = PendingAction::queue(
+        &mut ctx.accounts.timelock,
+        TimelockAction::Config { change },
+        clock.unix_timestamp,
+        ctx.accounts.executor.key(),
+        ctx.bumps.pending_action
+    );
+
+    Ok(())
+}
\ No newline at end of file
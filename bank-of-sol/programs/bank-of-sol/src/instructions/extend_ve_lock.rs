@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+
+use crate::state::ve::{VeSupply, VeLock};
+use crate::types::ve::{VePoint, lock_end};
+use crate::errors::ErrorCode;
+
+#[derive(Accounts)]
+pub struct ExtendVeLock<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"ve-supply"
+        ],
+        bump = ve_supply.bump
+    )]
+    pub ve_supply: Box<Account<'info, VeSupply>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"ve-lock",
+            owner.key().as_ref()
+        ],
+        bump = ve_lock.bump,
+        has_one = owner
+    )]
+    pub ve_lock: Account<'info, VeLock>,
+
+    pub owner: Signer<'info>,
+}
+
+/// Moves the end of an unexpired lock to `weeks` from now, which must be later than its current end
+pub fn extend_ve_lock_handler(ctx: Context<ExtendVeLock>, weeks: i64) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let ve_lock = &mut ctx.accounts.ve_lock;
+
+    require_gt!(ve_lock.point.end, now, ErrorCode::LockExpired);
+
+    let point = VePoint { amount: ve_lock.point.amount, end: lock_end(now, weeks)? };
+    require_gt!(point.end, ve_lock.point.end, ErrorCode::LockNotExtended);
+
+    let curve = &mut ctx.accounts.ve_supply.curve;
+    curve.checkpoint(now);
+    curve.update(&ve_lock.point, &point, now);
+
+    ve_lock.set_point(point, now);
+
+    Ok(())
+}
\ No newline at end of file
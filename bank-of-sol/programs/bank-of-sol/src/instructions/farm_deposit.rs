@@ -0,0 +1,93 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount, Transfer, transfer};
+
+use crate::state::{singleton::Singleton, farm::{Farm, FarmPosition}};
+use crate::types::pause::Subsystem;
+
+#[derive(Accounts)]
+pub struct FarmDeposit<'info> {
+    #[account(
+        seeds = [
+            b"singleton"
+        ],
+        bump = singleton.bump
+    )]
+    pub singleton: Account<'info, Singleton>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"farm",
+            farm.stake_mint.as_ref()
+        ],
+        bump = farm.bump,
+        has_one = stake_vault
+    )]
+    pub farm: Account<'info, Farm>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"farm-position",
+            farm.key().as_ref(),
+            signer.key().as_ref()
+        ],
+        bump = farm_position.bump
+    )]
+    pub farm_position: Account<'info, FarmPosition>,
+
+    #[account(mut)]
+    pub stake_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        token::mint = farm.stake_mint,
+        token::authority = signer
+    )]
+    pub payer_account: Account<'info, TokenAccount>,
+
+    /// CHECK: This account is not read or written
+    #[account(
+        seeds = [
+            b"token-authority"
+        ],
+        bump = singleton.unit_auth_bump
+    )]
+    pub token_authority: UncheckedAccount<'info>,
+
+    pub signer: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> FarmDeposit<'info> {
+    pub fn transfer_tokens_ctx(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        let cpi_accounts = Transfer {
+            from: self.payer_account.to_account_info(),
+            to: self.stake_vault.to_account_info(),
+            authority: self.signer.to_account_info(),
+        };
+
+        let cpi_program = self.token_program.to_account_info();
+
+        CpiContext::new(cpi_program, cpi_accounts)
+    }
+}
+
+/// Stakes `amount` into the farm, settling the position's rewards first
+pub fn farm_deposit_handler(ctx: Context<FarmDeposit>, amount: u64) -> Result<()> {
+    ctx.accounts.singleton.pause.require_active(Subsystem::Staking)?;
+
+    let farm = &mut ctx.accounts.farm;
+    let farm_position = &mut ctx.accounts.farm_position;
+
+    let reward_per_token = farm.rewards.update(Clock::get()?.unix_timestamp);
+    farm_position.settle_rewards(reward_per_token);
+
+    farm_position.amount += amount;
+    farm.rewards.total_staked += amount;
+
+    transfer(ctx.accounts.transfer_tokens_ctx(), amount)?;
+
+    Ok(())
+}
\ No newline at end of file
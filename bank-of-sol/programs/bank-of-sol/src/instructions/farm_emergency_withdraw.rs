@@ -0,0 +1,98 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount, Transfer, transfer};
+
+use crate::state::{singleton::Singleton, farm::{Farm, FarmPosition}};
+
+#[derive(Accounts)]
+pub struct FarmEmergencyWithdraw<'info> {
+    #[account(
+        seeds = [
+            b"singleton"
+        ],
+        bump = singleton.bump
+    )]
+    pub singleton: Account<'info, Singleton>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"farm",
+            farm.stake_mint.as_ref()
+        ],
+        bump = farm.bump,
+        has_one = stake_vault
+    )]
+    pub farm: Account<'info, Farm>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"farm-position",
+            farm.key().as_ref(),
+            signer.key().as_ref()
+        ],
+        bump = farm_position.bump
+    )]
+    pub farm_position: Account<'info, FarmPosition>,
+
+    #[account(mut)]
+    pub stake_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        token::mint = farm.stake_mint,
+        token::authority = signer
+    )]
+    pub receiver_account: Account<'info, TokenAccount>,
+
+    /// CHECK: This account is not read or written
+    #[account(
+        seeds = [
+            b"token-authority"
+        ],
+        bump = singleton.unit_auth_bump
+    )]
+    pub token_authority: UncheckedAccount<'info>,
+
+    pub signer: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> FarmEmergencyWithdraw<'info> {
+    pub fn transfer_tokens_ctx(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        let cpi_accounts = Transfer {
+            from: self.stake_vault.to_account_info(),
+            to: self.receiver_account.to_account_info(),
+            authority: self.token_authority.to_account_info(),
+        };
+
+        let cpi_program = self.token_program.to_account_info();
+
+        CpiContext::new(cpi_program, cpi_accounts)
+    }
+}
+
+/// Unstakes everything without settling rewards, all pending rewards are forfeited
+pub fn farm_emergency_withdraw_handler(ctx: Context<FarmEmergencyWithdraw>) -> Result<()> {
+    let unit_auth_bump = ctx.accounts.singleton.unit_auth_bump;
+    let farm = &mut ctx.accounts.farm;
+    let farm_position = &mut ctx.accounts.farm_position;
+
+    // Accrue up to now at the current total so other stakers are not affected by the withdrawal
+    let reward_per_token = farm.rewards.update(Clock::get()?.unix_timestamp);
+
+    let amount = farm_position.amount;
+    farm_position.amount = 0;
+    farm_position.pending_rewards = 0;
+    farm_position.reward_per_token_paid = reward_per_token;
+    farm.rewards.total_staked -= amount;
+
+    if amount > 0 {
+        let unit_auth_seed = &[&b"token-authority"[..], &[unit_auth_bump]];
+
+        transfer(ctx.accounts.transfer_tokens_ctx().with_signer(&[&unit_auth_seed[..]]), amount)?;
+    }
+
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,99 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount, Transfer, transfer};
+
+use crate::state::{singleton::Singleton, farm::{Farm, FarmPosition}};
+use crate::types::pause::Subsystem;
+
+#[derive(Accounts)]
+pub struct FarmHarvest<'info> {
+    #[account(
+        seeds = [
+            b"singleton"
+        ],
+        bump = singleton.bump
+    )]
+    pub singleton: Account<'info, Singleton>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"farm",
+            farm.stake_mint.as_ref()
+        ],
+        bump = farm.bump,
+        has_one = reward_vault
+    )]
+    pub farm: Account<'info, Farm>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"farm-position",
+            farm.key().as_ref(),
+            signer.key().as_ref()
+        ],
+        bump = farm_position.bump
+    )]
+    pub farm_position: Account<'info, FarmPosition>,
+
+    #[account(mut)]
+    pub reward_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        token::mint = farm.reward_mint,
+        token::authority = signer
+    )]
+    pub receiver_account: Account<'info, TokenAccount>,
+
+    /// CHECK: This account is not read or written
+    #[account(
+        seeds = [
+            b"token-authority"
+        ],
+        bump = singleton.unit_auth_bump
+    )]
+    pub token_authority: UncheckedAccount<'info>,
+
+    pub signer: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> FarmHarvest<'info> {
+    pub fn transfer_tokens_ctx(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        let cpi_accounts = Transfer {
+            from: self.reward_vault.to_account_info(),
+            to: self.receiver_account.to_account_info(),
+            authority: self.token_authority.to_account_info(),
+        };
+
+        let cpi_program = self.token_program.to_account_info();
+
+        CpiContext::new(cpi_program, cpi_accounts)
+    }
+}
+
+/// Pays out the position's rewards, up to the balance of the reward vault
+pub fn farm_harvest_handler(ctx: Context<FarmHarvest>) -> Result<()> {
+    ctx.accounts.singleton.pause.require_active(Subsystem::Claims)?;
+
+    let unit_auth_bump = ctx.accounts.singleton.unit_auth_bump;
+    let available = ctx.accounts.reward_vault.amount;
+    let farm = &mut ctx.accounts.farm;
+    let farm_position = &mut ctx.accounts.farm_position;
+
+    let reward_per_token = farm.rewards.update(Clock::get()?.unix_timestamp);
+    farm_position.settle_rewards(reward_per_token);
+
+    let reward = farm_position.pending_rewards.min(available);
+    farm_position.pending_rewards -= reward;
+
+    if reward > 0 {
+        let unit_auth_seed = &[&b"token-authority"[..], &[unit_auth_bump]];
+
+        transfer(ctx.accounts.transfer_tokens_ctx().with_signer(&[&unit_auth_seed[..]]), reward)?;
+    }
+
+    Ok(())
+}
\ No newline at end of file
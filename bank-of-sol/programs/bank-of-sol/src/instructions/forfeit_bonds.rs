@@ -0,0 +1,113 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token::{Mint, Token, TokenAccount, Transfer, transfer},
+    associated_token::AssociatedToken,
+};
+
+use crate::state::{bond_account::{BondAccount, BondAccountsStore}, singleton::Singleton};
+use crate::errors::ErrorCode;
+
+/// Forfeits an owner's oldest bond account once expired, clearing its liability from `BondStorage`.
+/// The Units burnt to purchase the bonds stay with the protocol, the Units reserved for any funded part
+/// of them go to the DAO fund and the rent is returned to the owner.
+#[derive(Accounts)]
+#[instruction(_seed_index: u64)]
+pub struct ForfeitBonds<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"bond-account",
+            owner.key().as_ref(),
+            _seed_index.to_be_bytes().as_ref()
+        ],
+        bump = bond_account.bump,
+        close = owner,
+        constraint = bond_account.user == owner.key() @ ErrorCode::UnexpectedAccount,
+        constraint = _seed_index == bond_accounts_store.get_oldest() @ ErrorCode::InvalidSeedIndex
+    )]
+    pub bond_account: Account<'info, BondAccount>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"bond-accounts-store",
+            owner.key().as_ref()
+        ],
+        bump = bond_accounts_store.bump,
+    )]
+    pub bond_accounts_store: Account<'info, BondAccountsStore>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"singleton"
+        ],
+        bump = singleton.bump,
+        has_one = unit_mint
+    )]
+    pub singleton: Account<'info, Singleton>,
+
+    pub unit_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = unit_mint,
+        associated_token::authority = token_authority
+    )]
+    pub unit_custody_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"dao-fund"
+        ],
+        bump
+    )]
+    pub dao_fund_vault: Account<'info, TokenAccount>,
+
+    /// CHECK: This account is not read or written
+    #[account(
+        seeds = [
+            b"token-authority"
+        ],
+        bump = singleton.unit_auth_bump
+    )]
+    pub token_authority: UncheckedAccount<'info>,
+
+    /// CHECK: Only receives the rent of the closed bond account
+    #[account(mut)]
+    pub owner: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+impl<'info> ForfeitBonds<'info> {
+    pub fn transfer_reserve_ctx(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        let cpi_accounts = Transfer {
+            from: self.unit_custody_account.to_account_info(),
+            to: self.dao_fund_vault.to_account_info(),
+            authority: self.token_authority.to_account_info(),
+        };
+
+        let cpi_program = self.token_program.to_account_info();
+
+        CpiContext::new(cpi_program, cpi_accounts)
+    }
+}
+
+pub fn forfeit_bonds_handler(ctx: Context<ForfeitBonds>, _seed_index: u64) -> Result<()> {
+    let singleton = &mut ctx.accounts.singleton;
+    let unit_auth_bump = singleton.unit_auth_bump;
+
+    let released = singleton.forfeit_bonds(&ctx.accounts.bond_account)?;
+    ctx.accounts.bond_accounts_store.pda_closed();
+
+    if released > 0 {
+        let unit_auth_seed = &[&b"token-authority"[..], &[unit_auth_bump]];
+
+        transfer(ctx.accounts.transfer_reserve_ctx().with_signer(&[&unit_auth_seed[..]]), released)?;
+    }
+
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount, Transfer, transfer};
+
+use crate::state::farm::Farm;
+
+#[derive(Accounts)]
+pub struct FundFarm<'info> {
+    #[account(
+        seeds = [
+            b"farm",
+            farm.stake_mint.as_ref()
+        ],
+        bump = farm.bump,
+        has_one = reward_vault
+    )]
+    pub farm: Account<'info, Farm>,
+
+    #[account(mut)]
+    pub reward_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        token::mint = farm.reward_mint,
+        token::authority = signer
+    )]
+    pub payer_account: Account<'info, TokenAccount>,
+
+    pub signer: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> FundFarm<'info> {
+    pub fn transfer_tokens_ctx(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        let cpi_accounts = Transfer {
+            from: self.payer_account.to_account_info(),
+            to: self.reward_vault.to_account_info(),
+            authority: self.signer.to_account_info(),
+        };
+
+        let cpi_program = self.token_program.to_account_info();
+
+        CpiContext::new(cpi_program, cpi_accounts)
+    }
+}
+
+/// Adds `amount` to the farm's reward vault, such as Units from a treasury fund for a Unit-paying farm
+pub fn fund_farm_handler(ctx: Context<FundFarm>, amount: u64) -> Result<()> {
+    transfer(ctx.accounts.transfer_tokens_ctx(), amount)
+}
\ No newline at end of file
@@ -0,0 +1,19 @@
+use anchor_lang::prelude::*;
+
+use crate::state::singleton::Singleton;
+
+#[derive(Accounts)]
+pub struct GetInterestRate<'info> {
+    #[account(
+        seeds = [
+            b"singleton"
+        ],
+        bump = singleton.bump
+    )]
+    pub singleton: Account<'info, Singleton>,
+}
+
+/// Returns the rate bonds are currently sold at, scaled by `INTEREST_RATE_PRECISION`
+pub fn get_interest_rate_handler(ctx: Context<GetInterestRate>) -> Result<u64> {
+    Ok(ctx.accounts.singleton.bond_rate(Clock::get()?.unix_timestamp))
+}
\ No newline at end of file
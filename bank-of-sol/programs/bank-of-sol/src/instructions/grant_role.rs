@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{singleton::Singleton, roles::RoleMember};
+use crate::types::roles::Role;
+
+#[derive(Accounts)]
+pub struct GrantRole<'info> {
+    #[account(
+        seeds = [
+            b"singleton"
+        ],
+        bump = singleton.bump,
+        has_one = authority
+    )]
+    pub singleton: Account<'info, Singleton>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = RoleMember::LEN,
+        seeds = [
+            b"role-member",
+            member.key().as_ref()
+        ],
+        bump
+    )]
+    pub role_member: Account<'info, RoleMember>,
+
+    /// CHECK: Any key can be granted a role
+    pub member: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn grant_role_handler(ctx: Context<GrantRole>, role: Role) -> Result<()> {
+    let role_member = &mut ctx.accounts.role_member;
+
+    role_member.member = ctx.accounts.member.key();
+    role_member.roles.grant(role);
+    role_member.bump = ctx.bumps.role_member;
+
+    Ok(())
+}
\ No newline at end of file
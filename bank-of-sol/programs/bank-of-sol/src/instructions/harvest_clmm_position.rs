@@ -0,0 +1,107 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount, Transfer, transfer};
+
+use crate::state::{singleton::Singleton, farm::{ClmmFarm, ClmmFarmPosition}};
+use crate::types::farm::pool_tick;
+use crate::types::pause::Subsystem;
+use crate::errors::ErrorCode;
+
+#[derive(Accounts)]
+pub struct HarvestClmmPosition<'info> {
+    #[account(
+        seeds = [
+            b"singleton"
+        ],
+        bump = singleton.bump
+    )]
+    pub singleton: Account<'info, Singleton>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"clmm-farm",
+            clmm_farm.pool.as_ref()
+        ],
+        bump = clmm_farm.bump,
+        has_one = reward_vault,
+        constraint = clmm_farm.pool == pool_state.key() @ ErrorCode::InvalidRaydiumPool
+    )]
+    pub clmm_farm: Account<'info, ClmmFarm>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"clmm-farm-position",
+            clmm_farm.key().as_ref(),
+            clmm_farm_position.nft_mint.as_ref()
+        ],
+        bump = clmm_farm_position.bump,
+        has_one = owner
+    )]
+    pub clmm_farm_position: Account<'info, ClmmFarmPosition>,
+
+    /// CHECK: Checked against the farm's pool and deserialized
+    pub pool_state: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub reward_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        token::mint = clmm_farm.reward_mint,
+        token::authority = owner
+    )]
+    pub receiver_account: Account<'info, TokenAccount>,
+
+    /// CHECK: This account is not read or written
+    #[account(
+        seeds = [
+            b"token-authority"
+        ],
+        bump = singleton.unit_auth_bump
+    )]
+    pub token_authority: UncheckedAccount<'info>,
+
+    pub owner: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> HarvestClmmPosition<'info> {
+    pub fn transfer_tokens_ctx(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        let cpi_accounts = Transfer {
+            from: self.reward_vault.to_account_info(),
+            to: self.receiver_account.to_account_info(),
+            authority: self.token_authority.to_account_info(),
+        };
+
+        let cpi_program = self.token_program.to_account_info();
+
+        CpiContext::new(cpi_program, cpi_accounts)
+    }
+}
+
+/// Syncs the position and pays out its rewards, up to the balance of the reward vault
+pub fn harvest_clmm_position_handler(ctx: Context<HarvestClmmPosition>) -> Result<()> {
+    ctx.accounts.singleton.pause.require_active(Subsystem::Claims)?;
+
+    let tick_current = pool_tick(&ctx.accounts.pool_state.to_account_info())?;
+    let unit_auth_bump = ctx.accounts.singleton.unit_auth_bump;
+    let available = ctx.accounts.reward_vault.amount;
+    let stake = &mut ctx.accounts.clmm_farm_position.stake;
+
+    // The owner synced the position, so any bounty is theirs
+    let bounty = stake.sync(&mut ctx.accounts.clmm_farm.rewards, tick_current, Clock::get()?.unix_timestamp);
+    stake.pending_rewards += bounty;
+
+    let reward = stake.pending_rewards.min(available);
+    stake.pending_rewards -= reward;
+
+    if reward > 0 {
+        let unit_auth_seed = &[&b"token-authority"[..], &[unit_auth_bump]];
+
+        transfer(ctx.accounts.transfer_tokens_ctx().with_signer(&[&unit_auth_seed[..]]), reward)?;
+    }
+
+    Ok(())
+}
\ No newline at end of file
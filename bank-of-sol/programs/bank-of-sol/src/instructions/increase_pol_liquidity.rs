@@ -0,0 +1,169 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token::{Mint, Token, TokenAccount},
+    associated_token::AssociatedToken,
+};
+use raydium_amm_v3::{cpi::accounts::IncreaseLiquidity, program::AmmV3};
+
+use crate::state::{singleton::Singleton, roles::RoleMember};
+use crate::types::pol::unit_is_token_0;
+use crate::types::roles::Role;
+use crate::errors::ErrorCode;
+
+#[derive(Accounts)]
+pub struct IncreasePolLiquidity<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"singleton"
+        ],
+        bump = singleton.bump,
+        has_one = unit_mint,
+        constraint = singleton.pool == pool_state.key() @ ErrorCode::InvalidRaydiumPool,
+        constraint = singleton.custody.unit_usdc_lp_custody == unit_usdc_lp_custody.key() @ ErrorCode::UnexpectedAccount
+    )]
+    pub singleton: Box<Account<'info, Singleton>>,
+
+    pub unit_mint: Box<Account<'info, Mint>>,
+
+    pub usdc_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = unit_mint,
+        associated_token::authority = token_authority
+    )]
+    pub unit_custody_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        associated_token::mint = usdc_mint,
+        associated_token::authority = token_authority
+    )]
+    pub usdc_custody_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        token::authority = token_authority
+    )]
+    pub unit_usdc_lp_custody: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK: This account is not read or written
+    #[account(
+        seeds = [
+            b"token-authority"
+        ],
+        bump = singleton.unit_auth_bump
+    )]
+    pub token_authority: UncheckedAccount<'info>,
+
+    /// CHECK: Checked against `singleton.pool`
+    #[account(mut)]
+    pub pool_state: UncheckedAccount<'info>,
+
+    /// CHECK: Checked by Raydium against the pool
+    #[account(mut)]
+    pub protocol_position: UncheckedAccount<'info>,
+
+    /// CHECK: Checked by Raydium against the position NFT
+    #[account(mut)]
+    pub personal_position: UncheckedAccount<'info>,
+
+    /// CHECK: Checked by Raydium against the position
+    #[account(mut)]
+    pub tick_array_lower: UncheckedAccount<'info>,
+
+    /// CHECK: Checked by Raydium against the position
+    #[account(mut)]
+    pub tick_array_upper: UncheckedAccount<'info>,
+
+    /// CHECK: Checked by Raydium against the pool
+    #[account(mut)]
+    pub token_vault_0: UncheckedAccount<'info>,
+
+    /// CHECK: Checked by Raydium against the pool
+    #[account(mut)]
+    pub token_vault_1: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [
+            b"role-member",
+            authority.key().as_ref()
+        ],
+        bump = role_member.bump,
+        constraint = singleton.has_role(&role_member, Role::Admin) @ ErrorCode::MissingRole
+    )]
+    pub role_member: Account<'info, RoleMember>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub clmm_program: Program<'info, AmmV3>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> IncreasePolLiquidity<'info> {
+    pub fn increase_liquidity_ctx(&self, unit_is_token_0: bool) -> CpiContext<'_, '_, '_, 'info, IncreaseLiquidity<'info>> {
+        let (token_account_0, token_account_1) = if unit_is_token_0 {
+            (self.unit_custody_account.to_account_info(), self.usdc_custody_account.to_account_info())
+        } else {
+            (self.usdc_custody_account.to_account_info(), self.unit_custody_account.to_account_info())
+        };
+
+        let cpi_accounts = IncreaseLiquidity {
+            nft_owner: self.token_authority.to_account_info(),
+            nft_account: self.unit_usdc_lp_custody.to_account_info(),
+            pool_state: self.pool_state.to_account_info(),
+            protocol_position: self.protocol_position.to_account_info(),
+            personal_position: self.personal_position.to_account_info(),
+            tick_array_lower: self.tick_array_lower.to_account_info(),
+            tick_array_upper: self.tick_array_upper.to_account_info(),
+            token_account_0,
+            token_account_1,
+            token_vault_0: self.token_vault_0.to_account_info(),
+            token_vault_1: self.token_vault_1.to_account_info(),
+            token_program: self.token_program.to_account_info(),
+        };
+
+        let cpi_program = self.clmm_program.to_account_info();
+
+        CpiContext::new(cpi_program, cpi_accounts)
+    }
+}
+
+/// Adds `liquidity` to the protocol's position, spending at most `units_max` of the Units available to POL and `usdc_max` of custodied USDC
+pub fn increase_pol_liquidity_handler(ctx: Context<IncreasePolLiquidity>, liquidity: u128, units_max: u64, usdc_max: u64) -> Result<()> {
+    ctx.accounts.singleton.pause.require_not_shutdown()?;
+
+    require_gte!(ctx.accounts.singleton.pol.available_units, units_max, ErrorCode::InsufficientPolUnits);
+
+    let unit_is_token_0 = unit_is_token_0(
+        &ctx.accounts.pool_state.to_account_info(),
+        ctx.accounts.unit_mint.key(),
+        ctx.accounts.usdc_mint.key()
+    )?;
+
+    let (amount_0_max, amount_1_max) = if unit_is_token_0 { (units_max, usdc_max) } else { (usdc_max, units_max) };
+
+    let units_before = ctx.accounts.unit_custody_account.amount;
+    let usdc_before = ctx.accounts.usdc_custody_account.amount;
+    let unit_auth_bump = ctx.accounts.singleton.unit_auth_bump;
+    let unit_auth_seed = &[&b"token-authority"[..], &[unit_auth_bump]];
+
+    raydium_amm_v3::cpi::increase_liquidity(
+        ctx.accounts.increase_liquidity_ctx(unit_is_token_0).with_signer(&[&unit_auth_seed[..]]),
+        liquidity,
+        amount_0_max,
+        amount_1_max
+    )?;
+
+    ctx.accounts.unit_custody_account.reload()?;
+    ctx.accounts.usdc_custody_account.reload()?;
+
+    let units = units_before - ctx.accounts.unit_custody_account.amount;
+    let usdc = usdc_before - ctx.accounts.usdc_custody_account.amount;
+
+    ctx.accounts.singleton.pol.liquidity_added(liquidity, units, usdc)
+}
\ No newline at end of file
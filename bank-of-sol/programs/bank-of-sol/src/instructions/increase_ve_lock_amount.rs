@@ -0,0 +1,88 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount, Transfer, transfer};
+
+use crate::state::{singleton::Singleton, ve::{VeSupply, VeLock}};
+use crate::types::ve::VePoint;
+use crate::errors::ErrorCode;
+
+#[derive(Accounts)]
+pub struct IncreaseVeLockAmount<'info> {
+    #[account(
+        seeds = [
+            b"singleton"
+        ],
+        bump = singleton.bump
+    )]
+    pub singleton: Box<Account<'info, Singleton>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"ve-supply"
+        ],
+        bump = ve_supply.bump,
+        has_one = vault
+    )]
+    pub ve_supply: Box<Account<'info, VeSupply>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"ve-lock",
+            owner.key().as_ref()
+        ],
+        bump = ve_lock.bump,
+        has_one = owner
+    )]
+    pub ve_lock: Account<'info, VeLock>,
+
+    #[account(mut)]
+    pub vault: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        token::mint = vault.mint,
+        token::authority = owner
+    )]
+    pub payer_account: Account<'info, TokenAccount>,
+
+    pub owner: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> IncreaseVeLockAmount<'info> {
+    pub fn transfer_tokens_ctx(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        let cpi_accounts = Transfer {
+            from: self.payer_account.to_account_info(),
+            to: self.vault.to_account_info(),
+            authority: self.owner.to_account_info(),
+        };
+
+        let cpi_program = self.token_program.to_account_info();
+
+        CpiContext::new(cpi_program, cpi_accounts)
+    }
+}
+
+/// Adds `amount` shares to an unexpired lock, keeping its end
+pub fn increase_ve_lock_amount_handler(ctx: Context<IncreaseVeLockAmount>, amount: u64) -> Result<()> {
+    ctx.accounts.singleton.pause.require_not_shutdown()?;
+
+    let now = Clock::get()?.unix_timestamp;
+    let ve_lock = &mut ctx.accounts.ve_lock;
+
+    require_gt!(ve_lock.point.end, now, ErrorCode::LockExpired);
+
+    let point = VePoint { amount: ve_lock.point.amount + amount, end: ve_lock.point.end };
+
+    let curve = &mut ctx.accounts.ve_supply.curve;
+    curve.checkpoint(now);
+    curve.update(&ve_lock.point, &point, now);
+
+    ve_lock.set_point(point, now);
+
+    transfer(ctx.accounts.transfer_tokens_ctx(), amount)?;
+
+    Ok(())
+}
\ No newline at end of file
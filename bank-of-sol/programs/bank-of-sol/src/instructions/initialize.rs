@@ -4,7 +4,10 @@ use anchor_spl::{
     associated_token::AssociatedToken,
 };
 
-use crate::{state::singleton::Singleton, types::epoch::Epoch};
+use raydium_amm_v3::states::PoolState;
+
+use crate::{state::{singleton::Singleton, treasury::Treasury}, types::{epoch::Epoch, share::ShareStorage, oracle::update_observations}};
+use crate::utils::account_deserialize;
 use crate::errors::ErrorCode;
 
 #[derive(Accounts)]
@@ -25,6 +28,7 @@ pub struct Initialize<'info> {
     )]
     pub collection: Account<'info, Mint>,
 
+    /// CHECK: Deserialized as a Raydium pool by the oracle
     pub raydium_pool: AccountInfo<'info>,
 
     #[account(
@@ -33,6 +37,7 @@ pub struct Initialize<'info> {
     )]
     pub unit_mint: Account<'info, Mint>,
 
+    /// CHECK: This account is not read or written
     #[account(
         seeds = [
             b"token-authority"
@@ -49,15 +54,72 @@ pub struct Initialize<'info> {
     )]
     pub unit_custody: Box<Account<'info, TokenAccount>>,
 
+    #[account(
+        init,
+        payer = creator,
+        seeds = [
+            b"share-mint"
+        ],
+        bump,
+        mint::decimals = unit_mint.decimals,
+        mint::authority = token_authority
+    )]
+    pub share_mint: Box<Account<'info, Mint>>,
+
     #[account(
         init_if_needed,
         payer = creator,
-        associated_token::mint = unit_usdc_lp_mint,
+        associated_token::mint = share_mint,
         associated_token::authority = token_authority
     )]
-    pub unit_usdc_lp_custody: Box<Account<'info, TokenAccount>>,
+    pub share_custody: Box<Account<'info, TokenAccount>>,
 
-    pub unit_usdc_lp_mint: AccountInfo<'info>,
+    #[account(
+        init,
+        payer = creator,
+        seeds = [
+            b"share-rewards"
+        ],
+        bump,
+        token::mint = share_mint,
+        token::authority = token_authority
+    )]
+    pub share_reward_vault: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        init,
+        payer = creator,
+        space = Treasury::LEN,
+        seeds = [
+            b"treasury"
+        ],
+        bump
+    )]
+    pub treasury: Box<Account<'info, Treasury>>,
+
+    #[account(
+        init,
+        payer = creator,
+        seeds = [
+            b"dao-fund"
+        ],
+        bump,
+        token::mint = unit_mint,
+        token::authority = token_authority
+    )]
+    pub dao_fund_vault: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        init,
+        payer = creator,
+        seeds = [
+            b"dev-fund"
+        ],
+        bump,
+        token::mint = unit_mint,
+        token::authority = token_authority
+    )]
+    pub dev_fund_vault: Box<Account<'info, TokenAccount>>,
 
     #[account(mut)]
     pub creator: Signer<'info>,
@@ -68,6 +130,7 @@ pub struct Initialize<'info> {
     pub token_program: Program<'info, Token>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
 }
 
 impl<'info> Initialize<'info> {
@@ -83,7 +146,9 @@ impl<'info> Initialize<'info> {
     }
 }
 
-pub fn initialize_handler(ctx: Context<Initialize>, minimum_period: i64) -> Result<()> {
+/// Creates the singleton along with the treasury, which advancing the epoch pays into. All seigniorage keeps going
+/// to the boardroom until the treasury split is configured
+pub fn initialize_handler(ctx: Context<Initialize>, minimum_period: i64, share_max_supply: u64) -> Result<()> {
     require_gte!(minimum_period, 0, ErrorCode::NegativePeriodValue);
 
     let token_authority = ctx.accounts.token_authority.key();
@@ -94,6 +159,12 @@ pub fn initialize_handler(ctx: Context<Initialize>, minimum_period: i64) -> Resu
         Some(token_authority)
     )?;
 
+    **ctx.accounts.treasury = Treasury::init(
+        ctx.accounts.dao_fund_vault.key(),
+        ctx.accounts.dev_fund_vault.key(),
+        ctx.bumps.treasury
+    );
+
     let singleton = &mut ctx.accounts.singleton;
 
     **singleton = Singleton::init(
@@ -103,10 +174,17 @@ pub fn initialize_handler(ctx: Context<Initialize>, minimum_period: i64) -> Resu
         ctx.accounts.unit_mint.key(),
         ctx.accounts.token_authority.key(),
         ctx.accounts.unit_custody.key(),
-        ctx.accounts.unit_usdc_lp_custody.key(),
+        ShareStorage::init(ctx.accounts.share_mint.key(), share_max_supply, ctx.accounts.share_reward_vault.key()),
         ctx.bumps.token_authority,
         ctx.bumps.singleton
     );
 
+    // The first observation starts the first epoch's TWAP window
+    let raydium_pool = ctx.accounts.raydium_pool.to_account_info();
+    let pool_state: PoolState = account_deserialize(&raydium_pool)?;
+    let a_to_b = pool_state.token_mint_0 == singleton.unit_mint;
+
+    update_observations(&Clock::get()?, a_to_b, &raydium_pool, &mut singleton.price_observations)?;
+
     Ok(())
 }
\ No newline at end of file
@@ -0,0 +1,92 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+use crate::state::{singleton::Singleton, gauge::GaugeController};
+use crate::types::gauge::EmissionsBudget;
+use crate::errors::ErrorCode;
+
+#[derive(Accounts)]
+pub struct InitializeGauges<'info> {
+    #[account(
+        seeds = [
+            b"singleton"
+        ],
+        bump = singleton.bump,
+        has_one = authority,
+        constraint = singleton.share.mint == share_mint.key() @ ErrorCode::UnexpectedAccount
+    )]
+    pub singleton: Box<Account<'info, Singleton>>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = GaugeController::LEN,
+        seeds = [
+            b"gauge-controller"
+        ],
+        bump
+    )]
+    pub gauge_controller: Box<Account<'info, GaugeController>>,
+
+    pub share_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        init,
+        payer = authority,
+        seeds = [
+            b"boardroom-share-rewards"
+        ],
+        bump,
+        token::mint = share_mint,
+        token::authority = token_authority
+    )]
+    pub boardroom_vault: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        init,
+        payer = authority,
+        seeds = [
+            b"nft-staking-share-rewards"
+        ],
+        bump,
+        token::mint = share_mint,
+        token::authority = token_authority
+    )]
+    pub nft_staking_vault: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK: This account is not read or written
+    #[account(
+        seeds = [
+            b"token-authority"
+        ],
+        bump = singleton.unit_auth_bump
+    )]
+    pub token_authority: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/// Creates the gauge controller and the vaults the boardroom and NFT staking gauges pay share rewards from
+pub fn initialize_gauges_handler(ctx: Context<InitializeGauges>) -> Result<()> {
+    let gauge_controller = &mut ctx.accounts.gauge_controller;
+    let epoch = ctx.accounts.singleton.epoch();
+
+    ***gauge_controller = GaugeController {
+        gauge_count: 0,
+        voting_epoch: epoch,
+        total_votes: 0,
+        previous_voting_epoch: epoch,
+        previous_total_votes: 0,
+        budget: EmissionsBudget::default(),
+        boardroom_vault: ctx.accounts.boardroom_vault.key(),
+        nft_staking_vault: ctx.accounts.nft_staking_vault.key(),
+        bump: ctx.bumps.gauge_controller,
+    };
+
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{singleton::Singleton, governance::Governance};
+use crate::types::governance::GovernanceParams;
+
+#[derive(Accounts)]
+pub struct InitializeGovernance<'info> {
+    #[account(
+        seeds = [
+            b"singleton"
+        ],
+        bump = singleton.bump,
+        has_one = authority
+    )]
+    pub singleton: Account<'info, Singleton>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = Governance::LEN,
+        seeds = [
+            b"governance"
+        ],
+        bump
+    )]
+    pub governance: Account<'info, Governance>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn initialize_governance_handler(
+    ctx: Context<InitializeGovernance>,
+    quorum: u64,
+    threshold: u64,
+    voting_period: i64
+) -> Result<()> {
+    let governance = &mut ctx.accounts.governance;
+
+    **governance = Governance {
+        params: GovernanceParams::init(quorum, threshold, voting_period)?,
+        proposal_count: 0,
+        bump: ctx.bumps.governance,
+    };
+
+    Ok(())
+}
\ No newline at end of file
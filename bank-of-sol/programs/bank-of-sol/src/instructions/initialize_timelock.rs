@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{singleton::Singleton, timelock::Timelock};
+use crate::types::timelock::validate_delay;
+
+#[derive(Accounts)]
+pub struct InitializeTimelock<'info> {
+    #[account(
+        seeds = [
+            b"singleton"
+        ],
+        bump = singleton.bump,
+        has_one = authority
+    )]
+    pub singleton: Account<'info, Singleton>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = Timelock::LEN,
+        seeds = [
+            b"timelock"
+        ],
+        bump
+    )]
+    pub timelock: Account<'info, Timelock>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn initialize_timelock_handler(ctx: Context<InitializeTimelock>, delay: i64) -> Result<()> {
+    validate_delay(delay)?;
+
+    let timelock = &mut ctx.accounts.timelock;
+
+    **timelock = Timelock {
+        delay,
+        action_count: 0,
+        bump: ctx.bumps.timelock,
+        valid_from: 0,
+    };
+
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+use crate::state::{singleton::Singleton, ve::VeSupply};
+use crate::types::ve::SupplyCurve;
+use crate::errors::ErrorCode;
+
+#[derive(Accounts)]
+pub struct InitializeVe<'info> {
+    #[account(
+        seeds = [
+            b"singleton"
+        ],
+        bump = singleton.bump,
+        has_one = authority,
+        constraint = singleton.share.mint == share_mint.key() @ ErrorCode::UnexpectedAccount
+    )]
+    pub singleton: Box<Account<'info, Singleton>>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = VeSupply::LEN,
+        seeds = [
+            b"ve-supply"
+        ],
+        bump
+    )]
+    pub ve_supply: Box<Account<'info, VeSupply>>,
+
+    pub share_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        init,
+        payer = authority,
+        seeds = [
+            b"ve-vault"
+        ],
+        bump,
+        token::mint = share_mint,
+        token::authority = token_authority
+    )]
+    pub ve_vault: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK: This account is not read or written
+    #[account(
+        seeds = [
+            b"token-authority"
+        ],
+        bump = singleton.unit_auth_bump
+    )]
+    pub token_authority: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn initialize_ve_handler(ctx: Context<InitializeVe>) -> Result<()> {
+    let ve_supply = &mut ctx.accounts.ve_supply;
+
+    ***ve_supply = VeSupply {
+        curve: SupplyCurve::init(Clock::get()?.unix_timestamp),
+        vault: ctx.accounts.ve_vault.key(),
+        bump: ctx.bumps.ve_supply,
+    };
+
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,77 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{singleton::Singleton, bond_account::{BondAccount, BondAccountsStore}};
+use crate::errors::ErrorCode;
+
+#[derive(Accounts)]
+#[instruction(_seed_index: u64, _source_seed_index: u64)]
+pub struct MergeBondAccounts<'info> {
+    #[account(
+        seeds = [
+            b"singleton"
+        ],
+        bump = singleton.bump
+    )]
+    pub singleton: Box<Account<'info, Singleton>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"bond-account",
+            signer.key().as_ref(),
+            _seed_index.to_be_bytes().as_ref()
+        ],
+        bump = bond_account.bump,
+        constraint = bond_account.user == signer.key() @ ErrorCode::UnexpectedAccount,
+        constraint = !bond_account.is_tokenized() @ ErrorCode::BondAccountTokenized,
+        constraint = _seed_index <= bond_accounts_store.get_index_of_accounts() @ ErrorCode::InvalidSeedIndex,
+        constraint = _seed_index > bond_accounts_store.get_offset() @ ErrorCode::InvalidSeedIndex,
+        constraint = _seed_index != _source_seed_index @ ErrorCode::InvalidSeedIndex
+    )]
+    pub bond_account: Account<'info, BondAccount>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"bond-account",
+            signer.key().as_ref(),
+            _source_seed_index.to_be_bytes().as_ref()
+        ],
+        bump = source_bond_account.bump,
+        close = signer,
+        constraint = source_bond_account.user == signer.key() @ ErrorCode::UnexpectedAccount,
+        constraint = !source_bond_account.is_tokenized() @ ErrorCode::BondAccountTokenized
+    )]
+    pub source_bond_account: Account<'info, BondAccount>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"bond-accounts-store",
+            signer.key().as_ref()
+        ],
+        bump = bond_accounts_store.bump
+    )]
+    pub bond_accounts_store: Account<'info, BondAccountsStore>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+}
+
+/// Merges the source bond account into another with the same epoch and rate and an adjacent place in the queue,
+/// closing the source
+pub fn merge_bond_accounts_handler(ctx: Context<MergeBondAccounts>, _seed_index: u64, source_seed_index: u64) -> Result<()> {
+    ctx.accounts.singleton.pause.require_not_shutdown()?;
+
+    let bond_accounts_store = &mut ctx.accounts.bond_accounts_store;
+
+    if source_seed_index == bond_accounts_store.get_oldest() {
+        bond_accounts_store.pda_closed();
+    } else if source_seed_index == bond_accounts_store.get_index_of_accounts() {
+        bond_accounts_store.newest_closed();
+    } else {
+        return err!(ErrorCode::InvalidMergeSource);
+    }
+
+    ctx.accounts.bond_account.merge(&ctx.accounts.source_bond_account)
+}
\ No newline at end of file
@@ -8,4 +8,71 @@ pub mod create_stake_record_index;
 pub mod claim_staking_reward;
 pub mod unstake_nft;
 pub mod boardroom_deposit;
-pub mod boardroom_withdraw;
\ No newline at end of file
+pub mod boardroom_withdraw;
+pub mod sync_boardroom_boost;
+pub mod advance_epoch;
+pub mod get_interest_rate;
+pub mod boardroom_claim;
+pub mod redeem_bonds;
+pub mod forfeit_bonds;
+pub mod create_bond_mint;
+pub mod purchase_bond_tokens;
+pub mod redeem_bond_tokens;
+pub mod tokenize_bond_account;
+pub mod redeem_bond_nft;
+pub mod split_bond_account;
+pub mod merge_bond_accounts;
+pub mod zap_purchase_bonds;
+pub mod open_pol_position;
+pub mod increase_pol_liquidity;
+pub mod decrease_pol_liquidity;
+pub mod spend_treasury;
+pub mod create_vesting_schedule;
+pub mod claim_vesting;
+pub mod revoke_vesting_schedule;
+pub mod change_vesting_beneficiary;
+pub mod emit_shares;
+pub mod create_farm;
+pub mod allocate_share_rewards;
+pub mod create_farm_position;
+pub mod farm_deposit;
+pub mod farm_withdraw;
+pub mod farm_harvest;
+pub mod farm_emergency_withdraw;
+pub mod fund_farm;
+pub mod create_clmm_farm;
+pub mod stake_clmm_position;
+pub mod sync_clmm_position;
+pub mod harvest_clmm_position;
+pub mod unstake_clmm_position;
+pub mod initialize_ve;
+pub mod create_ve_lock;
+pub mod increase_ve_lock_amount;
+pub mod extend_ve_lock;
+pub mod withdraw_ve_lock;
+pub mod checkpoint_ve_supply;
+pub mod initialize_gauges;
+pub mod create_gauge;
+pub mod vote_gauge;
+pub mod distribute_gauge_emissions;
+pub mod boardroom_claim_shares;
+pub mod claim_nft_staking_shares;
+pub mod initialize_governance;
+pub mod create_proposal;
+pub mod vote_proposal;
+pub mod execute_proposal;
+pub mod initialize_timelock;
+pub mod queue_action;
+pub mod cancel_action;
+pub mod execute_action;
+pub mod grant_role;
+pub mod revoke_role;
+pub mod configure_nft_staking;
+pub mod set_nft_staking_status;
+pub mod record_price_observation;
+pub mod propose_authority;
+pub mod accept_authority;
+pub mod cancel_authority_transfer;
+pub mod renounce_authority;
+pub mod set_subsystem_paused;
+pub mod set_emergency_shutdown;
\ No newline at end of file
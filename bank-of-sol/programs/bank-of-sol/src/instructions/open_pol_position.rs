@@ -0,0 +1,243 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{Transfer, transfer};
+use anchor_spl::{
+    token::{Mint, Token, TokenAccount},
+    associated_token::{AssociatedToken, get_associated_token_address},
+    metadata::Metadata,
+};
+use raydium_amm_v3::{cpi::accounts::OpenPosition, program::AmmV3};
+
+use crate::state::{singleton::Singleton, roles::RoleMember};
+use crate::types::pol::unit_is_token_0;
+use crate::types::roles::Role;
+use crate::errors::ErrorCode;
+
+/**
+ * Opening the POL position
+ *
+ * Raydium opens the position with token authority as the payer, since the payer is also the authority
+ * over the Units and USDC added. Token authority is lent `rent` lamports by the authority to pay for the
+ * accounts Raydium creates, and returns whatever is left once the position is open. The position NFT
+ * is minted into token authority's associated token account, which becomes `unit_usdc_lp_custody`.
+ */
+#[derive(Accounts)]
+pub struct OpenPolPosition<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"singleton"
+        ],
+        bump = singleton.bump,
+        has_one = unit_mint,
+        constraint = singleton.pool == pool_state.key() @ ErrorCode::InvalidRaydiumPool
+    )]
+    pub singleton: Box<Account<'info, Singleton>>,
+
+    pub unit_mint: Box<Account<'info, Mint>>,
+
+    pub usdc_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = unit_mint,
+        associated_token::authority = token_authority
+    )]
+    pub unit_custody_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        associated_token::mint = usdc_mint,
+        associated_token::authority = token_authority
+    )]
+    pub usdc_custody_account: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK: Pays for and owns the position
+    #[account(
+        mut,
+        seeds = [
+            b"token-authority"
+        ],
+        bump = singleton.unit_auth_bump
+    )]
+    pub token_authority: UncheckedAccount<'info>,
+
+    /// The position NFT mint, created by Raydium
+    #[account(mut)]
+    pub position_nft_mint: Signer<'info>,
+
+    /// CHECK: Created by Raydium as token authority's associated token account
+    #[account(
+        mut,
+        address = get_associated_token_address(&token_authority.key(), &position_nft_mint.key()) @ ErrorCode::UnexpectedAccount
+    )]
+    pub position_nft_account: UncheckedAccount<'info>,
+
+    /// CHECK: Created by Raydium through the token metadata program
+    #[account(mut)]
+    pub metadata_account: UncheckedAccount<'info>,
+
+    /// CHECK: Checked against `singleton.pool`
+    #[account(mut)]
+    pub pool_state: UncheckedAccount<'info>,
+
+    /// CHECK: Checked by Raydium against the pool
+    #[account(mut)]
+    pub protocol_position: UncheckedAccount<'info>,
+
+    /// CHECK: Created by Raydium for the position NFT
+    #[account(mut)]
+    pub personal_position: UncheckedAccount<'info>,
+
+    /// CHECK: Checked by Raydium against the position
+    #[account(mut)]
+    pub tick_array_lower: UncheckedAccount<'info>,
+
+    /// CHECK: Checked by Raydium against the position
+    #[account(mut)]
+    pub tick_array_upper: UncheckedAccount<'info>,
+
+    /// CHECK: Checked by Raydium against the pool
+    #[account(mut)]
+    pub token_vault_0: UncheckedAccount<'info>,
+
+    /// CHECK: Checked by Raydium against the pool
+    #[account(mut)]
+    pub token_vault_1: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [
+            b"role-member",
+            authority.key().as_ref()
+        ],
+        bump = role_member.bump,
+        constraint = singleton.has_role(&role_member, Role::Admin) @ ErrorCode::MissingRole
+    )]
+    pub role_member: Account<'info, RoleMember>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub clmm_program: Program<'info, AmmV3>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub metadata_program: Program<'info, Metadata>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+impl<'info> OpenPolPosition<'info> {
+    pub fn transfer_lamports_ctx(&self, from: &AccountInfo<'info>, to: &AccountInfo<'info>) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        let cpi_accounts = Transfer {
+            from: from.clone(),
+            to: to.clone(),
+        };
+
+        let cpi_program = self.system_program.to_account_info();
+
+        CpiContext::new(cpi_program, cpi_accounts)
+    }
+
+    pub fn open_position_ctx(&self, unit_is_token_0: bool) -> CpiContext<'_, '_, '_, 'info, OpenPosition<'info>> {
+        let (token_account_0, token_account_1) = if unit_is_token_0 {
+            (self.unit_custody_account.to_account_info(), self.usdc_custody_account.to_account_info())
+        } else {
+            (self.usdc_custody_account.to_account_info(), self.unit_custody_account.to_account_info())
+        };
+
+        let cpi_accounts = OpenPosition {
+            payer: self.token_authority.to_account_info(),
+            position_nft_owner: self.token_authority.to_account_info(),
+            position_nft_mint: self.position_nft_mint.to_account_info(),
+            position_nft_account: self.position_nft_account.to_account_info(),
+            metadata_account: self.metadata_account.to_account_info(),
+            pool_state: self.pool_state.to_account_info(),
+            protocol_position: self.protocol_position.to_account_info(),
+            tick_array_lower: self.tick_array_lower.to_account_info(),
+            tick_array_upper: self.tick_array_upper.to_account_info(),
+            personal_position: self.personal_position.to_account_info(),
+            token_account_0,
+            token_account_1,
+            token_vault_0: self.token_vault_0.to_account_info(),
+            token_vault_1: self.token_vault_1.to_account_info(),
+            rent: self.rent.to_account_info(),
+            system_program: self.system_program.to_account_info(),
+            token_program: self.token_program.to_account_info(),
+            associated_token_program: self.associated_token_program.to_account_info(),
+            metadata_program: self.metadata_program.to_account_info(),
+        };
+
+        let cpi_program = self.clmm_program.to_account_info();
+
+        CpiContext::new(cpi_program, cpi_accounts)
+    }
+}
+
+/// Opens the protocol's position on `[tick_lower_index, tick_upper_index)` with `liquidity`, spending at most `units_max`
+/// of the Units available to POL and `usdc_max` of custodied USDC. `rent` is lent to token authority for the position's accounts
+#[allow(clippy::too_many_arguments)]
+pub fn open_pol_position_handler(
+    ctx: Context<OpenPolPosition>,
+    tick_lower_index: i32,
+    tick_upper_index: i32,
+    tick_array_lower_start_index: i32,
+    tick_array_upper_start_index: i32,
+    liquidity: u128,
+    units_max: u64,
+    usdc_max: u64,
+    rent: u64
+) -> Result<()> {
+    ctx.accounts.singleton.pause.require_not_shutdown()?;
+
+    require_gte!(ctx.accounts.singleton.pol.available_units, units_max, ErrorCode::InsufficientPolUnits);
+
+    let unit_is_token_0 = unit_is_token_0(
+        &ctx.accounts.pool_state.to_account_info(),
+        ctx.accounts.unit_mint.key(),
+        ctx.accounts.usdc_mint.key()
+    )?;
+
+    let (amount_0_max, amount_1_max) = if unit_is_token_0 { (units_max, usdc_max) } else { (usdc_max, units_max) };
+
+    let authority = ctx.accounts.authority.to_account_info();
+    let token_authority = ctx.accounts.token_authority.to_account_info();
+    let lamports_before = token_authority.lamports();
+    let units_before = ctx.accounts.unit_custody_account.amount;
+    let usdc_before = ctx.accounts.usdc_custody_account.amount;
+    let unit_auth_bump = ctx.accounts.singleton.unit_auth_bump;
+    let unit_auth_seed = &[&b"token-authority"[..], &[unit_auth_bump]];
+
+    transfer(ctx.accounts.transfer_lamports_ctx(&authority, &token_authority), rent)?;
+
+    raydium_amm_v3::cpi::open_position(
+        ctx.accounts.open_position_ctx(unit_is_token_0).with_signer(&[&unit_auth_seed[..]]),
+        tick_lower_index,
+        tick_upper_index,
+        tick_array_lower_start_index,
+        tick_array_upper_start_index,
+        liquidity,
+        amount_0_max,
+        amount_1_max
+    )?;
+
+    // Return the rent that wasn't needed
+    let unspent = token_authority.lamports().saturating_sub(lamports_before);
+
+    if unspent > 0 {
+        transfer(
+            ctx.accounts.transfer_lamports_ctx(&token_authority, &authority).with_signer(&[&unit_auth_seed[..]]),
+            unspent
+        )?;
+    }
+
+    ctx.accounts.unit_custody_account.reload()?;
+    ctx.accounts.usdc_custody_account.reload()?;
+
+    let units = units_before - ctx.accounts.unit_custody_account.amount;
+    let usdc = usdc_before - ctx.accounts.usdc_custody_account.amount;
+    let position_nft_account = ctx.accounts.position_nft_account.key();
+    let singleton = &mut ctx.accounts.singleton;
+
+    singleton.custody.set_unit_usdc_lp_custody(position_nft_account)?;
+    singleton.pol.liquidity_added(liquidity, units, usdc)
+}
\ No newline at end of file
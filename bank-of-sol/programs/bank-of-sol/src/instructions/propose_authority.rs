@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+
+use crate::state::singleton::Singleton;
+use crate::events::AuthorityProposed;
+use crate::errors::ErrorCode;
+
+#[derive(Accounts)]
+pub struct ProposeAuthority<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"singleton"
+        ],
+        bump = singleton.bump,
+        has_one = authority
+    )]
+    pub singleton: Account<'info, Singleton>,
+
+    pub authority: Signer<'info>,
+}
+
+/// Proposes a new authority, which takes over once it accepts. Proposing again replaces the pending authority
+pub fn propose_authority_handler(ctx: Context<ProposeAuthority>, new_authority: Pubkey) -> Result<()> {
+    require_keys_neq!(new_authority, Pubkey::default(), ErrorCode::InvalidAuthority);
+
+    let singleton = &mut ctx.accounts.singleton;
+    singleton.pending_authority = new_authority;
+
+    emit!(AuthorityProposed {
+        authority: singleton.authority,
+        pending_authority: new_authority,
+    });
+
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,116 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token::{Mint, Token, TokenAccount, Burn, MintTo, burn, mint_to},
+    associated_token::AssociatedToken,
+};
+
+use crate::state::singleton::Singleton;
+use crate::types::pause::Subsystem;
+use crate::errors::ErrorCode;
+
+#[derive(Accounts)]
+pub struct PurchaseBondTokens<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"singleton"
+        ],
+        bump = singleton.bump,
+        has_one = unit_mint,
+        constraint = singleton.bonds.bond_mint == bond_mint.key() @ ErrorCode::UnexpectedAccount
+    )]
+    pub singleton: Account<'info, Singleton>,
+
+    #[account(mut)]
+    pub unit_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        mint::authority = token_authority
+    )]
+    pub bond_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = unit_mint,
+        associated_token::authority = signer
+    )]
+    pub unit_payer_account: Account<'info, TokenAccount>,
+
+    #[account(
+        associated_token::mint = unit_mint,
+        associated_token::authority = token_authority
+    )]
+    pub unit_custody_account: Account<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = signer,
+        associated_token::mint = bond_mint,
+        associated_token::authority = signer
+    )]
+    pub bond_receiver_account: Account<'info, TokenAccount>,
+
+    /// CHECK: This account is not read or written
+    #[account(
+        seeds = [
+            b"token-authority"
+        ],
+        bump = singleton.unit_auth_bump
+    )]
+    pub token_authority: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> PurchaseBondTokens<'info> {
+    pub fn burn_tokens_ctx(&self) -> CpiContext<'_, '_, '_, 'info, Burn<'info>> {
+        let cpi_accounts = Burn {
+            mint: self.unit_mint.to_account_info(),
+            from: self.unit_payer_account.to_account_info(),
+            authority: self.signer.to_account_info(),
+        };
+
+        let cpi_program = self.token_program.to_account_info();
+
+        CpiContext::new(cpi_program, cpi_accounts)
+    }
+
+    pub fn mint_bonds_ctx(&self) -> CpiContext<'_, '_, '_, 'info, MintTo<'info>> {
+        let cpi_accounts = MintTo {
+            mint: self.bond_mint.to_account_info(),
+            to: self.bond_receiver_account.to_account_info(),
+            authority: self.token_authority.to_account_info(),
+        };
+
+        let cpi_program = self.token_program.to_account_info();
+
+        CpiContext::new(cpi_program, cpi_accounts)
+    }
+}
+
+pub fn purchase_bond_tokens_handler(ctx: Context<PurchaseBondTokens>, units: u64) -> Result<()> {
+    ctx.accounts.singleton.pause.require_active(Subsystem::BondPurchase)?;
+
+    require!(ctx.accounts.singleton.bonds.token_mode, ErrorCode::BondTokenModeDisabled);
+    require!(!ctx.accounts.singleton.bonds.terms.is_active(), ErrorCode::BondTermsActive);
+
+    let circulating_supply = ctx.accounts.unit_mint.supply - ctx.accounts.unit_custody_account.amount;
+    let singleton = &mut ctx.accounts.singleton;
+    let unit_auth_bump = singleton.unit_auth_bump;
+
+    let (amount, _) = singleton.sell_bonds(units, circulating_supply, Clock::get()?.unix_timestamp)?;
+    singleton.bonds.bond_tokens_minted(amount);
+
+    let unit_auth_seed = &[&b"token-authority"[..], &[unit_auth_bump]];
+
+    burn(ctx.accounts.burn_tokens_ctx(), units)?;
+    mint_to(ctx.accounts.mint_bonds_ctx().with_signer(&[&unit_auth_seed[..]]), amount)?;
+
+    Ok(())
+}
\ No newline at end of file
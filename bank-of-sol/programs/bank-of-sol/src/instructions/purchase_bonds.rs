@@ -1,6 +1,11 @@
 use anchor_lang::prelude::*;
+use anchor_spl::{
+    token::{Mint, Token, TokenAccount, Burn, burn},
+    associated_token::AssociatedToken,
+};
 
 use crate::state::{bond_account::{BondAccount, BondAccountsStore}, singleton::Singleton};
+use crate::types::pause::Subsystem;
 use crate::errors::ErrorCode;
 
 #[derive(Accounts)]
@@ -15,8 +20,8 @@ pub struct PurchaseBonds<'info> {
         ],
         bump = bond_account.bump,
         constraint = bond_account.bonds.epoch == 0 || bond_account.bonds.epoch == singleton.epoch() @ ErrorCode::BondAccountInvalidEpoch,
-        constraint = _seed_index <= bond_accounts_store.get_index_of_accounts() @ ErrorCode::InvalidSeedIndex,
-        constraint = _seed_index > bond_accounts_store.get_offset() @ ErrorCode::InvalidSeedIndex
+        constraint = !bond_account.is_tokenized() @ ErrorCode::BondAccountTokenized,
+        constraint = _seed_index == bond_accounts_store.get_index_of_accounts() @ ErrorCode::InvalidSeedIndex
     )]
     pub bond_account: Account<'info, BondAccount>,
 
@@ -35,30 +40,71 @@ pub struct PurchaseBonds<'info> {
         seeds = [
             b"singleton"
         ],
-        bump = singleton.bump
+        bump = singleton.bump,
+        has_one = unit_mint
     )]
     pub singleton: Account<'info, Singleton>,
 
+    #[account(mut)]
+    pub unit_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = unit_mint,
+        associated_token::authority = signer
+    )]
+    pub unit_payer_account: Account<'info, TokenAccount>,
+
+    #[account(
+        associated_token::mint = unit_mint,
+        associated_token::authority = token_authority
+    )]
+    pub unit_custody_account: Account<'info, TokenAccount>,
+
+    /// CHECK: This account is not read or written
+    #[account(
+        seeds = [
+            b"token-authority"
+        ],
+        bump = singleton.unit_auth_bump
+    )]
+    pub token_authority: UncheckedAccount<'info>,
+
     pub signer: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+impl<'info> PurchaseBonds<'info> {
+    pub fn burn_tokens_ctx(&self) -> CpiContext<'_, '_, '_, 'info, Burn<'info>> {
+        let cpi_accounts = Burn {
+            mint: self.unit_mint.to_account_info(),
+            from: self.unit_payer_account.to_account_info(),
+            authority: self.signer.to_account_info(),
+        };
+
+        let cpi_program = self.token_program.to_account_info();
+
+        CpiContext::new(cpi_program, cpi_accounts)
+    }
 }
 
 pub fn purchase_bonds_handler(ctx: Context<PurchaseBonds>, _seed_index: u64, units: u64) -> Result<()> {
+    ctx.accounts.singleton.pause.require_active(Subsystem::BondPurchase)?;
+
+    require!(!ctx.accounts.singleton.bonds.token_mode, ErrorCode::BondTokenModeEnabled);
+
+    let circulating_supply = ctx.accounts.unit_mint.supply - ctx.accounts.unit_custody_account.amount;
     let bond_account = &mut ctx.accounts.bond_account;
     let singleton = &mut ctx.accounts.singleton;
 
-    let amount = singleton.units_to_bonds(units);
-    let rate = singleton.interest_rate();
-    let epoch = singleton.epoch();
-
-    if bond_account.bonds.amount == 0 {
-        bond_account.index = singleton.bonds.bonds_purchased;
-    }
+    let index = singleton.bonds.total_bonds_purchased;
+    let (amount, rate) = singleton.sell_bonds(units, circulating_supply, Clock::get()?.unix_timestamp)?;
 
-    bond_account.bonds.epoch = epoch;
-    bond_account.bonds.interest_rate = rate;
-    bond_account.bonds.amount += amount;
+    bond_account.record_purchase(amount, rate, singleton.epoch(), index)?;
 
-    singleton.bonds_purchased(amount);
+    burn(ctx.accounts.burn_tokens_ctx(), units)?;
 
     Ok(())
 }
\ No newline at end of file
@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{singleton::Singleton, timelock::{Timelock, PendingAction}, roles::RoleMember};
+use crate::types::timelock::TimelockAction;
+use crate::types::roles::Role;
+use crate::errors::ErrorCode;
+
+#[derive(Accounts)]
+pub struct QueueAction<'info> {
+    #[account(
+        seeds = [
+            b"singleton"
+        ],
+        bump = singleton.bump
+    )]
+    pub singleton: Box<Account<'info, Singleton>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"timelock"
+        ],
+        bump = timelock.bump
+    )]
+    pub timelock: Account<'info, Timelock>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = PendingAction::LEN,
+        seeds = [
+            b"pending-action",
+            timelock.action_count.to_be_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub pending_action: Box<Account<'info, PendingAction>>,
+
+    #[account(
+        seeds = [
+            b"role-member",
+            authority.key().as_ref()
+        ],
+        bump = role_member.bump,
+        constraint = singleton.has_role(&role_member, Role::Admin) @ ErrorCode::MissingRole
+    )]
+    pub role_member: Account<'info, RoleMember>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Queues an admin action, executable by anyone once the timelock's delay has passed
+pub fn queue_action_handler(ctx: Context<QueueAction>, action: TimelockAction) -> Result<()> {
+    action.validate()?;
+
+    let clock = Clock::get()?;
+    let pending_action = &mut ctx.accounts.pending_action;
+
+    ***pending_action = PendingAction::queue(
+        &mut ctx.accounts.timelock,
+        action,
+        clock.unix_timestamp,
+        ctx.accounts.authority.key(),
+        ctx.bumps.pending_action
+    );
+
+    Ok(())
+}
\ No newline at end of file
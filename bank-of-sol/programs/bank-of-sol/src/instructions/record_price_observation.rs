@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+use raydium_amm_v3::states::PoolState;
+
+use crate::state::{singleton::Singleton, roles::RoleMember};
+use crate::types::{oracle::update_observations, roles::Role};
+use crate::utils::account_deserialize;
+use crate::errors::ErrorCode;
+
+#[derive(Accounts)]
+pub struct RecordPriceObservation<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"singleton"
+        ],
+        bump = singleton.bump,
+        constraint = singleton.pool == raydium_pool.key() @ ErrorCode::InvalidRaydiumPool
+    )]
+    pub singleton: Account<'info, Singleton>,
+
+    /// CHECK: Checked against `singleton.pool` and deserialized by the oracle
+    pub raydium_pool: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [
+            b"role-member",
+            authority.key().as_ref()
+        ],
+        bump = role_member.bump,
+        constraint = singleton.has_role(&role_member, Role::OracleKeeper) @ ErrorCode::MissingRole
+    )]
+    pub role_member: Account<'info, RoleMember>,
+
+    pub authority: Signer<'info>,
+}
+
+/// Records a price observation between epoch advances, so the TWAP isn't only sampled when advancing.
+/// Observations must be at least `MIN_OBSERVATION_SPACING` apart
+pub fn record_price_observation_handler(ctx: Context<RecordPriceObservation>) -> Result<()> {
+    ctx.accounts.singleton.pause.require_not_shutdown()?;
+
+    let clock = Clock::get()?;
+    let raydium_pool = ctx.accounts.raydium_pool.to_account_info();
+    let singleton = &mut ctx.accounts.singleton;
+
+    let pool_state: PoolState = account_deserialize(&raydium_pool)?;
+    let a_to_b = pool_state.token_mint_0 == singleton.unit_mint;
+
+    require!(
+        singleton.price_observations.spaced(clock.unix_timestamp as u64),
+        ErrorCode::ObservationTooSoon
+    );
+
+    update_observations(&clock, a_to_b, &raydium_pool, &mut singleton.price_observations)?;
+
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,158 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token::{Mint, Token, TokenAccount, MintTo, Burn, Transfer, mint_to, burn, transfer},
+    associated_token::AssociatedToken,
+};
+
+use crate::state::{bond_account::BondAccount, singleton::Singleton};
+use crate::types::pause::Subsystem;
+use crate::errors::ErrorCode;
+
+/// Redeems a tokenized bond account on behalf of the holder of its NFT rather than `bond_account.user`.
+/// The NFT is burnt and the bond account closed to its original user once the bonds are fully redeemed,
+/// `redeem_bonds` skips the closed account when it becomes the user's oldest.
+#[derive(Accounts)]
+#[instruction(_seed_index: u64)]
+pub struct RedeemBondNft<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"bond-account",
+            bond_account.user.as_ref(),
+            _seed_index.to_be_bytes().as_ref()
+        ],
+        bump = bond_account.bump,
+        constraint = bond_account.nft_mint == bond_nft_mint.key() @ ErrorCode::UnexpectedAccount
+    )]
+    pub bond_account: Box<Account<'info, BondAccount>>,
+
+    #[account(mut)]
+    pub bond_nft_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = bond_nft_mint,
+        associated_token::authority = signer,
+        constraint = bond_nft_account.amount == 1 @ ErrorCode::TokenAccountEmpty
+    )]
+    pub bond_nft_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"singleton"
+        ],
+        bump = singleton.bump,
+        has_one = unit_mint
+    )]
+    pub singleton: Account<'info, Singleton>,
+
+    #[account(
+        mut,
+        mint::authority = token_authority
+    )]
+    pub unit_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = unit_mint,
+        associated_token::authority = token_authority
+    )]
+    pub unit_custody_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = signer,
+        associated_token::mint = unit_mint,
+        associated_token::authority = signer
+    )]
+    pub unit_receiver_account: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK: This account is not read or written
+    #[account(
+        seeds = [
+            b"token-authority"
+        ],
+        bump = singleton.unit_auth_bump
+    )]
+    pub token_authority: UncheckedAccount<'info>,
+
+    /// CHECK: Only receives the rent of the bond account once it's empty
+    #[account(
+        mut,
+        address = bond_account.user @ ErrorCode::UnexpectedAccount
+    )]
+    pub owner: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> RedeemBondNft<'info> {
+    pub fn transfer_reserve_ctx(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        let cpi_accounts = Transfer {
+            from: self.unit_custody_account.to_account_info(),
+            to: self.unit_receiver_account.to_account_info(),
+            authority: self.token_authority.to_account_info(),
+        };
+
+        let cpi_program = self.token_program.to_account_info();
+
+        CpiContext::new(cpi_program, cpi_accounts)
+    }
+
+    pub fn mint_token_ctx(&self) -> CpiContext<'_, '_, '_, 'info, MintTo<'info>> {
+        let cpi_accounts = MintTo {
+            mint: self.unit_mint.to_account_info(),
+            to: self.unit_receiver_account.to_account_info(),
+            authority: self.token_authority.to_account_info(),
+        };
+
+        let cpi_program = self.token_program.to_account_info();
+
+        CpiContext::new(cpi_program, cpi_accounts)
+    }
+
+    pub fn burn_nft_ctx(&self) -> CpiContext<'_, '_, '_, 'info, Burn<'info>> {
+        let cpi_accounts = Burn {
+            mint: self.bond_nft_mint.to_account_info(),
+            from: self.bond_nft_account.to_account_info(),
+            authority: self.signer.to_account_info(),
+        };
+
+        let cpi_program = self.token_program.to_account_info();
+
+        CpiContext::new(cpi_program, cpi_accounts)
+    }
+}
+
+pub fn redeem_bond_nft_handler(ctx: Context<RedeemBondNft>, _seed_index: u64, amount: u64) -> Result<()> {
+    ctx.accounts.singleton.pause.require_active(Subsystem::BondRedemption)?;
+
+    let singleton = &mut ctx.accounts.singleton;
+    let bond_account = &mut ctx.accounts.bond_account;
+    let unit_auth_bump = singleton.unit_auth_bump;
+
+    let units = singleton.redeem_bonds(bond_account, amount)?;
+
+    let unit_auth_seed = &[&b"token-authority"[..], &[unit_auth_bump]];
+
+    // The face value was reserved when the bonds were funded, only the premium is minted
+    transfer(ctx.accounts.transfer_reserve_ctx().with_signer(&[&unit_auth_seed[..]]), amount)?;
+
+    if units > amount {
+        mint_to(ctx.accounts.mint_token_ctx().with_signer(&[&unit_auth_seed[..]]), units - amount)?;
+    }
+
+    // The owner skips the closed account when it becomes their oldest
+    if ctx.accounts.bond_account.bonds.amount == 0 {
+        burn(ctx.accounts.burn_nft_ctx(), 1)?;
+        ctx.accounts.bond_account.close(ctx.accounts.owner.to_account_info())?;
+    }
+
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,136 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token::{Mint, Token, TokenAccount, Burn, MintTo, Transfer, burn, mint_to, transfer},
+    associated_token::AssociatedToken,
+};
+
+use crate::state::singleton::Singleton;
+use crate::types::pause::Subsystem;
+use crate::errors::ErrorCode;
+
+#[derive(Accounts)]
+pub struct RedeemBondTokens<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"singleton"
+        ],
+        bump = singleton.bump,
+        has_one = unit_mint,
+        constraint = singleton.bonds.bond_mint == bond_mint.key() @ ErrorCode::UnexpectedAccount
+    )]
+    pub singleton: Account<'info, Singleton>,
+
+    #[account(
+        mut,
+        mint::authority = token_authority
+    )]
+    pub unit_mint: Account<'info, Mint>,
+
+    #[account(mut)]
+    pub bond_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = bond_mint,
+        associated_token::authority = signer
+    )]
+    pub bond_payer_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        associated_token::mint = unit_mint,
+        associated_token::authority = token_authority
+    )]
+    pub unit_custody_account: Account<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = signer,
+        associated_token::mint = unit_mint,
+        associated_token::authority = signer
+    )]
+    pub unit_receiver_account: Account<'info, TokenAccount>,
+
+    /// CHECK: This account is not read or written
+    #[account(
+        seeds = [
+            b"token-authority"
+        ],
+        bump = singleton.unit_auth_bump
+    )]
+    pub token_authority: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> RedeemBondTokens<'info> {
+    pub fn burn_bonds_ctx(&self) -> CpiContext<'_, '_, '_, 'info, Burn<'info>> {
+        let cpi_accounts = Burn {
+            mint: self.bond_mint.to_account_info(),
+            from: self.bond_payer_account.to_account_info(),
+            authority: self.signer.to_account_info(),
+        };
+
+        let cpi_program = self.token_program.to_account_info();
+
+        CpiContext::new(cpi_program, cpi_accounts)
+    }
+
+    pub fn transfer_reserve_ctx(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        let cpi_accounts = Transfer {
+            from: self.unit_custody_account.to_account_info(),
+            to: self.unit_receiver_account.to_account_info(),
+            authority: self.token_authority.to_account_info(),
+        };
+
+        let cpi_program = self.token_program.to_account_info();
+
+        CpiContext::new(cpi_program, cpi_accounts)
+    }
+
+    pub fn mint_token_ctx(&self) -> CpiContext<'_, '_, '_, 'info, MintTo<'info>> {
+        let cpi_accounts = MintTo {
+            mint: self.unit_mint.to_account_info(),
+            to: self.unit_receiver_account.to_account_info(),
+            authority: self.token_authority.to_account_info(),
+        };
+
+        let cpi_program = self.token_program.to_account_info();
+
+        CpiContext::new(cpi_program, cpi_accounts)
+    }
+}
+
+pub fn redeem_bond_tokens_handler(ctx: Context<RedeemBondTokens>, amount: u64) -> Result<()> {
+    ctx.accounts.singleton.pause.require_active(Subsystem::BondRedemption)?;
+
+    let singleton = &mut ctx.accounts.singleton;
+    let twap = singleton.epoch.twap;
+    let unit_auth_bump = singleton.unit_auth_bump;
+
+    require_gte!(singleton.bonds.redeemable(), amount, ErrorCode::InsufficientRedeemableBonds);
+
+    let units = singleton.bonds.token_redemption_value(amount, twap);
+
+    singleton.bonds.bonds_redeemed(amount);
+    singleton.bonds.bond_tokens_burned(amount);
+
+    let unit_auth_seed = &[&b"token-authority"[..], &[unit_auth_bump]];
+
+    burn(ctx.accounts.burn_bonds_ctx(), amount)?;
+
+    // The face value was reserved when the bonds were funded, only the premium is minted
+    transfer(ctx.accounts.transfer_reserve_ctx().with_signer(&[&unit_auth_seed[..]]), amount)?;
+
+    if units > amount {
+        mint_to(ctx.accounts.mint_token_ctx().with_signer(&[&unit_auth_seed[..]]), units - amount)?;
+    }
+
+    Ok(())
+}
\ No newline at end of file
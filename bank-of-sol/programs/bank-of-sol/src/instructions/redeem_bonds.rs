@@ -0,0 +1,156 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token::{Mint, Token, TokenAccount, MintTo, Transfer, mint_to, transfer},
+    associated_token::AssociatedToken,
+};
+
+use crate::state::{bond_account::{BondAccount, BondAccountsStore}, singleton::Singleton};
+use crate::types::pause::Subsystem;
+use crate::errors::ErrorCode;
+
+/// Redeems bonds from the user's oldest bond account that isn't tokenized, closing it once empty.
+/// Tokenized or closed bond accounts older than it are passed in the remaining accounts in seed order, and skipped.
+#[derive(Accounts)]
+#[instruction(_seed_index: u64)]
+pub struct RedeemBonds<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"bond-account",
+            signer.key().as_ref(),
+            _seed_index.to_be_bytes().as_ref()
+        ],
+        bump = bond_account.bump,
+        constraint = bond_account.user == signer.key() @ ErrorCode::UnexpectedAccount,
+        constraint = !bond_account.is_tokenized() @ ErrorCode::BondAccountTokenized
+    )]
+    pub bond_account: Account<'info, BondAccount>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"bond-accounts-store",
+            signer.key().as_ref()
+        ],
+        bump = bond_accounts_store.bump,
+    )]
+    pub bond_accounts_store: Account<'info, BondAccountsStore>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"singleton"
+        ],
+        bump = singleton.bump,
+        has_one = unit_mint
+    )]
+    pub singleton: Account<'info, Singleton>,
+
+    #[account(
+        mut,
+        mint::authority = token_authority
+    )]
+    pub unit_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = unit_mint,
+        associated_token::authority = token_authority
+    )]
+    pub unit_custody_account: Account<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = signer,
+        associated_token::mint = unit_mint,
+        associated_token::authority = signer
+    )]
+    pub unit_receiver_account: Account<'info, TokenAccount>,
+
+    /// CHECK: This account is not read or written
+    #[account(
+        seeds = [
+            b"token-authority"
+        ],
+        bump = singleton.unit_auth_bump
+    )]
+    pub token_authority: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> RedeemBonds<'info> {
+    pub fn transfer_reserve_ctx(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        let cpi_accounts = Transfer {
+            from: self.unit_custody_account.to_account_info(),
+            to: self.unit_receiver_account.to_account_info(),
+            authority: self.token_authority.to_account_info(),
+        };
+
+        let cpi_program = self.token_program.to_account_info();
+
+        CpiContext::new(cpi_program, cpi_accounts)
+    }
+
+    pub fn mint_token_ctx(&self) -> CpiContext<'_, '_, '_, 'info, MintTo<'info>> {
+        let cpi_accounts = MintTo {
+            mint: self.unit_mint.to_account_info(),
+            to: self.unit_receiver_account.to_account_info(),
+            authority: self.token_authority.to_account_info(),
+        };
+
+        let cpi_program = self.token_program.to_account_info();
+
+        CpiContext::new(cpi_program, cpi_accounts)
+    }
+}
+
+pub fn redeem_bonds_handler<'info>(ctx: Context<'_, '_, 'info, 'info, RedeemBonds<'info>>, _seed_index: u64, amount: u64) -> Result<()> {
+    ctx.accounts.singleton.pause.require_active(Subsystem::BondRedemption)?;
+
+    let signer = ctx.accounts.signer.key();
+    let bond_accounts_store = &mut ctx.accounts.bond_accounts_store;
+
+    for info in ctx.remaining_accounts.iter() {
+        let seed_index = bond_accounts_store.get_oldest().to_be_bytes();
+        let (oldest, _) = Pubkey::find_program_address(&[b"bond-account", signer.as_ref(), seed_index.as_ref()], &crate::ID);
+
+        require_keys_eq!(info.key(), oldest, ErrorCode::InvalidSeedIndex);
+
+        if !info.data_is_empty() {
+            let skipped: Account<BondAccount> = Account::try_from(info)?;
+            require!(skipped.is_tokenized(), ErrorCode::InvalidSeedIndex);
+        }
+
+        bond_accounts_store.oldest_skipped();
+    }
+
+    require_eq!(_seed_index, bond_accounts_store.get_oldest(), ErrorCode::InvalidSeedIndex);
+
+    let singleton = &mut ctx.accounts.singleton;
+    let bond_account = &mut ctx.accounts.bond_account;
+    let unit_auth_bump = singleton.unit_auth_bump;
+
+    let units = singleton.redeem_bonds(bond_account, amount)?;
+
+    let unit_auth_seed = &[&b"token-authority"[..], &[unit_auth_bump]];
+
+    // The face value was reserved when the bonds were funded, only the premium is minted
+    transfer(ctx.accounts.transfer_reserve_ctx().with_signer(&[&unit_auth_seed[..]]), amount)?;
+
+    if units > amount {
+        mint_to(ctx.accounts.mint_token_ctx().with_signer(&[&unit_auth_seed[..]]), units - amount)?;
+    }
+
+    if ctx.accounts.bond_account.bonds.amount == 0 {
+        ctx.accounts.bond_accounts_store.pda_closed();
+        ctx.accounts.bond_account.close(ctx.accounts.signer.to_account_info())?;
+    }
+
+    Ok(())
+}
\ No newline at end of file
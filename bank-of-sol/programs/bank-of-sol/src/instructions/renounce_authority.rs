@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{singleton::Singleton, timelock::Timelock};
+use crate::events::AuthorityRenounced;
+
+#[derive(Accounts)]
+pub struct RenounceAuthority<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"singleton"
+        ],
+        bump = singleton.bump,
+        has_one = authority
+    )]
+    pub singleton: Account<'info, Singleton>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"timelock"
+        ],
+        bump = timelock.bump
+    )]
+    pub timelock: Account<'info, Timelock>,
+
+    pub authority: Signer<'info>,
+}
+
+/// Permanently gives up the authority, disabling its instructions and those of every role. Roles can no
+/// longer be granted or revoked, the actions queued so far are invalidated and parameters can only be
+/// changed through governance
+pub fn renounce_authority_handler(ctx: Context<RenounceAuthority>) -> Result<()> {
+    let singleton = &mut ctx.accounts.singleton;
+    let previous_authority = singleton.authority;
+
+    singleton.authority = Pubkey::default();
+    singleton.pending_authority = Pubkey::default();
+
+    ctx.accounts.timelock.invalidate_queue();
+
+    emit!(AuthorityRenounced {
+        previous_authority,
+    });
+
+    Ok(())
+}
\ No newline at end of file
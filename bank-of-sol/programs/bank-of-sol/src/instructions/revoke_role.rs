@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{singleton::Singleton, roles::RoleMember};
+use crate::types::roles::Role;
+
+#[derive(Accounts)]
+pub struct RevokeRole<'info> {
+    #[account(
+        seeds = [
+            b"singleton"
+        ],
+        bump = singleton.bump,
+        has_one = authority
+    )]
+    pub singleton: Account<'info, Singleton>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"role-member",
+            role_member.member.as_ref()
+        ],
+        bump = role_member.bump
+    )]
+    pub role_member: Account<'info, RoleMember>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn revoke_role_handler(ctx: Context<RevokeRole>, role: Role) -> Result<()> {
+    ctx.accounts.role_member.roles.revoke(role);
+
+    Ok(())
+}
\ No newline at end of file
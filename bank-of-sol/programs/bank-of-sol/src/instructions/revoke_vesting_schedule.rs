@@ -0,0 +1,124 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount, Transfer, transfer, Burn, burn};
+
+use crate::state::{singleton::Singleton, treasury::Treasury, vesting_schedule::VestingSchedule, roles::RoleMember};
+use crate::types::roles::Role;
+use crate::errors::ErrorCode;
+
+#[derive(Accounts)]
+pub struct RevokeVestingSchedule<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"singleton"
+        ],
+        bump = singleton.bump
+    )]
+    pub singleton: Account<'info, Singleton>,
+
+    #[account(
+        seeds = [
+            b"treasury"
+        ],
+        bump = treasury.bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"vesting-schedule",
+            vesting_schedule.mint.as_ref(),
+            vesting_schedule.seed.as_ref()
+        ],
+        bump = vesting_schedule.bump,
+        has_one = vault,
+        has_one = mint,
+        constraint = !vesting_schedule.revoked @ ErrorCode::VestingRevoked
+    )]
+    pub vesting_schedule: Account<'info, VestingSchedule>,
+
+    #[account(mut)]
+    pub vault: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        constraint = treasury.fund(vesting_schedule.fund).vault == fund_vault.key() @ ErrorCode::UnexpectedAccount
+    )]
+    pub fund_vault: Option<Account<'info, TokenAccount>>,
+
+    /// CHECK: This account is not read or written
+    #[account(
+        seeds = [
+            b"token-authority"
+        ],
+        bump = singleton.unit_auth_bump
+    )]
+    pub token_authority: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [
+            b"role-member",
+            authority.key().as_ref()
+        ],
+        bump = role_member.bump,
+        constraint = singleton.has_role(&role_member, Role::TreasurySpender) @ ErrorCode::MissingRole
+    )]
+    pub role_member: Account<'info, RoleMember>,
+
+    pub authority: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> RevokeVestingSchedule<'info> {
+    pub fn transfer_ctx(&self, fund_vault: &Account<'info, TokenAccount>) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        let cpi_accounts = Transfer {
+            from: self.vault.to_account_info(),
+            to: fund_vault.to_account_info(),
+            authority: self.token_authority.to_account_info(),
+        };
+
+        let cpi_program = self.token_program.to_account_info();
+
+        CpiContext::new(cpi_program, cpi_accounts)
+    }
+
+    pub fn burn_tokens_ctx(&self) -> CpiContext<'_, '_, '_, 'info, Burn<'info>> {
+        let cpi_accounts = Burn {
+            mint: self.mint.to_account_info(),
+            from: self.vault.to_account_info(),
+            authority: self.token_authority.to_account_info(),
+        };
+
+        let cpi_program = self.token_program.to_account_info();
+
+        CpiContext::new(cpi_program, cpi_accounts)
+    }
+}
+
+/// Stops vesting, the vested amount stays claimable. Unvested Units are returned to the treasury fund and
+/// unvested shares are burned, returning them to the share supply left to mint
+pub fn revoke_vesting_schedule_handler(ctx: Context<RevokeVestingSchedule>) -> Result<()> {
+    let unit_auth_bump = ctx.accounts.singleton.unit_auth_bump;
+    let unvested = ctx.accounts.vesting_schedule.revoke(Clock::get()?.unix_timestamp);
+
+    if unvested > 0 {
+        let unit_auth_seed = &[&b"token-authority"[..], &[unit_auth_bump]];
+
+        if ctx.accounts.mint.key() == ctx.accounts.singleton.share.mint {
+            ctx.accounts.singleton.share.burned(unvested);
+
+            burn(ctx.accounts.burn_tokens_ctx().with_signer(&[&unit_auth_seed[..]]), unvested)?;
+        } else {
+            let fund_vault = ctx.accounts.fund_vault.as_ref().ok_or(ErrorCode::MissingFundVault)?;
+
+            transfer(ctx.accounts.transfer_ctx(fund_vault).with_signer(&[&unit_auth_seed[..]]), unvested)?;
+        }
+    }
+
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{singleton::Singleton, roles::RoleMember};
+use crate::types::roles::Role;
+use crate::events::PauseStateChanged;
+use crate::errors::ErrorCode;
+
+#[derive(Accounts)]
+pub struct SetEmergencyShutdown<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"singleton"
+        ],
+        bump = singleton.bump
+    )]
+    pub singleton: Account<'info, Singleton>,
+
+    #[account(
+        seeds = [
+            b"role-member",
+            authority.key().as_ref()
+        ],
+        bump = role_member.bump,
+        constraint = singleton.has_role(&role_member, Role::Pauser) @ ErrorCode::MissingRole
+    )]
+    pub role_member: Account<'info, RoleMember>,
+
+    pub authority: Signer<'info>,
+}
+
+/// Shuts the protocol down or restarts it. While shut down nothing is minted or spent, governance is frozen
+/// and users can only withdraw their principal, from the boardroom, farms, ve locks and NFT staking
+pub fn set_emergency_shutdown_handler(ctx: Context<SetEmergencyShutdown>, shutdown: bool) -> Result<()> {
+    let pause = &mut ctx.accounts.singleton.pause;
+
+    pause.shutdown = shutdown;
+
+    emit!(PauseStateChanged {
+        paused: pause.paused,
+        shutdown: pause.shutdown,
+    });
+
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{singleton::Singleton, roles::RoleMember};
+use crate::types::roles::Role;
+use crate::errors::ErrorCode;
+
+#[derive(Accounts)]
+pub struct SetNftStakingStatus<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"singleton"
+        ],
+        bump = singleton.bump
+    )]
+    pub singleton: Account<'info, Singleton>,
+
+    #[account(
+        seeds = [
+            b"role-member",
+            authority.key().as_ref()
+        ],
+        bump = role_member.bump,
+        constraint = singleton.has_role(&role_member, Role::Pauser) @ ErrorCode::MissingRole
+    )]
+    pub role_member: Account<'info, RoleMember>,
+
+    pub authority: Signer<'info>,
+}
+
+/// Opens or closes NFT staking, staked NFTs can always be unstaked
+pub fn set_nft_staking_status_handler(ctx: Context<SetNftStakingStatus>, active: bool) -> Result<()> {
+    ctx.accounts.singleton.nft_staking.status = active;
+
+    Ok(())
+}
\ No newline at end of file
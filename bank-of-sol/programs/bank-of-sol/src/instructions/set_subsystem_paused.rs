@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{singleton::Singleton, roles::RoleMember};
+use crate::types::{pause::Subsystem, roles::Role};
+use crate::events::PauseStateChanged;
+use crate::errors::ErrorCode;
+
+#[derive(Accounts)]
+pub struct SetSubsystemPaused<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"singleton"
+        ],
+        bump = singleton.bump
+    )]
+    pub singleton: Account<'info, Singleton>,
+
+    #[account(
+        seeds = [
+            b"role-member",
+            authority.key().as_ref()
+        ],
+        bump = role_member.bump,
+        constraint = singleton.has_role(&role_member, Role::Pauser) @ ErrorCode::MissingRole
+    )]
+    pub role_member: Account<'info, RoleMember>,
+
+    pub authority: Signer<'info>,
+}
+
+/// Pauses or unpauses a single subsystem. Withdrawals of principal can't be paused during an emergency
+/// shutdown, see `PauseState::is_paused`
+pub fn set_subsystem_paused_handler(ctx: Context<SetSubsystemPaused>, subsystem: Subsystem, paused: bool) -> Result<()> {
+    let pause = &mut ctx.accounts.singleton.pause;
+
+    pause.set_paused(subsystem, paused);
+
+    emit!(PauseStateChanged {
+        paused: pause.paused,
+        shutdown: pause.shutdown,
+    });
+
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,94 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount, Transfer, transfer};
+
+use crate::state::{singleton::Singleton, treasury::Treasury, roles::RoleMember};
+use crate::types::treasury::FundKind;
+use crate::types::roles::Role;
+use crate::errors::ErrorCode;
+
+#[derive(Accounts)]
+#[instruction(fund: FundKind)]
+pub struct SpendTreasury<'info> {
+    #[account(
+        seeds = [
+            b"singleton"
+        ],
+        bump = singleton.bump,
+        has_one = unit_mint
+    )]
+    pub singleton: Account<'info, Singleton>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"treasury"
+        ],
+        bump = treasury.bump,
+        constraint = treasury.fund(fund).vault == vault.key() @ ErrorCode::UnexpectedAccount
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    pub unit_mint: Account<'info, Mint>,
+
+    #[account(mut)]
+    pub vault: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        token::mint = unit_mint
+    )]
+    pub recipient: Account<'info, TokenAccount>,
+
+    /// CHECK: This account is not read or written
+    #[account(
+        seeds = [
+            b"token-authority"
+        ],
+        bump = singleton.unit_auth_bump
+    )]
+    pub token_authority: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [
+            b"role-member",
+            authority.key().as_ref()
+        ],
+        bump = role_member.bump,
+        constraint = singleton.has_role(&role_member, Role::TreasurySpender) @ ErrorCode::MissingRole
+    )]
+    pub role_member: Account<'info, RoleMember>,
+
+    pub authority: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> SpendTreasury<'info> {
+    pub fn transfer_ctx(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        let cpi_accounts = Transfer {
+            from: self.vault.to_account_info(),
+            to: self.recipient.to_account_info(),
+            authority: self.token_authority.to_account_info(),
+        };
+
+        let cpi_program = self.token_program.to_account_info();
+
+        CpiContext::new(cpi_program, cpi_accounts)
+    }
+}
+
+/// Pays `amount` out of `fund` to `recipient`, within the fund's limit for the current epoch
+pub fn spend_treasury_handler(ctx: Context<SpendTreasury>, fund: FundKind, amount: u64) -> Result<()> {
+    ctx.accounts.singleton.pause.require_not_shutdown()?;
+
+    let epoch = ctx.accounts.singleton.epoch();
+    let unit_auth_bump = ctx.accounts.singleton.unit_auth_bump;
+
+    ctx.accounts.treasury.fund_mut(fund).spend(amount, epoch)?;
+
+    let unit_auth_seed = &[&b"token-authority"[..], &[unit_auth_bump]];
+
+    transfer(ctx.accounts.transfer_ctx().with_signer(&[&unit_auth_seed[..]]), amount)?;
+
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,82 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{singleton::Singleton, bond_account::{BondAccount, BondAccountsStore}};
+use crate::errors::ErrorCode;
+
+#[derive(Accounts)]
+#[instruction(_seed_index: u64)]
+pub struct SplitBondAccount<'info> {
+    #[account(
+        seeds = [
+            b"singleton"
+        ],
+        bump = singleton.bump
+    )]
+    pub singleton: Box<Account<'info, Singleton>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"bond-account",
+            signer.key().as_ref(),
+            _seed_index.to_be_bytes().as_ref()
+        ],
+        bump = bond_account.bump,
+        constraint = bond_account.user == signer.key() @ ErrorCode::UnexpectedAccount,
+        constraint = !bond_account.is_tokenized() @ ErrorCode::BondAccountTokenized,
+        constraint = _seed_index == bond_accounts_store.get_index_of_accounts() @ ErrorCode::InvalidSeedIndex
+    )]
+    pub bond_account: Account<'info, BondAccount>,
+
+    #[account(
+        init,
+        payer = signer,
+        space = BondAccount::LEN,
+        seeds = [
+            b"bond-account",
+            signer.key().as_ref(),
+            (bond_accounts_store.get_index_of_accounts() + 1).to_be_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub split_bond_account: Account<'info, BondAccount>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"bond-accounts-store",
+            signer.key().as_ref()
+        ],
+        bump = bond_accounts_store.bump
+    )]
+    pub bond_accounts_store: Account<'info, BondAccountsStore>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Moves `amount` bonds off the newest bond account into a new bond account on the same terms
+pub fn split_bond_account_handler(ctx: Context<SplitBondAccount>, _seed_index: u64, amount: u64) -> Result<()> {
+    ctx.accounts.singleton.pause.require_not_shutdown()?;
+
+    let bond_account = &mut ctx.accounts.bond_account;
+    let split_bond_account = &mut ctx.accounts.split_bond_account;
+
+    // The split off bonds take the tail of the original index range
+    let index = bond_account.split_off(amount)?;
+
+    **split_bond_account = BondAccount::init(
+        bond_account.user,
+        ctx.bumps.split_bond_account,
+        amount,
+        bond_account.bonds.epoch,
+        bond_account.bonds.interest_rate,
+        index,
+    );
+
+    ctx.accounts.bond_accounts_store.increment();
+
+    Ok(())
+}
\ No newline at end of file
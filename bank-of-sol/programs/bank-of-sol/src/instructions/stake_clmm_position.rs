@@ -0,0 +1,133 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token::{Mint, Token, TokenAccount, Transfer, transfer},
+    associated_token::AssociatedToken,
+};
+
+use crate::state::{singleton::Singleton, farm::{ClmmFarm, ClmmFarmPosition}};
+use crate::types::farm::{pool_tick, position_stake};
+use crate::types::pause::Subsystem;
+use crate::errors::ErrorCode;
+
+#[derive(Accounts)]
+pub struct StakeClmmPosition<'info> {
+    #[account(
+        seeds = [
+            b"singleton"
+        ],
+        bump = singleton.bump
+    )]
+    pub singleton: Box<Account<'info, Singleton>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"clmm-farm",
+            clmm_farm.pool.as_ref()
+        ],
+        bump = clmm_farm.bump,
+        constraint = clmm_farm.pool == pool_state.key() @ ErrorCode::InvalidRaydiumPool
+    )]
+    pub clmm_farm: Box<Account<'info, ClmmFarm>>,
+
+    #[account(
+        init,
+        payer = staker,
+        space = ClmmFarmPosition::LEN,
+        seeds = [
+            b"clmm-farm-position",
+            clmm_farm.key().as_ref(),
+            nft_mint.key().as_ref()
+        ],
+        bump
+    )]
+    pub clmm_farm_position: Box<Account<'info, ClmmFarmPosition>>,
+
+    /// CHECK: Checked against the farm's pool and deserialized
+    pub pool_state: UncheckedAccount<'info>,
+
+    /// CHECK: Owned by Raydium, checked against the pool and the NFT mint when deserialized
+    #[account(
+        owner = raydium_amm_v3::id()
+    )]
+    pub personal_position: UncheckedAccount<'info>,
+
+    #[account(
+        mint::decimals = 0,
+        constraint = nft_mint.supply == 1 @ ErrorCode::TokenNotNFT
+    )]
+    pub nft_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = nft_mint,
+        associated_token::authority = staker
+    )]
+    pub nft_payer_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = staker,
+        associated_token::mint = nft_mint,
+        associated_token::authority = nft_authority
+    )]
+    pub nft_custody: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK: This account is not read or written
+    #[account(
+        seeds = [
+            b"nft-authority"
+        ],
+        bump = singleton.nft_staking.nft_auth_bump
+    )]
+    pub nft_authority: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub staker: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> StakeClmmPosition<'info> {
+    pub fn transfer_nft_ctx(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        let cpi_accounts = Transfer {
+            from: self.nft_payer_account.to_account_info(),
+            to: self.nft_custody.to_account_info(),
+            authority: self.staker.to_account_info(),
+        };
+
+        let cpi_program = self.token_program.to_account_info();
+
+        CpiContext::new(cpi_program, cpi_accounts)
+    }
+}
+
+/// Stakes a position NFT, the position's liquidity accrues rewards from now while it is in range
+pub fn stake_clmm_position_handler(ctx: Context<StakeClmmPosition>) -> Result<()> {
+    ctx.accounts.singleton.pause.require_active(Subsystem::Staking)?;
+
+    let tick_current = pool_tick(&ctx.accounts.pool_state.to_account_info())?;
+    let mut stake = position_stake(
+        &ctx.accounts.personal_position.to_account_info(),
+        ctx.accounts.clmm_farm.pool,
+        ctx.accounts.nft_mint.key()
+    )?;
+
+    stake.sync(&mut ctx.accounts.clmm_farm.rewards, tick_current, Clock::get()?.unix_timestamp);
+
+    let clmm_farm_position = &mut ctx.accounts.clmm_farm_position;
+
+    ***clmm_farm_position = ClmmFarmPosition {
+        farm: ctx.accounts.clmm_farm.key(),
+        owner: ctx.accounts.staker.key(),
+        nft_mint: ctx.accounts.nft_mint.key(),
+        stake,
+        bump: ctx.bumps.clmm_farm_position,
+    };
+
+    transfer(ctx.accounts.transfer_nft_ctx(), 1)?;
+
+    Ok(())
+}
\ No newline at end of file
@@ -6,17 +6,20 @@ use anchor_spl::{
 };
 
 use crate::state::{singleton::Singleton, nft_stake_record::{StakeRecord, StakeRecordIndex}};
+use crate::types::pause::Subsystem;
 use crate::errors::ErrorCode;
 
 #[derive(Accounts)]
 pub struct Stake<'info> {
     #[account(
+        mut,
         seeds = [b"singleton"],
         bump = singleton.bump
     )]
     pub singleton: Account<'info, Singleton>,
 
     #[account(
+        mut,
         seeds = [
             b"stake-record-index",
             signer.key().as_ref(),
@@ -32,7 +35,7 @@ pub struct Stake<'info> {
         seeds = [
             b"stake-record",
             signer.key().as_ref(),
-            (stake_record_index.index + stake_record_index.offset).to_be_bytes().as_ref()
+            (stake_record_index.get_index_of_accounts() + 1).to_be_bytes().as_ref()
         ],
         bump
     )]
@@ -118,6 +121,8 @@ impl<'info> Stake<'info> {
 }
 
 pub fn stake_handler(ctx: Context<Stake>) -> Result<()> {
+    ctx.accounts.singleton.pause.require_active(Subsystem::Staking)?;
+
     let staking_status = ctx.accounts.singleton.nft_staking.status;
 
     require_eq!(staking_status, true, ErrorCode::StakingInactive);
@@ -132,5 +137,13 @@ pub fn stake_handler(ctx: Context<Stake>) -> Result<()> {
     let nft_record = &mut ctx.accounts.stake_record;
     **nft_record = StakeRecord::init(staker, nft_mint, staked_epoch, bump);
 
+    let share_reward_per_nft = ctx.accounts.singleton.nft_staking.share_reward_per_nft;
+    let stake_record_index = &mut ctx.accounts.stake_record_index;
+
+    stake_record_index.settle_share_rewards(share_reward_per_nft);
+    stake_record_index.increment();
+
+    ctx.accounts.singleton.nft_staking.staked_nfts += 1;
+
     Ok(())
 }
\ No newline at end of file
@@ -0,0 +1,83 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{singleton::Singleton, boardroom::BoardroomAccount, nft_stake_record::{StakeRecord, StakeRecordIndex}};
+use crate::utils::account_deserialize;
+use crate::errors::ErrorCode;
+
+/// Recounts the NFTs a user has staked and re-weights their boardroom account.
+///
+/// The owner passes their `StakeRecord` PDAs as remaining accounts. Anyone else may call this to
+/// lower a stale boost down to the owner's current stake count, but never to raise it.
+#[derive(Accounts)]
+pub struct SyncBoardroomBoost<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"singleton"
+        ],
+        bump = singleton.bump
+    )]
+    pub singleton: Account<'info, Singleton>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"boardroom-account",
+            owner.key().as_ref()
+        ],
+        bump = boardroom_account.bump
+    )]
+    pub boardroom_account: Account<'info, BoardroomAccount>,
+
+    #[account(
+        seeds = [
+            b"stake-record-index",
+            owner.key().as_ref()
+        ],
+        bump = stake_record_index.bump
+    )]
+    pub stake_record_index: Account<'info, StakeRecordIndex>,
+
+    /// CHECK: Only used to derive the boardroom and stake record PDAs
+    pub owner: UncheckedAccount<'info>,
+
+    pub signer: Signer<'info>,
+}
+
+pub fn sync_boardroom_boost_handler<'info>(ctx: Context<'_, '_, '_, 'info, SyncBoardroomBoost<'info>>) -> Result<()> {
+    let owner = ctx.accounts.owner.key();
+    let epoch = ctx.accounts.singleton.epoch();
+    let minimum_period = ctx.accounts.singleton.nft_staking.minimum_period;
+    let currently_staked = ctx.accounts.stake_record_index.index;
+
+    let staked_nfts = if ctx.accounts.signer.key() == owner {
+        let mut seen: Vec<Pubkey> = Vec::with_capacity(ctx.remaining_accounts.len());
+        let mut eligible: u64 = 0;
+
+        for account in ctx.remaining_accounts.iter() {
+            require_keys_eq!(*account.owner, crate::ID, ErrorCode::InvalidStakeRecord);
+            require!(!seen.contains(account.key), ErrorCode::DuplicateStakeRecord);
+
+            let stake_record: StakeRecord = account_deserialize(account)?;
+            require_keys_eq!(stake_record.staker, owner, ErrorCode::InvalidStakeRecord);
+
+            if stake_record.staked_epoch + minimum_period <= epoch {
+                eligible += 1;
+            }
+
+            seen.push(account.key());
+        }
+
+        eligible.min(currently_staked)
+    } else {
+        ctx.accounts.boardroom_account.staked_nfts.min(currently_staked)
+    };
+
+    let singleton = &mut ctx.accounts.singleton;
+    let boardroom_account = &mut ctx.accounts.boardroom_account;
+
+    boardroom_account.staked_nfts = staked_nfts;
+    singleton.sync_boardroom_account(boardroom_account);
+
+    Ok(())
+}
\ No newline at end of file
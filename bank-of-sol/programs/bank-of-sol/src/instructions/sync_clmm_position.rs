@@ -0,0 +1,101 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount, Transfer, transfer};
+
+use crate::state::{singleton::Singleton, farm::{ClmmFarm, ClmmFarmPosition}};
+use crate::types::farm::pool_tick;
+use crate::types::pause::Subsystem;
+use crate::errors::ErrorCode;
+
+#[derive(Accounts)]
+pub struct SyncClmmPosition<'info> {
+    #[account(
+        seeds = [
+            b"singleton"
+        ],
+        bump = singleton.bump
+    )]
+    pub singleton: Account<'info, Singleton>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"clmm-farm",
+            clmm_farm.pool.as_ref()
+        ],
+        bump = clmm_farm.bump,
+        has_one = reward_vault,
+        constraint = clmm_farm.pool == pool_state.key() @ ErrorCode::InvalidRaydiumPool
+    )]
+    pub clmm_farm: Account<'info, ClmmFarm>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"clmm-farm-position",
+            clmm_farm.key().as_ref(),
+            clmm_farm_position.nft_mint.as_ref()
+        ],
+        bump = clmm_farm_position.bump
+    )]
+    pub clmm_farm_position: Account<'info, ClmmFarmPosition>,
+
+    /// CHECK: Checked against the farm's pool and deserialized
+    pub pool_state: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub reward_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        token::mint = clmm_farm.reward_mint,
+        token::authority = signer
+    )]
+    pub receiver_account: Account<'info, TokenAccount>,
+
+    /// CHECK: This account is not read or written
+    #[account(
+        seeds = [
+            b"token-authority"
+        ],
+        bump = singleton.unit_auth_bump
+    )]
+    pub token_authority: UncheckedAccount<'info>,
+
+    pub signer: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> SyncClmmPosition<'info> {
+    pub fn transfer_tokens_ctx(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        let cpi_accounts = Transfer {
+            from: self.reward_vault.to_account_info(),
+            to: self.receiver_account.to_account_info(),
+            authority: self.token_authority.to_account_info(),
+        };
+
+        let cpi_program = self.token_program.to_account_info();
+
+        CpiContext::new(cpi_program, cpi_accounts)
+    }
+}
+
+/// Settles a staked position's rewards and (de)activates it for the pool's current tick, callable by anyone.
+/// Deactivating a position pays the caller its unsettled rewards as a bounty, up to the balance of the reward
+/// vault and unless claims are paused
+pub fn sync_clmm_position_handler(ctx: Context<SyncClmmPosition>) -> Result<()> {
+    let tick_current = pool_tick(&ctx.accounts.pool_state.to_account_info())?;
+    let unit_auth_bump = ctx.accounts.singleton.unit_auth_bump;
+    let claims_active = !ctx.accounts.singleton.pause.is_paused(Subsystem::Claims);
+
+    let bounty = ctx.accounts.clmm_farm_position.stake.sync(&mut ctx.accounts.clmm_farm.rewards, tick_current, Clock::get()?.unix_timestamp);
+    let reward = if claims_active { bounty.min(ctx.accounts.reward_vault.amount) } else { 0 };
+
+    if reward > 0 {
+        let unit_auth_seed = &[&b"token-authority"[..], &[unit_auth_bump]];
+
+        transfer(ctx.accounts.transfer_tokens_ctx().with_signer(&[&unit_auth_seed[..]]), reward)?;
+    }
+
+    Ok(())
+}
\ No newline at end of file
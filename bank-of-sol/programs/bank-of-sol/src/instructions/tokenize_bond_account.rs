@@ -0,0 +1,195 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token::{Mint, Token, TokenAccount, MintTo, mint_to},
+    metadata::{
+        Metadata, CreateMetadataAccountsV3, CreateMasterEditionV3,
+        create_metadata_accounts_v3, create_master_edition_v3,
+        mpl_token_metadata::types::DataV2,
+    },
+    associated_token::AssociatedToken,
+};
+
+use crate::state::{bond_account::BondAccount, singleton::Singleton};
+use crate::errors::ErrorCode;
+
+/// Mints a one-of-one NFT representing a bond account, whose holder is entitled to redeem it
+#[derive(Accounts)]
+#[instruction(_seed_index: u64)]
+pub struct TokenizeBondAccount<'info> {
+    #[account(
+        seeds = [b"singleton"],
+        bump = singleton.bump
+    )]
+    pub singleton: Account<'info, Singleton>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"bond-account",
+            signer.key().as_ref(),
+            _seed_index.to_be_bytes().as_ref()
+        ],
+        bump = bond_account.bump,
+        constraint = bond_account.user == signer.key() @ ErrorCode::UnexpectedAccount,
+        constraint = !bond_account.is_tokenized() @ ErrorCode::BondAccountTokenized
+    )]
+    pub bond_account: Box<Account<'info, BondAccount>>,
+
+    #[account(
+        init,
+        payer = signer,
+        seeds = [
+            b"bond-nft",
+            bond_account.key().as_ref()
+        ],
+        bump,
+        mint::decimals = 0,
+        mint::authority = token_authority,
+        mint::freeze_authority = token_authority
+    )]
+    pub bond_nft_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        init_if_needed,
+        payer = signer,
+        associated_token::mint = bond_nft_mint,
+        associated_token::authority = signer
+    )]
+    pub bond_nft_account: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK: Created and checked by the token metadata program
+    #[account(
+        mut,
+        seeds = [
+            b"metadata",
+            Metadata::id().as_ref(),
+            bond_nft_mint.key().as_ref()
+        ],
+        seeds::program = Metadata::id(),
+        bump
+    )]
+    pub bond_nft_metadata: UncheckedAccount<'info>,
+
+    /// CHECK: Created and checked by the token metadata program
+    #[account(
+        mut,
+        seeds = [
+            b"metadata",
+            Metadata::id().as_ref(),
+            bond_nft_mint.key().as_ref(),
+            b"edition"
+        ],
+        seeds::program = Metadata::id(),
+        bump
+    )]
+    pub bond_nft_edition: UncheckedAccount<'info>,
+
+    /// CHECK: This account is not read or written
+    #[account(
+        seeds = [
+            b"token-authority"
+        ],
+        bump = singleton.unit_auth_bump
+    )]
+    pub token_authority: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub token_metadata_program: Program<'info, Metadata>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+impl<'info> TokenizeBondAccount<'info> {
+    pub fn mint_nft_ctx(&self) -> CpiContext<'_, '_, '_, 'info, MintTo<'info>> {
+        let cpi_accounts = MintTo {
+            mint: self.bond_nft_mint.to_account_info(),
+            to: self.bond_nft_account.to_account_info(),
+            authority: self.token_authority.to_account_info(),
+        };
+
+        let cpi_program = self.token_program.to_account_info();
+
+        CpiContext::new(cpi_program, cpi_accounts)
+    }
+
+    pub fn create_metadata_ctx(&self) -> CpiContext<'_, '_, '_, 'info, CreateMetadataAccountsV3<'info>> {
+        let cpi_accounts = CreateMetadataAccountsV3 {
+            metadata: self.bond_nft_metadata.to_account_info(),
+            mint: self.bond_nft_mint.to_account_info(),
+            mint_authority: self.token_authority.to_account_info(),
+            payer: self.signer.to_account_info(),
+            update_authority: self.token_authority.to_account_info(),
+            system_program: self.system_program.to_account_info(),
+            rent: self.rent.to_account_info(),
+        };
+
+        let cpi_program = self.token_metadata_program.to_account_info();
+
+        CpiContext::new(cpi_program, cpi_accounts)
+    }
+
+    pub fn create_master_edition_ctx(&self) -> CpiContext<'_, '_, '_, 'info, CreateMasterEditionV3<'info>> {
+        let cpi_accounts = CreateMasterEditionV3 {
+            edition: self.bond_nft_edition.to_account_info(),
+            mint: self.bond_nft_mint.to_account_info(),
+            update_authority: self.token_authority.to_account_info(),
+            mint_authority: self.token_authority.to_account_info(),
+            payer: self.signer.to_account_info(),
+            metadata: self.bond_nft_metadata.to_account_info(),
+            token_program: self.token_program.to_account_info(),
+            system_program: self.system_program.to_account_info(),
+            rent: self.rent.to_account_info(),
+        };
+
+        let cpi_program = self.token_metadata_program.to_account_info();
+
+        CpiContext::new(cpi_program, cpi_accounts)
+    }
+}
+
+pub fn tokenize_bond_account_handler(
+    ctx: Context<TokenizeBondAccount>,
+    _seed_index: u64,
+    name: String,
+    symbol: String,
+    uri: String
+) -> Result<()> {
+    ctx.accounts.singleton.pause.require_not_shutdown()?;
+
+    let unit_auth_bump = ctx.accounts.singleton.unit_auth_bump;
+    let unit_auth_seed = &[&b"token-authority"[..], &[unit_auth_bump]];
+
+    mint_to(ctx.accounts.mint_nft_ctx().with_signer(&[&unit_auth_seed[..]]), 1)?;
+
+    let data = DataV2 {
+        name,
+        symbol,
+        uri,
+        seller_fee_basis_points: 0,
+        creators: None,
+        collection: None,
+        uses: None,
+    };
+
+    create_metadata_accounts_v3(
+        ctx.accounts.create_metadata_ctx().with_signer(&[&unit_auth_seed[..]]),
+        data,
+        false,
+        true,
+        None
+    )?;
+
+    // A max supply of zero makes this a one-of-one, it also revokes the mint authority
+    create_master_edition_v3(
+        ctx.accounts.create_master_edition_ctx().with_signer(&[&unit_auth_seed[..]]),
+        Some(0)
+    )?;
+
+    ctx.accounts.bond_account.nft_mint = ctx.accounts.bond_nft_mint.key();
+
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,173 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token::{Mint, Token, TokenAccount, Transfer, CloseAccount, transfer, close_account},
+    associated_token::AssociatedToken,
+};
+
+use crate::state::{singleton::Singleton, farm::{ClmmFarm, ClmmFarmPosition}};
+use crate::types::{farm::pool_tick, pause::Subsystem};
+use crate::errors::ErrorCode;
+
+#[derive(Accounts)]
+pub struct UnstakeClmmPosition<'info> {
+    #[account(
+        seeds = [
+            b"singleton"
+        ],
+        bump = singleton.bump
+    )]
+    pub singleton: Box<Account<'info, Singleton>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"clmm-farm",
+            clmm_farm.pool.as_ref()
+        ],
+        bump = clmm_farm.bump,
+        has_one = reward_vault,
+        constraint = clmm_farm.pool == pool_state.key() @ ErrorCode::InvalidRaydiumPool
+    )]
+    pub clmm_farm: Box<Account<'info, ClmmFarm>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"clmm-farm-position",
+            clmm_farm.key().as_ref(),
+            nft_mint.key().as_ref()
+        ],
+        bump = clmm_farm_position.bump,
+        has_one = owner,
+        has_one = nft_mint,
+        close = owner
+    )]
+    pub clmm_farm_position: Box<Account<'info, ClmmFarmPosition>>,
+
+    /// CHECK: Checked against the farm's pool and deserialized
+    pub pool_state: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub reward_vault: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = clmm_farm.reward_mint,
+        token::authority = owner
+    )]
+    pub receiver_account: Box<Account<'info, TokenAccount>>,
+
+    pub nft_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        associated_token::mint = nft_mint,
+        associated_token::authority = owner
+    )]
+    pub nft_receive_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = nft_mint,
+        associated_token::authority = nft_authority
+    )]
+    pub nft_custody: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK: This account is not read or written
+    #[account(
+        seeds = [
+            b"token-authority"
+        ],
+        bump = singleton.unit_auth_bump
+    )]
+    pub token_authority: UncheckedAccount<'info>,
+
+    /// CHECK: This account is not read or written
+    #[account(
+        seeds = [
+            b"nft-authority"
+        ],
+        bump = singleton.nft_staking.nft_auth_bump
+    )]
+    pub nft_authority: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> UnstakeClmmPosition<'info> {
+    pub fn transfer_tokens_ctx(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        let cpi_accounts = Transfer {
+            from: self.reward_vault.to_account_info(),
+            to: self.receiver_account.to_account_info(),
+            authority: self.token_authority.to_account_info(),
+        };
+
+        let cpi_program = self.token_program.to_account_info();
+
+        CpiContext::new(cpi_program, cpi_accounts)
+    }
+
+    pub fn transfer_nft_ctx(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        let cpi_accounts = Transfer {
+            from: self.nft_custody.to_account_info(),
+            to: self.nft_receive_account.to_account_info(),
+            authority: self.nft_authority.to_account_info(),
+        };
+
+        let cpi_program = self.token_program.to_account_info();
+
+        CpiContext::new(cpi_program, cpi_accounts)
+    }
+
+    pub fn close_account_ctx(&self) -> CpiContext<'_, '_, '_, 'info, CloseAccount<'info>> {
+        let cpi_accounts = CloseAccount {
+            account: self.nft_custody.to_account_info(),
+            destination: self.owner.to_account_info(),
+            authority: self.nft_authority.to_account_info(),
+        };
+
+        let cpi_program = self.token_program.to_account_info();
+
+        CpiContext::new(cpi_program, cpi_accounts)
+    }
+}
+
+/// Returns the position NFT and pays out its rewards. Rewards the reward vault can't cover, or all of them while
+/// claims are paused, are only left behind with `forfeit_rewards`, otherwise the position stays staked
+pub fn unstake_clmm_position_handler(ctx: Context<UnstakeClmmPosition>, forfeit_rewards: bool) -> Result<()> {
+    let tick_current = pool_tick(&ctx.accounts.pool_state.to_account_info())?;
+    let unit_auth_bump = ctx.accounts.singleton.unit_auth_bump;
+    let nft_auth_bump = ctx.accounts.singleton.nft_staking.nft_auth_bump;
+    let available = ctx.accounts.reward_vault.amount;
+    let claims_active = !ctx.accounts.singleton.pause.is_paused(Subsystem::Claims);
+    let rewards = &mut ctx.accounts.clmm_farm.rewards;
+    let stake = &mut ctx.accounts.clmm_farm_position.stake;
+
+    // The owner synced the position, so any bounty is theirs
+    let bounty = stake.sync(rewards, tick_current, Clock::get()?.unix_timestamp);
+    stake.pending_rewards += bounty;
+    stake.set_active(rewards, false);
+
+    let reward = if claims_active { stake.pending_rewards.min(available) } else { 0 };
+
+    require!(reward == stake.pending_rewards || forfeit_rewards, ErrorCode::RewardsNotCovered);
+
+    if reward > 0 {
+        let unit_auth_seed = &[&b"token-authority"[..], &[unit_auth_bump]];
+
+        transfer(ctx.accounts.transfer_tokens_ctx().with_signer(&[&unit_auth_seed[..]]), reward)?;
+    }
+
+    let nft_auth_seed = &[&b"nft-authority"[..], &[nft_auth_bump]];
+
+    transfer(ctx.accounts.transfer_nft_ctx().with_signer(&[&nft_auth_seed[..]]), 1)?;
+    close_account(ctx.accounts.close_account_ctx().with_signer(&[&nft_auth_seed[..]]))?;
+
+    Ok(())
+}
\ No newline at end of file
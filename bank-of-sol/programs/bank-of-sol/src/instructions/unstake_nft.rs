@@ -5,12 +5,14 @@ use anchor_spl::{
 };
 
 use crate::state::{nft_stake_record::{StakeRecord, StakeRecordIndex}, singleton::Singleton};
+use crate::types::pause::Subsystem;
 use crate::errors::ErrorCode;
 
 #[derive(Accounts)]
 #[instruction(_seed_index: u64)]
 pub struct UnstakeNft<'info> {
     #[account(
+        mut,
         seeds = [b"singleton"],
         bump = singleton.bump,
         has_one = unit_mint
@@ -144,14 +146,18 @@ impl<'info> UnstakeNft<'info> {
 }
 
 pub fn unstake_nft_handler(ctx: Context<UnstakeNft>, _seed_index: u64) -> Result<()> {
-    let singleton = &ctx.accounts.singleton;
+    let singleton = &mut ctx.accounts.singleton;
     let stake_record_index = &mut ctx.accounts.stake_record_index;
 
+    // Share rewards stay claimable from the index after the record is closed
+    stake_record_index.settle_share_rewards(singleton.nft_staking.share_reward_per_nft);
     stake_record_index.pda_closed();
+    singleton.nft_staking.staked_nfts -= 1;
 
     let staked_at = ctx.accounts.stake_record.staked_at;
     let minimum_stake_period = singleton.nft_staking.minimum_period;
     let staking_active = singleton.nft_staking.status;
+    let claims_active = !singleton.pause.is_paused(Subsystem::Claims);
     let unit_auth_bump = singleton.unit_auth_bump;
     let nft_auth_bump = singleton.nft_staking.nft_auth_bump;
     
@@ -161,7 +167,8 @@ pub fn unstake_nft_handler(ctx: Context<UnstakeNft>, _seed_index: u64) -> Result
     let unit_auth_seed = &[&b"token-authority"[..], &[unit_auth_bump]];
     let nft_auth_seed = &[&b"nft-authority"[..], &[nft_auth_bump]];
 
-    if eligible_for_reward && staking_active {
+    // The NFT can always be unstaked, the reward is only minted while claims are active
+    if eligible_for_reward && staking_active && claims_active {
         // mint units
         mint_to(
             ctx.accounts.mint_token_ctx().with_signer(&[&unit_auth_seed[..]]),
@@ -0,0 +1,138 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{singleton::Singleton, gauge::{GaugeController, Gauge, GaugeVoter, GaugeVote}, ve::{VeSupply, VeLock, VeCheckpoint}};
+use crate::types::gauge::{MAX_VOTE_WEIGHT, weighted_votes};
+use crate::errors::ErrorCode;
+
+#[derive(Accounts)]
+pub struct VoteGauge<'info> {
+    #[account(
+        seeds = [
+            b"singleton"
+        ],
+        bump = singleton.bump
+    )]
+    pub singleton: Box<Account<'info, Singleton>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"gauge-controller"
+        ],
+        bump = gauge_controller.bump
+    )]
+    pub gauge_controller: Account<'info, GaugeController>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"gauge",
+            gauge.target_vault.as_ref()
+        ],
+        bump = gauge.bump
+    )]
+    pub gauge: Account<'info, Gauge>,
+
+    #[account(
+        seeds = [
+            b"ve-lock",
+            owner.key().as_ref()
+        ],
+        bump = ve_lock.bump,
+        has_one = owner
+    )]
+    pub ve_lock: Account<'info, VeLock>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"ve-supply"
+        ],
+        bump = ve_supply.bump
+    )]
+    pub ve_supply: Box<Account<'info, VeSupply>>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = VeCheckpoint::LEN,
+        seeds = [
+            b"ve-checkpoint",
+            singleton.epoch().to_be_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub ve_checkpoint: Account<'info, VeCheckpoint>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = GaugeVoter::LEN,
+        seeds = [
+            b"gauge-voter",
+            owner.key().as_ref(),
+            singleton.epoch().to_be_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub gauge_voter: Account<'info, GaugeVoter>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = GaugeVote::LEN,
+        seeds = [
+            b"gauge-vote",
+            gauge.key().as_ref(),
+            owner.key().as_ref(),
+            singleton.epoch().to_be_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub gauge_vote: Account<'info, GaugeVote>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Votes for a gauge with `weight` basis points of the lock's voting power at this epoch's checkpoint,
+/// recording the checkpoint if this is the epoch's first vote
+pub fn vote_gauge_handler(ctx: Context<VoteGauge>, weight: u64) -> Result<()> {
+    ctx.accounts.singleton.pause.require_not_shutdown()?;
+
+    let epoch = ctx.accounts.singleton.epoch();
+
+    if !ctx.accounts.ve_checkpoint.is_recorded() {
+        *ctx.accounts.ve_checkpoint = VeCheckpoint::record(
+            &mut ctx.accounts.ve_supply,
+            epoch,
+            Clock::get()?.unix_timestamp,
+            ctx.bumps.ve_checkpoint
+        );
+    }
+
+    let gauge_voter = &mut ctx.accounts.gauge_voter;
+
+    require!(weight > 0 && gauge_voter.used_weight + weight <= MAX_VOTE_WEIGHT, ErrorCode::InvalidVoteWeight);
+
+    let power = ctx.accounts.ve_lock.voting_power(&ctx.accounts.ve_checkpoint)?;
+    let votes = weighted_votes(power, weight);
+
+    gauge_voter.used_weight += weight;
+    gauge_voter.bump = ctx.bumps.gauge_voter;
+
+    // The gauge's share of this epoch's emissions is set from the last epoch's votes before they are replaced
+    let gauge_controller = &mut ctx.accounts.gauge_controller;
+    let gauge = &mut ctx.accounts.gauge;
+
+    gauge.roll_over(epoch, gauge_controller.previous_total_votes(epoch));
+    gauge.add_votes(votes, epoch);
+    gauge_controller.add_votes(votes, epoch);
+
+    ctx.accounts.gauge_vote.votes = votes;
+    ctx.accounts.gauge_vote.bump = ctx.bumps.gauge_vote;
+
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,78 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{singleton::Singleton, governance::{Proposal, ProposalVote}, boardroom::BoardroomAccount};
+use crate::errors::ErrorCode;
+
+#[derive(Accounts)]
+pub struct VoteProposal<'info> {
+    #[account(
+        seeds = [
+            b"singleton"
+        ],
+        bump = singleton.bump
+    )]
+    pub singleton: Box<Account<'info, Singleton>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"proposal",
+            proposal.index.to_be_bytes().as_ref()
+        ],
+        bump = proposal.bump
+    )]
+    pub proposal: Box<Account<'info, Proposal>>,
+
+    #[account(
+        init,
+        payer = voter,
+        space = ProposalVote::LEN,
+        seeds = [
+            b"proposal-vote",
+            proposal.key().as_ref(),
+            voter.key().as_ref()
+        ],
+        bump
+    )]
+    pub proposal_vote: Account<'info, ProposalVote>,
+
+    #[account(
+        seeds = [
+            b"boardroom-account",
+            voter.key().as_ref()
+        ],
+        bump = boardroom_account.bump
+    )]
+    pub boardroom_account: Account<'info, BoardroomAccount>,
+
+    #[account(mut)]
+    pub voter: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Votes on a proposal with the boardroom balance, only accounts without deposits since the snapshot can vote
+pub fn vote_proposal_handler(ctx: Context<VoteProposal>, support: bool) -> Result<()> {
+    ctx.accounts.singleton.pause.require_not_shutdown()?;
+
+    let clock = Clock::get()?;
+    let boardroom_account = &ctx.accounts.boardroom_account;
+    let proposal = &mut ctx.accounts.proposal;
+
+    require_gt!(proposal.snapshot_epoch, boardroom_account.epoch_last_deposited, ErrorCode::DepositedAfterSnapshot);
+
+    let votes = boardroom_account.balance();
+    require_gt!(votes, 0, ErrorCode::NoVotingPower);
+
+    proposal.vote(votes, support, clock.unix_timestamp)?;
+
+    let proposal_vote = &mut ctx.accounts.proposal_vote;
+
+    **proposal_vote = ProposalVote {
+        votes,
+        support,
+        bump: ctx.bumps.proposal_vote,
+    };
+
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,94 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount, Transfer, transfer};
+
+use crate::state::{singleton::Singleton, ve::{VeSupply, VeLock}};
+use crate::errors::ErrorCode;
+
+#[derive(Accounts)]
+pub struct WithdrawVeLock<'info> {
+    #[account(
+        seeds = [
+            b"singleton"
+        ],
+        bump = singleton.bump
+    )]
+    pub singleton: Box<Account<'info, Singleton>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"ve-supply"
+        ],
+        bump = ve_supply.bump,
+        has_one = vault
+    )]
+    pub ve_supply: Box<Account<'info, VeSupply>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"ve-lock",
+            owner.key().as_ref()
+        ],
+        bump = ve_lock.bump,
+        has_one = owner,
+        close = owner
+    )]
+    pub ve_lock: Account<'info, VeLock>,
+
+    #[account(mut)]
+    pub vault: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        token::mint = vault.mint,
+        token::authority = owner
+    )]
+    pub receiver_account: Account<'info, TokenAccount>,
+
+    /// CHECK: This account is not read or written
+    #[account(
+        seeds = [
+            b"token-authority"
+        ],
+        bump = singleton.unit_auth_bump
+    )]
+    pub token_authority: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> WithdrawVeLock<'info> {
+    pub fn transfer_tokens_ctx(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        let cpi_accounts = Transfer {
+            from: self.vault.to_account_info(),
+            to: self.receiver_account.to_account_info(),
+            authority: self.token_authority.to_account_info(),
+        };
+
+        let cpi_program = self.token_program.to_account_info();
+
+        CpiContext::new(cpi_program, cpi_accounts)
+    }
+}
+
+/// Returns the shares of an expired lock and closes it
+pub fn withdraw_ve_lock_handler(ctx: Context<WithdrawVeLock>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let unit_auth_bump = ctx.accounts.singleton.unit_auth_bump;
+    let amount = ctx.accounts.ve_lock.point.amount;
+
+    require_gte!(now, ctx.accounts.ve_lock.point.end, ErrorCode::LockNotExpired);
+
+    // An expired lock no longer contributes to the supply, checkpointing removes its slope
+    ctx.accounts.ve_supply.curve.checkpoint(now);
+
+    let unit_auth_seed = &[&b"token-authority"[..], &[unit_auth_bump]];
+
+    transfer(ctx.accounts.transfer_tokens_ctx().with_signer(&[&unit_auth_seed[..]]), amount)?;
+
+    Ok(())
+}
\ No newline at end of file
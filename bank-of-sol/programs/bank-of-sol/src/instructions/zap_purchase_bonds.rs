@@ -0,0 +1,191 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token::{Mint, Token, TokenAccount, Burn, burn},
+    associated_token::AssociatedToken,
+};
+use raydium_amm_v3::{cpi::accounts::SwapSingle, program::AmmV3};
+
+use crate::state::{bond_account::{BondAccount, BondAccountsStore}, singleton::Singleton};
+use crate::types::pause::Subsystem;
+use crate::errors::ErrorCode;
+
+/**
+ * USDC zap
+ *
+ * Swaps USDC for Units on the oracle pool and burns the Units received for bonds in one instruction.
+ * Any tick arrays the swap crosses beyond `tick_array` are passed as remaining accounts. Raydium
+ * checks the config, vaults, observation state and tick arrays against the pool, and the pool is
+ * checked against `singleton.pool`.
+ */
+#[derive(Accounts)]
+#[instruction(_seed_index: u64)]
+pub struct ZapPurchaseBonds<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"bond-account",
+            signer.key().as_ref(),
+            _seed_index.to_be_bytes().as_ref()
+        ],
+        bump = bond_account.bump,
+        constraint = bond_account.bonds.epoch == 0 || bond_account.bonds.epoch == singleton.epoch() @ ErrorCode::BondAccountInvalidEpoch,
+        constraint = !bond_account.is_tokenized() @ ErrorCode::BondAccountTokenized,
+        constraint = _seed_index == bond_accounts_store.get_index_of_accounts() @ ErrorCode::InvalidSeedIndex
+    )]
+    pub bond_account: Box<Account<'info, BondAccount>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"bond-accounts-store",
+            signer.key().as_ref()
+        ],
+        bump = bond_accounts_store.bump,
+    )]
+    pub bond_accounts_store: Box<Account<'info, BondAccountsStore>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"singleton"
+        ],
+        bump = singleton.bump,
+        has_one = unit_mint,
+        constraint = singleton.pool == pool_state.key() @ ErrorCode::InvalidRaydiumPool
+    )]
+    pub singleton: Box<Account<'info, Singleton>>,
+
+    #[account(mut)]
+    pub unit_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = unit_mint,
+        associated_token::authority = signer
+    )]
+    pub unit_payer_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        associated_token::mint = unit_mint,
+        associated_token::authority = token_authority
+    )]
+    pub unit_custody_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::authority = signer
+    )]
+    pub usdc_payer_account: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK: This account is not read or written
+    #[account(
+        seeds = [
+            b"token-authority"
+        ],
+        bump = singleton.unit_auth_bump
+    )]
+    pub token_authority: UncheckedAccount<'info>,
+
+    /// CHECK: Checked by Raydium
+    pub amm_config: UncheckedAccount<'info>,
+
+    /// CHECK: Checked against `singleton.pool`
+    #[account(mut)]
+    pub pool_state: UncheckedAccount<'info>,
+
+    /// CHECK: Checked by Raydium against the pool
+    #[account(mut)]
+    pub input_vault: UncheckedAccount<'info>,
+
+    /// CHECK: Checked by Raydium against the pool
+    #[account(mut)]
+    pub output_vault: UncheckedAccount<'info>,
+
+    /// CHECK: Checked by Raydium against the pool
+    #[account(mut)]
+    pub observation_state: UncheckedAccount<'info>,
+
+    /// CHECK: Checked by Raydium against the pool
+    #[account(mut)]
+    pub tick_array: UncheckedAccount<'info>,
+
+    pub signer: Signer<'info>,
+
+    pub clmm_program: Program<'info, AmmV3>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+impl<'info> ZapPurchaseBonds<'info> {
+    pub fn swap_ctx(&self) -> CpiContext<'_, '_, '_, 'info, SwapSingle<'info>> {
+        let cpi_accounts = SwapSingle {
+            payer: self.signer.to_account_info(),
+            amm_config: self.amm_config.to_account_info(),
+            pool_state: self.pool_state.to_account_info(),
+            input_token_account: self.usdc_payer_account.to_account_info(),
+            output_token_account: self.unit_payer_account.to_account_info(),
+            input_vault: self.input_vault.to_account_info(),
+            output_vault: self.output_vault.to_account_info(),
+            observation_state: self.observation_state.to_account_info(),
+            token_program: self.token_program.to_account_info(),
+            tick_array: self.tick_array.to_account_info(),
+        };
+
+        let cpi_program = self.clmm_program.to_account_info();
+
+        CpiContext::new(cpi_program, cpi_accounts)
+    }
+
+    pub fn burn_tokens_ctx(&self) -> CpiContext<'_, '_, '_, 'info, Burn<'info>> {
+        let cpi_accounts = Burn {
+            mint: self.unit_mint.to_account_info(),
+            from: self.unit_payer_account.to_account_info(),
+            authority: self.signer.to_account_info(),
+        };
+
+        let cpi_program = self.token_program.to_account_info();
+
+        CpiContext::new(cpi_program, cpi_accounts)
+    }
+}
+
+/// Swaps `usdc_amount` for at least `minimum_units_out` Units and purchases bonds with them
+pub fn zap_purchase_bonds_handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, ZapPurchaseBonds<'info>>,
+    _seed_index: u64,
+    usdc_amount: u64,
+    minimum_units_out: u64,
+    sqrt_price_limit_x64: u128
+) -> Result<()> {
+    ctx.accounts.singleton.pause.require_active(Subsystem::BondPurchase)?;
+
+    require!(!ctx.accounts.singleton.bonds.token_mode, ErrorCode::BondTokenModeEnabled);
+
+    let units_before = ctx.accounts.unit_payer_account.amount;
+
+    raydium_amm_v3::cpi::swap(
+        ctx.accounts.swap_ctx().with_remaining_accounts(ctx.remaining_accounts.to_vec()),
+        usdc_amount,
+        minimum_units_out,
+        sqrt_price_limit_x64,
+        true
+    )?;
+
+    ctx.accounts.unit_payer_account.reload()?;
+
+    let units = ctx.accounts.unit_payer_account.amount - units_before;
+    require!(units >= minimum_units_out, ErrorCode::SlippageExceeded);
+
+    let circulating_supply = ctx.accounts.unit_mint.supply - ctx.accounts.unit_custody_account.amount;
+    let bond_account = &mut ctx.accounts.bond_account;
+    let singleton = &mut ctx.accounts.singleton;
+
+    let index = singleton.bonds.total_bonds_purchased;
+    let (amount, rate) = singleton.sell_bonds(units, circulating_supply, Clock::get()?.unix_timestamp)?;
+
+    bond_account.record_purchase(amount, rate, singleton.epoch(), index)?;
+
+    burn(ctx.accounts.burn_tokens_ctx(), units)?;
+
+    Ok(())
+}
\ No newline at end of file
@@ -7,6 +7,25 @@ mod state;
 mod utils;
 mod errors;
 mod types;
+mod events;
+
+use instructions::{
+    initialize::*,
+    create_bond_accounts_store::*,
+    create_bond_account::*,
+    zap_purchase_bonds::*,
+    grant_role::*,
+    revoke_role::*,
+    record_price_observation::*,
+    create_farm::*,
+    create_farm_position::*,
+    farm_deposit::*,
+    farm_withdraw::*,
+    farm_harvest::*,
+    farm_emergency_withdraw::*,
+    fund_farm::*,
+};
+use types::roles::Role;
 
 declare_id!("5Xi2uL4MB1RDnDPFHEiec6beWxFqyzFhgYpd2ACjr17m");
 
@@ -14,10 +33,65 @@ declare_id!("5Xi2uL4MB1RDnDPFHEiec6beWxFqyzFhgYpd2ACjr17m");
 pub mod bank_of_sol {
     use super::*;
 
-    pub fn initialize(ctx: Context<Initialize>) -> Result<()> {
-        Ok(())
+    pub fn initialize(ctx: Context<Initialize>, minimum_period: i64, share_max_supply: u64) -> Result<()> {
+        initialize_handler(ctx, minimum_period, share_max_supply)
+    }
+
+    pub fn create_bond_accounts_store(ctx: Context<CreateBondAccountsStore>) -> Result<()> {
+        create_bond_accounts_store_handler(ctx)
+    }
+
+    pub fn create_bond_account(ctx: Context<CreateBondAccount>) -> Result<()> {
+        create_bond_account_handler(ctx)
+    }
+
+    pub fn zap_purchase_bonds<'info>(
+        ctx: Context<'_, '_, '_, 'info, ZapPurchaseBonds<'info>>,
+        seed_index: u64,
+        usdc_amount: u64,
+        minimum_units_out: u64,
+        sqrt_price_limit_x64: u128
+    ) -> Result<()> {
+        zap_purchase_bonds_handler(ctx, seed_index, usdc_amount, minimum_units_out, sqrt_price_limit_x64)
+    }
+
+    pub fn grant_role(ctx: Context<GrantRole>, role: Role) -> Result<()> {
+        grant_role_handler(ctx, role)
+    }
+
+    pub fn revoke_role(ctx: Context<RevokeRole>, role: Role) -> Result<()> {
+        revoke_role_handler(ctx, role)
+    }
+
+    pub fn record_price_observation(ctx: Context<RecordPriceObservation>) -> Result<()> {
+        record_price_observation_handler(ctx)
     }
-}
 
-#[derive(Accounts)]
-pub struct Initialize {}
+    pub fn create_farm(ctx: Context<CreateFarm>, rate: u64) -> Result<()> {
+        create_farm_handler(ctx, rate)
+    }
+
+    pub fn create_farm_position(ctx: Context<CreateFarmPosition>) -> Result<()> {
+        create_farm_position_handler(ctx)
+    }
+
+    pub fn farm_deposit(ctx: Context<FarmDeposit>, amount: u64) -> Result<()> {
+        farm_deposit_handler(ctx, amount)
+    }
+
+    pub fn farm_withdraw(ctx: Context<FarmWithdraw>, amount: u64) -> Result<()> {
+        farm_withdraw_handler(ctx, amount)
+    }
+
+    pub fn farm_harvest(ctx: Context<FarmHarvest>) -> Result<()> {
+        farm_harvest_handler(ctx)
+    }
+
+    pub fn farm_emergency_withdraw(ctx: Context<FarmEmergencyWithdraw>) -> Result<()> {
+        farm_emergency_withdraw_handler(ctx)
+    }
+
+    pub fn fund_farm(ctx: Context<FundFarm>, amount: u64) -> Result<()> {
+        fund_farm_handler(ctx, amount)
+    }
+}
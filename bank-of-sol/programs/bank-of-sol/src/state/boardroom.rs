@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 
-use crate::types::boardroom::BoardroomStatus;
+use crate::types::boardroom::{BoardroomStatus, REWARD_PER_SHARE_PRECISION};
 
 /// A PDA for storing user boardroom state
 #[account]
@@ -17,12 +17,24 @@ pub struct BoardroomAccount {
     pub status: BoardroomStatus,
     /// Staged balances waiting to be staked (16)
     pub staged_balance: u64,
+    /// The number of verified NFTs counted towards this account's boost at the last sync (8)
+    pub staked_nfts: u64,
+    /// The balance of this account with the NFT boost applied (8)
+    pub boosted_shares: u64,
+    /// Rewards already accounted for at the current `boosted_shares` (16)
+    pub reward_debt: u128,
+    /// Rewards settled but not yet claimed (8)
+    pub pending_rewards: u64,
+    /// Share rewards already accounted for at the current `boosted_shares` (16)
+    pub share_reward_debt: u128,
+    /// Share rewards settled but not yet claimed (8)
+    pub pending_share_rewards: u64,
     /// The bump of this PDA (1)
     pub bump: u8,
 }
 
 impl BoardroomAccount {
-    pub const LEN: usize = 8 + 8 + 8 + 8 + 8 + 16 + 16 + 1;
+    pub const LEN: usize = 8 + 8 + 8 + 8 + 8 + 16 + 16 + 8 + 8 + 16 + 8 + 16 + 8 + 1;
 
     pub fn init(epoch: u64, bump: u8) -> Self {
         let clock = Clock::get().unwrap();
@@ -34,6 +46,12 @@ impl BoardroomAccount {
             epoch_last_deposited: epoch,
             status: BoardroomStatus::frozen(epoch),
             staged_balance: 0,
+            staked_nfts: 0,
+            boosted_shares: 0,
+            reward_debt: 0,
+            pending_rewards: 0,
+            share_reward_debt: 0,
+            pending_share_rewards: 0,
             bump,
         }
     }
@@ -46,6 +64,48 @@ impl BoardroomAccount {
         self.staged_balance -= amount;
     }
 
+    /// The unboosted balance used to weight this account in the boardroom
+    pub fn balance(&self) -> u64 {
+        self.shares + self.staged_balance
+    }
+
+    /// The rewards owed to this account, including those accrued since the last settlement
+    pub fn earned(&self, reward_per_share: u128) -> u64 {
+        let accrued = (self.boosted_shares as u128 * reward_per_share / REWARD_PER_SHARE_PRECISION)
+            .saturating_sub(self.reward_debt);
+
+        self.pending_rewards + accrued as u64
+    }
+
+    pub fn settle_rewards(&mut self, reward_per_share: u128) {
+        self.pending_rewards = self.earned(reward_per_share);
+        self.reward_debt = self.boosted_shares as u128 * reward_per_share / REWARD_PER_SHARE_PRECISION;
+    }
+
+    /// Must only be called after `settle_rewards` so that no accrued rewards are lost
+    pub fn set_boosted_shares(&mut self, boosted_shares: u64, reward_per_share: u128) {
+        self.boosted_shares = boosted_shares;
+        self.reward_debt = boosted_shares as u128 * reward_per_share / REWARD_PER_SHARE_PRECISION;
+    }
+
+    /// The share rewards owed to this account, including those accrued since the last settlement
+    pub fn earned_shares(&self, share_reward_per_share: u128) -> u64 {
+        let accrued = (self.boosted_shares as u128 * share_reward_per_share / REWARD_PER_SHARE_PRECISION)
+            .saturating_sub(self.share_reward_debt);
+
+        self.pending_share_rewards + accrued as u64
+    }
+
+    pub fn settle_share_rewards(&mut self, share_reward_per_share: u128) {
+        self.pending_share_rewards = self.earned_shares(share_reward_per_share);
+        self.set_share_reward_debt(share_reward_per_share);
+    }
+
+    /// Must only be called after `settle_share_rewards` and `set_boosted_shares` so that no accrued share rewards are lost
+    pub fn set_share_reward_debt(&mut self, share_reward_per_share: u128) {
+        self.share_reward_debt = self.boosted_shares as u128 * share_reward_per_share / REWARD_PER_SHARE_PRECISION;
+    }
+
     pub fn only_frozen_or_locked(&self) -> bool {
         match self.status {
             BoardroomStatus::Frozen { .. } => true,
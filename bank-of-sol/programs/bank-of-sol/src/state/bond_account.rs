@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
 
 use crate::types::bond::UserBondStorage;
+use crate::errors::ErrorCode;
 
 /**
  * Bond PDA architecture
@@ -16,6 +17,19 @@ use crate::types::bond::UserBondStorage;
  * When a user completely redeems the bonds from one of the bond accounts, the PDA is closed,
  * an offset number is incremented to keep track of which account is now the oldest.
  * 
+ * Tokenized bond accounts are redeemed by their NFT holder and closed once empty, the owner skips them
+ * when they become the oldest so that they can't block the owner's other accounts.
+ * 
+ * Bonds are redeemed first in first out across all users. Each account's bonds hold the positions
+ * `[index, index + amount)` of the queue of all bonds ever purchased, and can only be redeemed once
+ * `BondStorage::redeemable_index` has passed them. Redeeming takes bonds from the front of the account.
+ * 
+ * Seed order follows queue order, so the oldest account always holds the user's first bonds to be funded.
+ * Bonds are therefore only purchased into and split off the newest account, whose split off bonds take the
+ * tail of its `index` range in a new PDA at the end of the range. Merging closes the source PDA, which must
+ * be the oldest or the newest so that the open seeds stay contiguous, and the two accounts must hold
+ * adjacent places in the queue so that the merged account still holds a single range.
+ * 
  */
 
 /// A PDA for storing the number of bond accounts for a particular user
@@ -43,12 +57,23 @@ impl BondAccountsStore {
     pub fn increment(&mut self) {
         self.number_of_accounts += 1;
     }
-    // TODO: this is probably very broken
+    /// Must only be called when the oldest bond account is closed
     pub fn pda_closed(&mut self) {
         self.number_of_accounts -= 1;
         self.offset += 1;
     }
 
+    /// Must only be called when the oldest bond account is tokenized or closed, a tokenized account is
+    /// redeemed by its NFT holder instead
+    pub fn oldest_skipped(&mut self) {
+        self.pda_closed();
+    }
+
+    /// Must only be called when the newest bond account is closed
+    pub fn newest_closed(&mut self) {
+        self.number_of_accounts -= 1;
+    }
+
     pub fn get_index_of_accounts(&self) -> u64 {
         self.offset + self.number_of_accounts
     }
@@ -56,6 +81,11 @@ impl BondAccountsStore {
     pub fn get_offset(&self) -> u64 {
         self.offset
     }
+
+    /// The seed index of the oldest open bond account
+    pub fn get_oldest(&self) -> u64 {
+        self.offset + 1
+    }
 }
 
 #[account]
@@ -66,12 +96,14 @@ pub struct BondAccount {
     pub bonds: UserBondStorage,
     /// The index of these bonds; the total amount of bonds purchased over all time (8)
     pub index: u64,
+    /// The mint of the NFT entitled to redeem these bonds, the default pubkey if not tokenized (32)
+    pub nft_mint: Pubkey,
     /// The bump of this PDA (1)
     pub bump: u8,
 }
 
 impl BondAccount {
-    pub const LEN: usize = 8 + 32 + 24 + 1;
+    pub const LEN: usize = 8 + 32 + 24 + 8 + 32 + 1;
 
     pub fn init(user: Pubkey, bump: u8, amount: u64, epoch: u64, interest_rate: u64, index: u64) -> Self {
         Self {
@@ -79,6 +111,61 @@ impl BondAccount {
             bonds: UserBondStorage::new(amount, epoch, interest_rate),
             bump,
             index,
+            nft_mint: Pubkey::default(),
         }
     }
+
+    /// Records bonds purchased in `epoch`, `index` is the number of bonds purchased before them. Bonds can only
+    /// be added to the latest purchase so that this account's bonds stay in one place in the redemption queue
+    pub fn record_purchase(&mut self, amount: u64, interest_rate: u64, epoch: u64, index: u64) -> Result<()> {
+        if self.bonds.amount == 0 {
+            self.index = index;
+        }
+
+        require_eq!(self.index + self.bonds.amount, index, ErrorCode::BondQueuePositionTaken);
+
+        self.bonds.epoch = epoch;
+        self.bonds.add(amount, interest_rate);
+
+        Ok(())
+    }
+
+    /// Removes `amount` bonds from the front of this account's place in the redemption queue
+    pub fn bonds_removed(&mut self, amount: u64) {
+        self.bonds.amount -= amount;
+        self.index += amount;
+    }
+
+    /// Whether `other` holds bonds on the same terms, so that the two can be merged
+    pub fn is_mergeable_with(&self, other: &BondAccount) -> bool {
+        self.bonds.epoch == other.bonds.epoch && self.bonds.interest_rate == other.bonds.interest_rate
+    }
+
+    /// Removes `amount` bonds from the back of this account's place in the redemption queue,
+    /// returning the index of the removed bonds
+    pub fn split_off(&mut self, amount: u64) -> Result<u64> {
+        require!(amount > 0 && amount < self.bonds.amount, ErrorCode::InvalidSplitAmount);
+
+        self.bonds.amount -= amount;
+
+        Ok(self.index + self.bonds.amount)
+    }
+
+    /// Adds the bonds of `source`, which must be directly before or after this account's bonds in the queue
+    pub fn merge(&mut self, source: &BondAccount) -> Result<()> {
+        require!(self.is_mergeable_with(source), ErrorCode::BondAccountsNotMergeable);
+        require!(
+            self.index + self.bonds.amount == source.index || source.index + source.bonds.amount == self.index,
+            ErrorCode::BondAccountsNotAdjacent
+        );
+
+        self.bonds.amount += source.bonds.amount;
+        self.index = self.index.min(source.index);
+
+        Ok(())
+    }
+
+    pub fn is_tokenized(&self) -> bool {
+        self.nft_mint != Pubkey::default()
+    }
 }
\ No newline at end of file
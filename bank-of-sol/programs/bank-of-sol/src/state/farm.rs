@@ -0,0 +1,112 @@
+use anchor_lang::prelude::*;
+
+use crate::types::farm::{FarmRewards, RangeStake, earned};
+
+/**
+ * Farm PDA architecture
+ *
+ * A farm is derived from the fungible mint it stakes. Staked tokens and rewards are held in vaults
+ * derived from the farm and owned by the token authority PDA. Anyone can fund the reward vault, shares
+ * can also be allocated from emissions. Each staker has a `FarmPosition` derived from the farm
+ * and their key.
+ *
+ * Rewards accrue through a reward-per-token accumulator, which must be updated before `total_staked`
+ * or `rate` change. Units farms pay the rate set by an admin. Share farms start without a rate, it is
+ * set by streaming the emissions their gauge distributes, or shares an admin allocates, over the next
+ * `EMISSION_PERIOD`. A position settles its rewards against the accumulator before its amount changes.
+ * Harvests pay out at most the reward vault's balance, anything unpaid stays pending.
+ */
+#[account]
+pub struct Farm {
+    /// The mint of the staked tokens (32)
+    pub stake_mint: Pubkey,
+    /// The mint rewards are paid in, Units or shares (32)
+    pub reward_mint: Pubkey,
+    /// The vault holding the staked tokens (32)
+    pub stake_vault: Pubkey,
+    /// The vault rewards are paid from (32)
+    pub reward_vault: Pubkey,
+    /// The reward accumulator (48)
+    pub rewards: FarmRewards,
+    /// The bump of this PDA (1)
+    pub bump: u8,
+}
+
+impl Farm {
+    pub const LEN: usize = 8 + 32 + 32 + 32 + 32 + 48 + 1;
+}
+
+/// A PDA for storing a user's stake in a farm
+#[account]
+pub struct FarmPosition {
+    /// The farm staked in (32)
+    pub farm: Pubkey,
+    /// The owner of the position (32)
+    pub owner: Pubkey,
+    /// The amount staked (8)
+    pub amount: u64,
+    /// The farm's reward per token at the last settlement (16)
+    pub reward_per_token_paid: u128,
+    /// Rewards settled but not yet harvested (8)
+    pub pending_rewards: u64,
+    /// The bump of this PDA (1)
+    pub bump: u8,
+}
+
+impl FarmPosition {
+    pub const LEN: usize = 8 + 32 + 32 + 8 + 16 + 8 + 1;
+
+    pub fn init(farm: Pubkey, owner: Pubkey, reward_per_token: u128, bump: u8) -> Self {
+        Self {
+            farm,
+            owner,
+            amount: 0,
+            reward_per_token_paid: reward_per_token,
+            pending_rewards: 0,
+            bump,
+        }
+    }
+
+    pub fn settle_rewards(&mut self, reward_per_token: u128) {
+        self.pending_rewards += earned(self.amount, reward_per_token, self.reward_per_token_paid);
+        self.reward_per_token_paid = reward_per_token;
+    }
+}
+
+/// A PDA for storing a farm of Raydium CLMM positions in the oracle pool, see `RangeStake`
+#[account]
+pub struct ClmmFarm {
+    /// The Raydium pool the staked positions provide liquidity to (32)
+    pub pool: Pubkey,
+    /// The mint rewards are paid in, Units or shares (32)
+    pub reward_mint: Pubkey,
+    /// The vault rewards are paid from (32)
+    pub reward_vault: Pubkey,
+    /// The reward accumulator over the liquidity of in range positions (48)
+    pub rewards: FarmRewards,
+    /// The bump of this PDA (1)
+    pub bump: u8,
+}
+
+impl ClmmFarm {
+    pub const LEN: usize = 8 + 32 + 32 + 32 + 48 + 1;
+}
+
+/// A PDA for storing a staked Raydium position, derived from the farm and the position NFT
+#[account]
+pub struct ClmmFarmPosition {
+    /// The farm staked in (32)
+    pub farm: Pubkey,
+    /// The owner of the position (32)
+    pub owner: Pubkey,
+    /// The mint of the position NFT (32)
+    pub nft_mint: Pubkey,
+    /// The position's stake in the farm (41)
+    pub stake: RangeStake,
+    /// The bump of this PDA (1)
+    pub bump: u8,
+}
+
+impl ClmmFarmPosition {
+    pub const LEN: usize = 8 + 32 + 32 + 32 + 41 + 1;
+}
\ No newline at end of file
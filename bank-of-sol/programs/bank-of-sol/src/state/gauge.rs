@@ -0,0 +1,161 @@
+use anchor_lang::prelude::*;
+
+use crate::types::gauge::{emission_share, EmissionsBudget, GaugeTarget};
+
+/**
+ * Gauge voting architecture
+ *
+ * Each share-paying reward pool has a `Gauge`, derived from the pool's reward vault. Share farms, CLMM
+ * farms, the boardroom and NFT staking can all have gauges, the boardroom and NFT staking are paid into
+ * vaults created with the controller. During an epoch, ve lock holders split up to `MAX_VOTE_WEIGHT` of
+ * their voting power at the epoch's `VeCheckpoint` across gauges, tracked per voter and epoch in a
+ * `GaugeVoter`.
+ *
+ * Nothing happens to gauges when the epoch advances. The first vote for or distribution of a gauge in
+ * an epoch sets its share of the epoch's emissions from the votes cast in the last one, and the first
+ * distribution starts the epoch's `EmissionsBudget`. Shares are added to the budget as `emit_shares`
+ * mints them, so emissions reach the pools without the authority moving them. Anyone can distribute a
+ * gauge's part of the budget once per epoch. Farms stream it to their stakers by setting their reward
+ * rate, so votes decide the emission rates. Anything not distributed joins the next budget.
+ */
+#[account]
+pub struct GaugeController {
+    /// The number of gauges (8)
+    pub gauge_count: u64,
+    /// The epoch votes are being cast in (8)
+    pub voting_epoch: u64,
+    /// The total votes cast in `voting_epoch` (8)
+    pub total_votes: u64,
+    /// The epoch votes were cast in before `voting_epoch` (8)
+    pub previous_voting_epoch: u64,
+    /// The total votes cast in `previous_voting_epoch` (8)
+    pub previous_total_votes: u64,
+    /// The emitted shares distributed between gauges (32)
+    pub budget: EmissionsBudget,
+    /// The vault boardroom share rewards are paid from (32)
+    pub boardroom_vault: Pubkey,
+    /// The vault NFT staking share rewards are paid from (32)
+    pub nft_staking_vault: Pubkey,
+    /// The bump of this PDA (1)
+    pub bump: u8,
+}
+
+impl GaugeController {
+    pub const LEN: usize = 8 + 8 + 8 + 8 + 8 + 8 + 32 + 32 + 32 + 1;
+
+    pub fn add_votes(&mut self, votes: u64, epoch: u64) {
+        if self.voting_epoch != epoch {
+            self.previous_voting_epoch = self.voting_epoch;
+            self.previous_total_votes = self.total_votes;
+            self.voting_epoch = epoch;
+            self.total_votes = 0;
+        }
+
+        self.total_votes += votes;
+    }
+
+    /// The total votes cast in `epoch`
+    pub fn total_votes(&self, epoch: u64) -> u64 {
+        if self.voting_epoch == epoch {
+            self.total_votes
+        } else if self.previous_voting_epoch == epoch {
+            self.previous_total_votes
+        } else {
+            0
+        }
+    }
+
+    /// The total votes cast in the epoch before `epoch`, which set the gauges' shares of its emissions
+    pub fn previous_total_votes(&self, epoch: u64) -> u64 {
+        epoch.checked_sub(1).map_or(0, |previous| self.total_votes(previous))
+    }
+}
+
+/// A PDA for storing a reward pool's votes and share of emissions
+#[account]
+pub struct Gauge {
+    /// The kind of pool emissions are distributed to (1)
+    pub target: GaugeTarget,
+    /// The farm or CLMM farm emissions are distributed to, default for other targets (32)
+    pub pool: Pubkey,
+    /// The reward vault of the pool emissions are distributed to (32)
+    pub target_vault: Pubkey,
+    /// The epoch votes are being cast in (8)
+    pub voting_epoch: u64,
+    /// The votes cast in `voting_epoch` (8)
+    pub votes: u64,
+    /// The share of the emissions budget, scaled by `INTEREST_RATE_PRECISION` (8)
+    pub emission_share: u64,
+    /// The epoch `emission_share` was set for (8)
+    pub share_epoch: u64,
+    /// The last epoch emissions were distributed in (8)
+    pub distributed_epoch: u64,
+    /// The bump of this PDA (1)
+    pub bump: u8,
+}
+
+impl Gauge {
+    pub const LEN: usize = 8 + 1 + 32 + 32 + 8 + 8 + 8 + 8 + 8 + 1;
+
+    /// Must be called before `add_votes` so that the votes of the last epoch are counted
+    pub fn add_votes(&mut self, votes: u64, epoch: u64) {
+        if self.voting_epoch != epoch {
+            self.voting_epoch = epoch;
+            self.votes = 0;
+        }
+
+        self.votes += votes;
+    }
+
+    /// Sets the share of `epoch`'s emissions from the votes cast in the epoch before, once per epoch
+    pub fn roll_over(&mut self, epoch: u64, previous_total_votes: u64) {
+        if self.share_epoch == epoch {
+            return;
+        }
+
+        let votes = if self.voting_epoch + 1 == epoch { self.votes } else { 0 };
+
+        self.emission_share = emission_share(votes, previous_total_votes);
+        self.share_epoch = epoch;
+    }
+
+    /// Takes the emissions owed from the controller's budget of `epoch`, zero if already distributed
+    pub fn distribute(&mut self, controller: &mut GaugeController, epoch: u64) -> u64 {
+        self.roll_over(epoch, controller.previous_total_votes(epoch));
+        controller.budget.roll_over(epoch);
+
+        if self.distributed_epoch == epoch {
+            return 0;
+        }
+
+        self.distributed_epoch = epoch;
+
+        controller.budget.take(self.emission_share)
+    }
+}
+
+/// A PDA for storing the vote weight a voter has allocated in an epoch
+#[account]
+pub struct GaugeVoter {
+    /// The weight allocated, in basis points (8)
+    pub used_weight: u64,
+    /// The bump of this PDA (1)
+    pub bump: u8,
+}
+
+impl GaugeVoter {
+    pub const LEN: usize = 8 + 8 + 1;
+}
+
+/// A PDA marking that a voter has voted for a gauge in an epoch
+#[account]
+pub struct GaugeVote {
+    /// The votes cast (8)
+    pub votes: u64,
+    /// The bump of this PDA (1)
+    pub bump: u8,
+}
+
+impl GaugeVote {
+    pub const LEN: usize = 8 + 8 + 1;
+}
\ No newline at end of file
@@ -0,0 +1,99 @@
+use anchor_lang::prelude::*;
+
+use crate::types::governance::{GovernanceParams, ConfigChange};
+use crate::errors::ErrorCode;
+
+/**
+ * Governance architecture
+ *
+ * Boardroom depositors propose and vote on `ConfigChange`s, each proposal is a `Proposal` PDA indexed
+ * by `Governance.proposal_count`. Voting power is the unboosted boardroom balance, snapshotted at the
+ * proposal's epoch: an account can only vote if it hasn't deposited since before that epoch, so its
+ * balance can only have decreased since the snapshot and moving deposits between accounts can't be used
+ * to vote twice. Quorum is measured against the total boardroom deposits when the proposal was created.
+ *
+ * Once voting has ended anyone can execute a passed proposal, which queues its change in the timelock.
+ * It applies to the Singleton when the queued action is executed after the timelock's delay.
+ */
+#[account]
+pub struct Governance {
+    /// The voting rules of new proposals (24)
+    pub params: GovernanceParams,
+    /// The number of proposals created (8)
+    pub proposal_count: u64,
+    /// The bump of this PDA (1)
+    pub bump: u8,
+}
+
+impl Governance {
+    pub const LEN: usize = 8 + 24 + 8 + 1;
+}
+
+/// A PDA for storing a proposed change and its votes
+#[account]
+pub struct Proposal {
+    /// The index of this proposal (8)
+    pub index: u64,
+    /// The creator of this proposal (32)
+    pub proposer: Pubkey,
+    /// The change applied if the proposal passes (114)
+    pub change: ConfigChange,
+    /// The voting rules at creation (24)
+    pub params: GovernanceParams,
+    /// The epoch voting power is snapshotted at (8)
+    pub snapshot_epoch: u64,
+    /// The total boardroom deposits at creation, quorum is measured against it (8)
+    pub snapshot_supply: u64,
+    /// The timestamp voting ends at (8)
+    pub voting_ends: i64,
+    /// The votes in favour (8)
+    pub votes_for: u64,
+    /// The votes against (8)
+    pub votes_against: u64,
+    /// Whether the change has been applied (1)
+    pub executed: bool,
+    /// The bump of this PDA (1)
+    pub bump: u8,
+}
+
+impl Proposal {
+    pub const LEN: usize = 8 + 8 + 32 + ConfigChange::LEN + 24 + 8 + 8 + 8 + 8 + 8 + 1 + 1;
+
+    pub fn vote(&mut self, votes: u64, support: bool, now: i64) -> Result<()> {
+        require_gt!(self.voting_ends, now, ErrorCode::VotingEnded);
+
+        if support {
+            self.votes_for += votes;
+        } else {
+            self.votes_against += votes;
+        }
+
+        Ok(())
+    }
+
+    /// Marks the proposal executed, failing unless voting has ended and it passed
+    pub fn execute(&mut self, now: i64) -> Result<ConfigChange> {
+        require!(!self.executed, ErrorCode::ProposalExecuted);
+        require_gte!(now, self.voting_ends, ErrorCode::VotingNotEnded);
+        require!(self.params.passed(self.votes_for, self.votes_against, self.snapshot_supply), ErrorCode::ProposalNotPassed);
+
+        self.executed = true;
+
+        Ok(self.change)
+    }
+}
+
+/// A PDA marking that a voter has voted on a proposal
+#[account]
+pub struct ProposalVote {
+    /// The votes cast (8)
+    pub votes: u64,
+    /// Whether the votes are in favour (1)
+    pub support: bool,
+    /// The bump of this PDA (1)
+    pub bump: u8,
+}
+
+impl ProposalVote {
+    pub const LEN: usize = 8 + 8 + 1 + 1;
+}
\ No newline at end of file
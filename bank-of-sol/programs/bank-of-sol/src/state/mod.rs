@@ -1,4 +1,12 @@
 pub mod singleton;
 pub mod bond_account;
 pub mod nft_stake_record;
-pub mod boardroom;
\ No newline at end of file
+pub mod boardroom;
+pub mod treasury;
+pub mod vesting_schedule;
+pub mod farm;
+pub mod ve;
+pub mod gauge;
+pub mod governance;
+pub mod timelock;
+pub mod roles;
\ No newline at end of file
@@ -1,5 +1,18 @@
 use anchor_lang::prelude::*;
 
+use crate::types::farm::earned;
+
+/**
+ * Stake record PDA architecture
+ *
+ * Each staked NFT gets a stake record PDA seeded by the staker and a seed index. Seed indexes
+ * are handed out in order, the next stake record is created at `get_index_of_accounts() + 1`
+ * and `increment` has to be called once it is created so that the next stake doesn't collide
+ * with it. Open records are found at seed indexes in `(get_offset(), get_index_of_accounts()]`.
+ *
+ * Share rewards from the NFT staking gauge accrue per staked NFT and are settled on the index, so they
+ * survive the records being closed on unstake.
+ */
 /// A PDA for storing the index of stake accounts
 #[account]
 pub struct StakeRecordIndex {
@@ -7,25 +20,42 @@ pub struct StakeRecordIndex {
     pub index: u64,
     /// The offset; number of stake accounts closed (8)
     pub offset: u64,
+    /// The share rewards per staked NFT at the last settlement (16)
+    pub share_reward_per_nft_paid: u128,
+    /// Share rewards settled but not yet claimed (8)
+    pub pending_share_rewards: u64,
     /// The bump of this PDA (1)
     pub bump: u8,
 }
 
 impl StakeRecordIndex {
-    pub const LEN: usize = 8 + 8 + 8 + 1;
+    pub const LEN: usize = 8 + 8 + 8 + 16 + 8 + 1;
 
     pub fn init(bump: u8) -> Self {
         Self {
             index: 0,
             offset: 0,
+            share_reward_per_nft_paid: 0,
+            pending_share_rewards: 0,
             bump,
         }
     }
 
+    /// Must be called before the number of staked NFTs changes
+    pub fn settle_share_rewards(&mut self, share_reward_per_nft: u128) {
+        self.pending_share_rewards += earned(self.index, share_reward_per_nft, self.share_reward_per_nft_paid);
+        self.share_reward_per_nft_paid = share_reward_per_nft;
+    }
+
     pub fn get_index_of_accounts(&self) -> u64 {
         self.index + self.offset
     }
 
+    /// Must be called whenever a stake record is created at the next seed index
+    pub fn increment(&mut self) {
+        self.index += 1;
+    }
+
     pub fn get_offset(&self) -> u64 {
         self.offset
     }
@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+use crate::types::roles::{Role, Roles};
+
+/**
+ * Access control architecture
+ *
+ * Privileged instructions are restricted to holders of a `Role`. The roles of a key are stored in a
+ * `RoleMember` PDA derived from it, and each privileged instruction derives the PDA of its signer and
+ * checks the one role it needs.
+ *
+ * The Singleton's authority grants and revokes roles and performs one-off setup such as initializing
+ * the treasury, ve locks, gauges, governance and the timelock. It holds no role implicitly and has to
+ * grant itself any it needs. Once the authority is renounced every role lapses and the actions queued
+ * until then can no longer be executed, leaving parameter changes to governance.
+ */
+#[account]
+pub struct RoleMember {
+    /// The key holding the roles (32)
+    pub member: Pubkey,
+    /// The roles held (1)
+    pub roles: Roles,
+    /// The bump of this PDA (1)
+    pub bump: u8,
+}
+
+impl RoleMember {
+    pub const LEN: usize = 8 + 32 + 1 + 1;
+
+    pub fn has_role(&self, role: Role) -> bool {
+        self.roles.has(role)
+    }
+}
\ No newline at end of file
@@ -2,7 +2,13 @@ use std::ops::{Add, Sub};
 
 use anchor_lang::prelude::*;
 use muldiv::*;
-use crate::types::{epoch::Epoch, oracle::PriceStorage, bond::BondStorage, nft_staking::NFTStakingStorage, custody::CustodyAccounts, boardroom::BoardroomBalances};
+use crate::types::{epoch::Epoch, oracle::PriceStorage, bond::{validate_debt_ceiling, BondStorage, BondTerms, BondAuction, RedemptionPremium}, nft_staking::NFTStakingStorage, custody::CustodyAccounts, pol::PolStorage, share::ShareStorage, pause::PauseState, boardroom::{BoardroomBalances, BoardroomBoost, BoardroomDepositToken, REWARD_PER_SHARE_PRECISION}};
+use crate::types::{interest_rate::{InterestRateConfig, INTEREST_RATE_PRECISION, ONE_HUNDRED_PCT}, oracle::PEG, pid::PidController};
+use crate::types::governance::ConfigChange;
+use crate::types::monetary_policy::{MonetaryPolicy, MonetaryPolicyKind, PolicyParams, PolicyContext, PolicyDecision, BasisPolicy, TombPolicy, FixedSchedulePolicy, PidPolicy};
+use crate::types::roles::Role;
+use crate::state::{boardroom::BoardroomAccount, bond_account::BondAccount, roles::RoleMember};
+use crate::errors::ErrorCode;
 
 #[account]
 pub struct Singleton {
@@ -10,13 +16,13 @@ pub struct Singleton {
     pub authority: Pubkey,
     /// The Raydium AMMv3 pool used by the oracle (32)
     pub pool: Pubkey,
-    /// The current epoch (40)
+    /// The current epoch (56)
     pub epoch: Epoch,
-    /// The last 5 price observations (200)
+    /// The last 5 price observations and their time weighted accumulation (240)
     pub price_observations: PriceStorage,
-    /// Bond info (40)
+    /// Bond info (186)
     pub bonds: BondStorage,
-    /// NFT Staking info (80)
+    /// NFT Staking info (104)
     pub nft_staking: NFTStakingStorage,
     /// Unit token mint (32)
     pub unit_mint: Pubkey,
@@ -24,19 +30,37 @@ pub struct Singleton {
     pub token_authority: Pubkey,
     /// The token custody accounts (64)
     pub custody: CustodyAccounts,
-    /// Total deposits into boardroom
+    /// Total deposits into boardroom (49)
     pub boardroom_deposits: BoardroomBalances,
+    /// The boardroom boost for staked NFTs (24)
+    pub boardroom_boost: BoardroomBoost,
+    /// The bond interest rate curve (81)
+    pub interest_rate_config: InterestRateConfig,
+    /// The PID controller, used by `MonetaryPolicyKind::Pid` (72)
+    pub pid: PidController,
+    /// The policy applied when advancing the epoch (1)
+    pub monetary_policy: MonetaryPolicyKind,
+    /// The parameters of the monetary policies (112)
+    pub policy_params: PolicyParams,
+    /// The protocol-owned liquidity (64)
+    pub pol: PolStorage,
+    /// The share token and its emission (112)
+    pub share: ShareStorage,
+    /// The paused subsystems and emergency shutdown (3)
+    pub pause: PauseState,
     /// The bump of `token_authority` (1)
     pub unit_auth_bump: u8,
     /// The bump of the Singleton PDA (1)
     pub bump: u8,
+    /// The authority proposed by `authority`, which has to accept the transfer (32)
+    pub pending_authority: Pubkey,
 }
 
 impl Singleton {
-    pub const LEN: usize = 8 + 32 + 32 + 40 + 200 + 40 + 80 + 32 + 32 + 64 + 1 + 1;
+    pub const LEN: usize = 8 + 32 + 32 + 56 + 240 + 186 + 104 + 32 + 32 + 64 + 49 + 24 + 81 + 72 + 1 + 112 + 64 + 112 + 3 + 1 + 1 + 32;
 
-    const INTEREST_RATE_PRECISION: u64 = 1_000_000;
-    const ONE_HUNDRED_PCT: u64 = 100 * Self::INTEREST_RATE_PRECISION;
+    pub const INTEREST_RATE_PRECISION: u64 = INTEREST_RATE_PRECISION;
+    pub const ONE_HUNDRED_PCT: u64 = ONE_HUNDRED_PCT;
 
     pub fn init(
         authority: Pubkey, 
@@ -45,7 +69,7 @@ impl Singleton {
         unit_mint: Pubkey, 
         token_authority: Pubkey, 
         unit_custody: Pubkey,
-        unit_usdc_lp_custody: Pubkey,
+        share: ShareStorage,
         unit_auth_bump: u8, 
         bump: u8
     ) -> Self {
@@ -58,15 +82,101 @@ impl Singleton {
             nft_staking: NFTStakingStorage::default(),
             unit_mint,
             token_authority,
-            custody: CustodyAccounts::init(unit_custody, unit_usdc_lp_custody),
+            custody: CustodyAccounts::init(unit_custody),
             boardroom_deposits: BoardroomBalances::default(),
+            boardroom_boost: BoardroomBoost::default(),
+            interest_rate_config: InterestRateConfig::default(),
+            pid: PidController::default(),
+            monetary_policy: MonetaryPolicyKind::default(),
+            policy_params: PolicyParams::default(),
+            pol: PolStorage::default(),
+            share,
+            pause: PauseState::default(),
             unit_auth_bump,
             bump,
+            pending_authority: Pubkey::default(),
         }
     }
 
-    pub fn advance(&mut self) {
-        self.epoch.advance(true, 10).unwrap();
+    /// Advances the epoch, deciding the next epoch's policy from the TWAP since the last advance
+    pub fn advance(&mut self, supply: u64) -> Result<PolicyDecision> {
+        let twap = self.price_observations.twap()?;
+        self.price_observations.new_window();
+
+        let ctx = PolicyContext {
+            epoch: self.epoch.index + 1,
+            twap,
+            supply,
+            outstanding_bonds: self.bonds.outstanding(),
+        };
+
+        let decision = match self.monetary_policy {
+            MonetaryPolicyKind::Basis => BasisPolicy { params: &self.policy_params, rates: &self.interest_rate_config }.decide(&ctx),
+            MonetaryPolicyKind::Tomb => TombPolicy { params: &self.policy_params, rates: &self.interest_rate_config }.decide(&ctx),
+            MonetaryPolicyKind::FixedSchedule => FixedSchedulePolicy { schedule: &self.policy_params.schedule }.decide(&ctx),
+            MonetaryPolicyKind::Pid => PidPolicy { params: &self.policy_params, pid: &mut self.pid }.decide(&ctx),
+        };
+
+        self.epoch = self.epoch.advance(twap >= PEG, twap, decision.interest_rate, decision.expansion_rate(supply))?;
+        self.bonds.new_epoch(decision.available_bonds.min(self.bonds.debt_headroom(supply)));
+
+        Ok(decision)
+    }
+
+    /// Applies a parameter change with the same validation as the corresponding `configure_*` instruction
+    pub fn apply_config_change(&mut self, change: ConfigChange) -> Result<()> {
+        match change {
+            ConfigChange::InterestRate { curve, floor, ceiling } => {
+                self.interest_rate_config = InterestRateConfig::init(curve, floor, ceiling)?;
+            },
+            ConfigChange::DebtCeiling { debt_ceiling } => {
+                validate_debt_ceiling(debt_ceiling)?;
+
+                self.bonds.debt_ceiling = debt_ceiling;
+            },
+            ConfigChange::BondTerms { maturity, expiry, premium_per_epoch, max_hold_premium } => {
+                self.bonds.set_terms(BondTerms::init(maturity, expiry, premium_per_epoch, max_hold_premium)?)?;
+            },
+            ConfigChange::BondAuction { enabled, start_discount, max_discount, duration } => {
+                self.bonds.auction = BondAuction::init(enabled, start_discount, max_discount, duration)?;
+            },
+            ConfigChange::RedemptionPremium { threshold, multiplier, max_premium } => {
+                self.bonds.premium = RedemptionPremium::init(threshold, multiplier, max_premium)?;
+            },
+            ConfigChange::BoardroomBoost { boost_per_nft, max_boosted_nfts, max_boost } => {
+                self.boardroom_boost = BoardroomBoost::init(boost_per_nft, max_boosted_nfts, max_boost);
+            },
+            ConfigChange::MonetaryPolicy { kind, params } => {
+                params.validate(kind)?;
+
+                self.monetary_policy = kind;
+                self.policy_params = params;
+            },
+            ConfigChange::Pid { gains, integral_limit, max_expansion_rate, max_bond_discount } => {
+                self.pid = PidController::init(gains, integral_limit, max_expansion_rate, max_bond_discount);
+            },
+            ConfigChange::PolShare { seigniorage_share } => {
+                self.pol.set_seigniorage_share(seigniorage_share)?;
+            },
+            ConfigChange::Collection { collection } => {
+                self.nft_staking.collection = collection;
+            },
+            ConfigChange::MinimumPeriod { minimum_period } => {
+                self.nft_staking.minimum_period = minimum_period;
+            },
+        }
+
+        Ok(())
+    }
+
+    /// Whether the authority has been renounced, disabling the authority's and `Role::Admin`'s instructions
+    pub fn renounced(&self) -> bool {
+        self.authority == Pubkey::default()
+    }
+
+    /// Whether `member` holds `role`, every role lapses once the authority is renounced
+    pub fn has_role(&self, member: &RoleMember, role: Role) -> bool {
+        member.has_role(role) && !self.renounced()
     }
 
     pub fn interest_rate(&self) -> u64 {
@@ -77,21 +187,88 @@ impl Singleton {
         self.epoch.index
     }
 
-    pub fn units_to_bonds(&self, units: u64) -> u64 {
+    /// The rate bonds are sold at `now`, the auction's discount when enabled or the epoch's interest rate otherwise
+    pub fn bond_rate(&self, now: i64) -> u64 {
+        if self.bonds.auction.enabled {
+            self.bonds.auction.discount(now - self.epoch.timestamp)
+        } else {
+            self.interest_rate()
+        }
+    }
+
+    pub fn units_to_bonds(&self, units: u64, rate: u64) -> u64 {
         let bonds = units.mul_div_floor(
-            self.interest_rate().add(Self::ONE_HUNDRED_PCT), 
+            rate.add(Self::ONE_HUNDRED_PCT), 
             Self::ONE_HUNDRED_PCT
         ).unwrap();
 
         bonds
     }
 
+    /// Checks that `amount` bonds can be issued this epoch without exceeding the debt ceiling
+    pub fn check_bond_issuance(&self, amount: u64, circulating_supply: u64) -> Result<()> {
+        require_gte!(self.bonds.available_bonds, amount, ErrorCode::InsufficientAvailableBonds);
+        require_gte!(self.bonds.debt_headroom(circulating_supply), amount, ErrorCode::DebtCeilingExceeded);
+
+        Ok(())
+    }
+
+    /// Sells bonds for `units` at the rate at `now`, returning the bonds sold and the rate they were sold at
+    pub fn sell_bonds(&mut self, units: u64, circulating_supply: u64, now: i64) -> Result<(u64, u64)> {
+        let rate = self.bond_rate(now);
+        let amount = self.units_to_bonds(units, rate);
+
+        self.check_bond_issuance(amount, circulating_supply)?;
+        self.bonds_purchased(amount);
+
+        Ok((amount, rate))
+    }
+
+    /// Redeems `amount` bonds from `bond_account`, returning the Units to pay out
+    pub fn redeem_bonds(&mut self, bond_account: &mut BondAccount, amount: u64) -> Result<u64> {
+        let epoch = self.epoch();
+        let terms = self.bonds.terms;
+        let bonds = &bond_account.bonds;
+
+        require!(terms.is_mature(bonds.epoch, epoch), ErrorCode::BondsNotMature);
+        require!(!terms.is_expired(bonds.epoch, epoch), ErrorCode::BondsExpired);
+        require_gte!(bonds.amount, amount, ErrorCode::InsufficientBondBalance);
+        require_gte!(self.bonds.redeemable_index, bond_account.index + amount, ErrorCode::BondsNotFunded);
+        require_gte!(self.bonds.redeemable(), amount, ErrorCode::InsufficientRedeemableBonds);
+
+        let units = self.bonds.redemption_value(bonds, amount, epoch, self.epoch.twap);
+
+        bond_account.bonds_removed(amount);
+        self.bonds.bonds_redeemed(amount);
+
+        Ok(units)
+    }
+
+    /// Forfeits the bonds of an expired `bond_account`, returning the Units released from the reserve
+    pub fn forfeit_bonds(&mut self, bond_account: &BondAccount) -> Result<u64> {
+        let bonds = &bond_account.bonds;
+
+        require!(self.bonds.terms.is_expired(bonds.epoch, self.epoch()), ErrorCode::BondsNotExpired);
+        // The queue has to reach the bonds so that their unfunded tail can be skipped
+        require_gte!(self.bonds.redeemable_index, bond_account.index, ErrorCode::BondsNotFunded);
+
+        Ok(self.bonds.bonds_forfeited(bond_account.index, bonds.amount))
+    }
+
     pub fn bonds_purchased(&mut self, amount: u64) {
         self.bonds.bonds_purchased += amount;
         self.bonds.total_bonds_purchased += amount;
         self.bonds.available_bonds = self.bonds.available_bonds.sub(amount);
     }
 
+    /// The mint of the token deposited into the boardroom
+    pub fn boardroom_deposit_mint(&self) -> Pubkey {
+        match self.boardroom_deposits.deposit_token {
+            BoardroomDepositToken::Units => self.unit_mint,
+            BoardroomDepositToken::Shares => self.share.mint,
+        }
+    }
+
     pub fn increment_total_balance_of_staged(&mut self, amount: u64) {
         self.boardroom_deposits.total_deposited_units += amount;
     }
@@ -99,4 +276,54 @@ impl Singleton {
     pub fn decrement_total_balance_of_staged(&mut self, amount: u64) {
         self.boardroom_deposits.total_deposited_units -= amount;
     }
+
+    /// Applies the staked NFT boost to a boardroom balance
+    pub fn boosted_shares(&self, balance: u64, staked_nfts: u64) -> u64 {
+        let boost = self.boardroom_boost.boost(staked_nfts);
+
+        balance + balance.mul_div_floor(boost, Self::ONE_HUNDRED_PCT).unwrap()
+    }
+
+    /// Settles the rewards of `account` and re-weights it against its current balance and NFT boost
+    pub fn sync_boardroom_account(&mut self, account: &mut BoardroomAccount) {
+        let reward_per_share = self.boardroom_deposits.reward_per_share;
+        let share_reward_per_share = self.boardroom_deposits.share_reward_per_share;
+        account.settle_rewards(reward_per_share);
+        account.settle_share_rewards(share_reward_per_share);
+
+        let boosted_shares = self.boosted_shares(account.balance(), account.staked_nfts);
+
+        self.boardroom_deposits.total_boosted_shares = self.boardroom_deposits.total_boosted_shares
+            .sub(account.boosted_shares)
+            .add(boosted_shares);
+
+        account.set_boosted_shares(boosted_shares, reward_per_share);
+        account.set_share_reward_debt(share_reward_per_share);
+    }
+
+    /// Distributes `amount` of rewards pro rata over boosted shares, returns false if there are no shares to reward
+    pub fn distribute_boardroom_reward(&mut self, amount: u64) -> bool {
+        let total_boosted_shares = self.boardroom_deposits.total_boosted_shares;
+
+        if total_boosted_shares == 0 {
+            return false;
+        }
+
+        self.boardroom_deposits.reward_per_share += amount as u128 * REWARD_PER_SHARE_PRECISION / total_boosted_shares as u128;
+
+        true
+    }
+
+    /// Distributes `amount` of share rewards pro rata over boosted shares, returns false if there are no shares to reward
+    pub fn distribute_boardroom_share_reward(&mut self, amount: u64) -> bool {
+        let total_boosted_shares = self.boardroom_deposits.total_boosted_shares;
+
+        if total_boosted_shares == 0 {
+            return false;
+        }
+
+        self.boardroom_deposits.share_reward_per_share += amount as u128 * REWARD_PER_SHARE_PRECISION / total_boosted_shares as u128;
+
+        true
+    }
 }
\ No newline at end of file
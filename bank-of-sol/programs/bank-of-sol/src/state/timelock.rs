@@ -0,0 +1,109 @@
+use anchor_lang::prelude::*;
+
+use crate::types::timelock::{TimelockAction, GRACE_PERIOD};
+use crate::errors::ErrorCode;
+
+/**
+ * Timelock architecture
+ *
+ * Admins queue actions as `PendingAction` PDAs, indexed by `Timelock.action_count`, with an ETA of the
+ * current time plus `Timelock.delay`. Passed governance proposals are queued the same way, so no config
+ * change applies before the delay has passed. Every `configure_*` style change is a `TimelockAction`,
+ * there is no other way to change the protocol's parameters.
+ *
+ * Until the ETA an admin can cancel an action. From the ETA anyone can execute it for `GRACE_PERIOD`,
+ * which applies it, and it can't be cancelled, so that a reviewed action can't be withheld once it's due.
+ * An action that wasn't executed in time, for example because it no longer applies, expires and can
+ * then be closed by anyone. Closing an action returns the rent to whoever queued it.
+ *
+ * Renouncing the authority invalidates every action queued until then, see `Timelock.valid_from`, so
+ * that admin actions can't be applied once there are no admins left to cancel them. Invalidated actions
+ * can be closed by anyone.
+ *
+ * The delay is itself changed by queueing a `TimelockAction::SetDelay`, so shortening it is subject to
+ * the delay being replaced. The ETA is fixed at queue time, changing the delay doesn't affect actions
+ * already queued.
+ */
+#[account]
+pub struct Timelock {
+    /// The time between queueing and executing an action, in seconds (8)
+    pub delay: i64,
+    /// The number of actions queued (8)
+    pub action_count: u64,
+    /// The bump of this PDA (1)
+    pub bump: u8,
+    /// The index of the first action that can be executed, the earlier ones were invalidated (8)
+    pub valid_from: u64,
+}
+
+impl Timelock {
+    pub const LEN: usize = 8 + 8 + 8 + 1 + 8;
+
+    /// Invalidates every action queued so far
+    pub fn invalidate_queue(&mut self) {
+        self.valid_from = self.action_count;
+    }
+}
+
+/// A PDA for storing a queued admin action
+#[account]
+pub struct PendingAction {
+    /// The index of this action (8)
+    pub index: u64,
+    /// The action to apply (115)
+    pub action: TimelockAction,
+    /// The timestamp from which the action can be executed (8)
+    pub eta: i64,
+    /// The authority that queued the action, refunded when it's closed (32)
+    pub queued_by: Pubkey,
+    /// The bump of this PDA (1)
+    pub bump: u8,
+}
+
+impl PendingAction {
+    pub const LEN: usize = 8 + 8 + TimelockAction::LEN + 8 + 32 + 1;
+
+    /// Queues `action` as the timelock's next action, executable once the delay has passed from `now`
+    pub fn queue(timelock: &mut Timelock, action: TimelockAction, now: i64, queued_by: Pubkey, bump: u8) -> Self {
+        let index = timelock.action_count;
+
+        timelock.action_count += 1;
+
+        Self {
+            index,
+            action,
+            eta: now + timelock.delay,
+            queued_by,
+            bump,
+        }
+    }
+
+    /// The action to apply, failing before the ETA, once expired and once invalidated
+    pub fn ready(&self, timelock: &Timelock, now: i64) -> Result<TimelockAction> {
+        require!(!self.invalidated(timelock), ErrorCode::ActionInvalidated);
+        require_gte!(now, self.eta, ErrorCode::ActionNotReady);
+        require!(!self.expired(now), ErrorCode::ActionExpired);
+
+        Ok(self.action)
+    }
+
+    pub fn expired(&self, now: i64) -> bool {
+        now > self.eta + GRACE_PERIOD
+    }
+
+    pub fn invalidated(&self, timelock: &Timelock) -> bool {
+        self.index < timelock.valid_from
+    }
+
+    /// Whether the action can no longer be executed, so anyone can close it
+    pub fn closable(&self, timelock: &Timelock, now: i64) -> bool {
+        self.invalidated(timelock) || self.expired(now)
+    }
+
+    /// Fails while the action can be executed
+    pub fn cancellable(&self, timelock: &Timelock, now: i64) -> Result<()> {
+        require!(now < self.eta || self.closable(timelock, now), ErrorCode::ActionExecutable);
+
+        Ok(())
+    }
+}
\ No newline at end of file
@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+use crate::types::treasury::{FundKind, SeigniorageSplit, TreasuryFund};
+
+/// A PDA for storing the seigniorage split and the treasury funds
+#[account]
+pub struct Treasury {
+    /// The split of seigniorage left after bond redemptions and POL (24)
+    pub split: SeigniorageSplit,
+    /// The DAO fund (64)
+    pub dao_fund: TreasuryFund,
+    /// The dev fund (64)
+    pub dev_fund: TreasuryFund,
+    /// The bump of this PDA (1)
+    pub bump: u8,
+}
+
+impl Treasury {
+    pub const LEN: usize = 8 + 24 + 64 + 64 + 1;
+
+    pub fn init(dao_fund_vault: Pubkey, dev_fund_vault: Pubkey, bump: u8) -> Self {
+        Self {
+            split: SeigniorageSplit::default(),
+            dao_fund: TreasuryFund::init(dao_fund_vault),
+            dev_fund: TreasuryFund::init(dev_fund_vault),
+            bump,
+        }
+    }
+
+    pub fn fund(&self, kind: FundKind) -> &TreasuryFund {
+        match kind {
+            FundKind::Dao => &self.dao_fund,
+            FundKind::Dev => &self.dev_fund,
+        }
+    }
+
+    pub fn fund_mut(&mut self, kind: FundKind) -> &mut TreasuryFund {
+        match kind {
+            FundKind::Dao => &mut self.dao_fund,
+            FundKind::Dev => &mut self.dev_fund,
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,91 @@
+use anchor_lang::prelude::*;
+
+use crate::types::ve::{SupplyCurve, VePoint, LockHistory};
+
+/**
+ * veShares architecture
+ *
+ * Shares are locked in a single vault owned by the token authority PDA. Each owner has one `VeLock`,
+ * which cannot be transferred, holding the locked amount and lock end. `VeSupply` tracks the total
+ * voting power and is checkpointed on every lock change.
+ *
+ * The total voting power is recorded once per epoch in a `VeCheckpoint`, by anyone or by the first
+ * vote of the epoch. A lock's voting share in an epoch is its power at the checkpoint over the
+ * checkpoint's total. Locks keep a `LockHistory`, so their power at the checkpoint is the one they had
+ * then, and power cannot be added or lost after the fact.
+ */
+#[account]
+pub struct VeSupply {
+    /// The total voting power (3384)
+    pub curve: SupplyCurve,
+    /// The vault holding the locked shares (32)
+    pub vault: Pubkey,
+    /// The bump of this PDA (1)
+    pub bump: u8,
+}
+
+impl VeSupply {
+    pub const LEN: usize = 8 + SupplyCurve::LEN + 32 + 1;
+}
+
+/// A PDA for storing an owner's locked shares
+#[account]
+pub struct VeLock {
+    /// The owner of the lock (32)
+    pub owner: Pubkey,
+    /// The locked amount and lock end (16)
+    pub point: VePoint,
+    /// The past points of the lock (200)
+    pub history: LockHistory,
+    /// The bump of this PDA (1)
+    pub bump: u8,
+}
+
+impl VeLock {
+    pub const LEN: usize = 8 + 32 + 16 + LockHistory::LEN + 1;
+
+    /// Changes the lock to `point` from `now`
+    pub fn set_point(&mut self, point: VePoint, now: i64) {
+        self.point = point;
+        self.history.push(point, now);
+    }
+
+    /// The voting power of this lock at `checkpoint`
+    pub fn voting_power(&self, checkpoint: &VeCheckpoint) -> Result<u64> {
+        Ok(self.history.point_at(checkpoint.timestamp)?.power_at(checkpoint.timestamp))
+    }
+}
+
+/// A PDA for storing the total voting power in an epoch
+#[account]
+pub struct VeCheckpoint {
+    /// The epoch of the checkpoint (8)
+    pub epoch: u64,
+    /// The timestamp of the checkpoint (8)
+    pub timestamp: i64,
+    /// The total voting power at `timestamp` (8)
+    pub total_power: u64,
+    /// The bump of this PDA (1)
+    pub bump: u8,
+}
+
+impl VeCheckpoint {
+    pub const LEN: usize = 8 + 8 + 8 + 8 + 1;
+
+    /// Records the total voting power of `ve_supply` at `now` for `epoch`
+    pub fn record(ve_supply: &mut VeSupply, epoch: u64, now: i64, bump: u8) -> Self {
+        ve_supply.curve.checkpoint(now);
+
+        Self {
+            epoch,
+            timestamp: now,
+            total_power: ve_supply.curve.total_power(),
+            bump,
+        }
+    }
+
+    /// Whether the checkpoint has been recorded, `init_if_needed` leaves it zeroed otherwise
+    pub fn is_recorded(&self) -> bool {
+        self.timestamp != 0
+    }
+}
\ No newline at end of file
@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+
+use crate::types::{treasury::FundKind, vesting::VestingTerms};
+
+/**
+ * Vesting schedule PDA
+ *
+ * Each schedule is derived from its mint and the beneficiary it was created for, which is kept as `seed`
+ * so the address does not move when the beneficiary changes. The schedule's tokens are held in a vault
+ * owned by the token authority PDA, derived from the schedule's address.
+ *
+ * Schedules vest either Units paid from a treasury fund or newly minted shares. Revoking freezes the
+ * schedule at what has vested so far, that remains claimable by the beneficiary. The rest of a Unit
+ * schedule is returned to the treasury fund it was paid from and the rest of a share schedule is burned.
+ */
+#[account]
+pub struct VestingSchedule {
+    /// The beneficiary the schedule was created for (32)
+    pub seed: Pubkey,
+    /// The current beneficiary (32)
+    pub beneficiary: Pubkey,
+    /// The mint of the vested tokens (32)
+    pub mint: Pubkey,
+    /// The vault holding the unclaimed tokens (32)
+    pub vault: Pubkey,
+    /// The treasury fund a Unit schedule was paid from (1)
+    pub fund: FundKind,
+    /// The total amount vesting, reduced to the vested amount when revoked (8)
+    pub total: u64,
+    /// The amount claimed so far (8)
+    pub claimed: u64,
+    /// When the tokens vest (24)
+    pub terms: VestingTerms,
+    /// Whether the schedule has been revoked (1)
+    pub revoked: bool,
+    /// The bump of this PDA (1)
+    pub bump: u8,
+}
+
+impl VestingSchedule {
+    pub const LEN: usize = 8 + 32 + 32 + 32 + 32 + 1 + 8 + 8 + 24 + 1 + 1;
+
+    pub fn vested(&self, now: i64) -> u64 {
+        if self.revoked {
+            self.total
+        } else {
+            self.terms.vested(self.total, now)
+        }
+    }
+
+    /// The amount that can be claimed at `now`
+    pub fn claimable(&self, now: i64) -> u64 {
+        self.vested(now) - self.claimed
+    }
+
+    /// Stops vesting at `now`, returns the unvested amount
+    pub fn revoke(&mut self, now: i64) -> u64 {
+        let vested = self.vested(now);
+        let unvested = self.total - vested;
+
+        self.total = vested;
+        self.revoked = true;
+
+        unvested
+    }
+}
\ No newline at end of file
@@ -22,7 +22,139 @@ impl BoardroomStatus {
     }
 }
 
+/// The token deposited into the boardroom
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub enum BoardroomDepositToken {
+    #[default]
+    Units,
+    Shares,
+}
+
 #[derive(Debug, Default, Clone, Copy, BorshSerialize, BorshDeserialize)]
 pub struct BoardroomBalances {
+    /// The total deposited, denominated in `deposit_token` (8)
     pub total_deposited_units: u64,
+    /// The sum of every account's boosted shares, used as the reward denominator (8)
+    pub total_boosted_shares: u64,
+    /// Rewards accrued per boosted share, scaled by `REWARD_PER_SHARE_PRECISION` (16)
+    pub reward_per_share: u128,
+    /// Share rewards from the boardroom's gauge accrued per boosted share, scaled by `REWARD_PER_SHARE_PRECISION` (16)
+    pub share_reward_per_share: u128,
+    /// The token deposited into the boardroom (1)
+    pub deposit_token: BoardroomDepositToken,
+}
+
+pub const REWARD_PER_SHARE_PRECISION: u128 = 1_000_000_000_000;
+
+/// Configures the boardroom boost granted for staked NFTs
+#[derive(Debug, Default, Clone, Copy, BorshSerialize, BorshDeserialize)]
+pub struct BoardroomBoost {
+    /// The boost applied per staked NFT, as a percentage scaled by `INTEREST_RATE_PRECISION` (8)
+    pub boost_per_nft: u64,
+    /// The maximum number of staked NFTs counted towards the boost (8)
+    pub max_boosted_nfts: u64,
+    /// The maximum total boost, as a percentage scaled by `INTEREST_RATE_PRECISION` (8)
+    pub max_boost: u64,
+}
+
+impl BoardroomBoost {
+    pub fn init(boost_per_nft: u64, max_boosted_nfts: u64, max_boost: u64) -> Self {
+        Self { boost_per_nft, max_boosted_nfts, max_boost }
+    }
+
+    /// The boost for the given number of staked NFTs, capped by both `max_boosted_nfts` and `max_boost`
+    pub fn boost(&self, staked_nfts: u64) -> u64 {
+        let counted_nfts = staked_nfts.min(self.max_boosted_nfts);
+
+        self.boost_per_nft
+            .saturating_mul(counted_nfts)
+            .min(self.max_boost)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::boardroom::BoardroomAccount;
+    use crate::types::interest_rate::INTEREST_RATE_PRECISION;
+    use test_case::test_case;
+
+    const PCT: u64 = INTEREST_RATE_PRECISION;
+
+    fn account(boosted_shares: u64) -> BoardroomAccount {
+        BoardroomAccount {
+            shares: boosted_shares,
+            futures: 0,
+            last_deposited_timestamp: 0,
+            epoch_last_deposited: 0,
+            status: BoardroomStatus::frozen(0),
+            staged_balance: 0,
+            staked_nfts: 0,
+            boosted_shares,
+            reward_debt: 0,
+            pending_rewards: 0,
+            share_reward_debt: 0,
+            pending_share_rewards: 0,
+            bump: 0,
+        }
+    }
+
+    #[test_case(0, 0; "no nfts")]
+    #[test_case(2, 10 * PCT; "below caps")]
+    #[test_case(4, 20 * PCT; "below max boost")]
+    #[test_case(5, 22 * PCT; "boost capped")]
+    fn test_boost(staked_nfts: u64, expected: u64) {
+        let boost = BoardroomBoost::init(5 * PCT, 5, 22 * PCT);
+
+        assert_eq!(boost.boost(staked_nfts), expected);
+    }
+
+    #[test]
+    fn test_boost_is_capped_by_max_boosted_nfts() {
+        let boost = BoardroomBoost::init(5 * PCT, 3, 100 * PCT);
+
+        assert_eq!(boost.boost(10), 15 * PCT);
+    }
+
+    #[test_case(0, 0; "nothing accrued")]
+    #[test_case(REWARD_PER_SHARE_PRECISION / 2, 500; "half a reward per share")]
+    #[test_case(2 * REWARD_PER_SHARE_PRECISION, 2_000; "two rewards per share")]
+    fn test_earned(reward_per_share: u128, expected: u64) {
+        assert_eq!(account(1_000).earned(reward_per_share), expected);
+    }
+
+    #[test]
+    fn test_earned_excludes_rewards_before_boosted_shares_were_set() {
+        let mut account = account(0);
+        account.set_boosted_shares(1_000, REWARD_PER_SHARE_PRECISION);
+
+        assert_eq!(account.earned(REWARD_PER_SHARE_PRECISION), 0);
+        assert_eq!(account.earned(3 * REWARD_PER_SHARE_PRECISION), 2_000);
+    }
+
+    #[test]
+    fn test_settle_keeps_accrued_rewards_pending() {
+        let mut account = account(1_000);
+        account.settle_rewards(REWARD_PER_SHARE_PRECISION);
+
+        assert_eq!(account.pending_rewards, 1_000);
+        assert_eq!(account.earned(REWARD_PER_SHARE_PRECISION), 1_000);
+
+        account.set_boosted_shares(2_000, REWARD_PER_SHARE_PRECISION);
+
+        assert_eq!(account.earned(2 * REWARD_PER_SHARE_PRECISION), 3_000);
+    }
+
+    #[test]
+    fn test_share_rewards_settle_separately() {
+        let mut account = account(1_000);
+        account.settle_rewards(REWARD_PER_SHARE_PRECISION);
+        account.settle_share_rewards(REWARD_PER_SHARE_PRECISION / 2);
+
+        account.set_boosted_shares(2_000, REWARD_PER_SHARE_PRECISION);
+        account.set_share_reward_debt(REWARD_PER_SHARE_PRECISION / 2);
+
+        assert_eq!(account.earned_shares(REWARD_PER_SHARE_PRECISION), 500 + 1_000);
+        assert_eq!(account.earned(REWARD_PER_SHARE_PRECISION), 1_000);
+    }
 }
\ No newline at end of file
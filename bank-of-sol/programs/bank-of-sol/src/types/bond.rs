@@ -1,7 +1,23 @@
 use anchor_lang::prelude::{*, borsh::{BorshSerialize, BorshDeserialize}};
+use muldiv::*;
+use crate::types::interest_rate::{INTEREST_RATE_PRECISION, ONE_HUNDRED_PCT};
+use crate::types::oracle::PEG;
+use crate::errors::ErrorCode;
+
+/// The default maximum of outstanding bonds as a percentage of circulating supply
+pub const DEFAULT_DEBT_CEILING: u64 = 35 * INTEREST_RATE_PRECISION;
+/// The highest debt ceiling, outstanding bonds can't exceed the circulating supply
+pub const MAX_DEBT_CEILING: u64 = ONE_HUNDRED_PCT;
+
+/// A debt ceiling of zero would stop bond sales entirely, which pausing `BondPurchase` is for
+pub fn validate_debt_ceiling(debt_ceiling: u64) -> Result<()> {
+    require!((1..=MAX_DEBT_CEILING).contains(&debt_ceiling), ErrorCode::InvalidDebtCeiling);
+
+    Ok(())
+}
 
 /// Stores bond specific data
-#[derive(Debug, Default, Clone, BorshSerialize, BorshDeserialize)]
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
 pub struct BondStorage {
     /// The number of Units the protocol is willing to borrow/how many bonds are available to purchase, reset each epoch
     pub available_bonds: u64,
@@ -11,8 +27,277 @@ pub struct BondStorage {
     pub total_bonds_purchased: u64,
     /// The total number of bonds ever redeemed
     pub redeemed: u64,
-    /// The redeemable index; the total number of bonds that have ever been redeemable, include previously redeemed bonds
+    /// The redeemable index; bonds are queued in purchase order and every bond before this index has been
+    /// made redeemable or forfeited
     pub redeemable_index: u64,
+    /// The maximum of outstanding bonds as a percentage of circulating supply, scaled by `INTEREST_RATE_PRECISION`
+    pub debt_ceiling: u64,
+    /// The total number of bonds ever forfeited on expiry
+    pub forfeited: u64,
+    /// The maturity and expiry of bonds
+    pub terms: BondTerms,
+    /// The premium paid on redemption while the TWAP is above peg
+    pub premium: RedemptionPremium,
+    /// The Dutch auction pricing of bonds within an epoch
+    pub auction: BondAuction,
+    /// Whether bonds are sold as `bond_mint` tokens rather than into bond accounts
+    pub token_mode: bool,
+    /// The fungible bond token mint, the default pubkey until created
+    pub bond_mint: Pubkey,
+    /// The outstanding supply of bond tokens
+    pub bond_token_supply: u64,
+    /// The Units minted into custody to pay out the face value of redeemable bonds
+    pub reserve: u64,
+}
+
+impl Default for BondStorage {
+    fn default() -> Self {
+        Self {
+            available_bonds: 0,
+            bonds_purchased: 0,
+            total_bonds_purchased: 0,
+            redeemed: 0,
+            redeemable_index: 0,
+            debt_ceiling: DEFAULT_DEBT_CEILING,
+            forfeited: 0,
+            terms: BondTerms::default(),
+            premium: RedemptionPremium::default(),
+            auction: BondAuction::default(),
+            token_mode: false,
+            bond_mint: Pubkey::default(),
+            bond_token_supply: 0,
+            reserve: 0,
+        }
+    }
+}
+
+impl BondStorage {
+    /// Bonds purchased and neither redeemed nor forfeited
+    pub fn outstanding(&self) -> u64 {
+        self.total_bonds_purchased - self.redeemed - self.forfeited
+    }
+
+    /// Bonds that have been made redeemable and neither redeemed nor forfeited
+    pub fn redeemable(&self) -> u64 {
+        self.redeemable_index.saturating_sub(self.redeemed + self.forfeited)
+    }
+
+    /// Pays the face value of the redeemed bonds out of the reserve
+    pub fn bonds_redeemed(&mut self, amount: u64) {
+        self.redeemed += amount;
+        self.reserve -= amount;
+    }
+
+    /// Clears the liability of `amount` expired bonds queued at `index`, which must not be past `redeemable_index`.
+    /// Returns the funded part, whose Units are released from the reserve, the unfunded tail is skipped by the queue
+    pub fn bonds_forfeited(&mut self, index: u64, amount: u64) -> u64 {
+        let funded = self.redeemable_index.saturating_sub(index).min(amount);
+
+        self.forfeited += amount;
+        self.reserve -= funded;
+        self.redeemable_index += amount - funded;
+
+        funded
+    }
+
+    /// The bonds that can be issued before outstanding bonds reach the debt ceiling
+    pub fn debt_headroom(&self, circulating_supply: u64) -> u64 {
+        let ceiling = circulating_supply.mul_div_floor(self.debt_ceiling, ONE_HUNDRED_PCT).unwrap_or(u64::MAX);
+
+        ceiling.saturating_sub(self.outstanding())
+    }
+
+    /// Resets the per-epoch bond allowance
+    pub fn new_epoch(&mut self, available_bonds: u64) {
+        self.available_bonds = available_bonds;
+        self.bonds_purchased = 0;
+    }
+
+    /// The Units paid out for redeeming `amount` of `bonds` at `epoch`, while the TWAP is `twap`
+    pub fn redemption_value(&self, bonds: &UserBondStorage, amount: u64, epoch: u64, twap: u64) -> u64 {
+        let hold_premium = self.terms.hold_premium(bonds.epoch, epoch);
+        let twap_premium = self.premium.premium(bonds.interest_rate, twap);
+
+        amount + amount.mul_div_floor(hold_premium + twap_premium, ONE_HUNDRED_PCT).unwrap()
+    }
+
+    /// The Units paid out for redeeming `amount` bond tokens. Bond tokens are fungible so they
+    /// carry no purchase rate or issuance epoch, only the TWAP premium applies
+    pub fn token_redemption_value(&self, amount: u64, twap: u64) -> u64 {
+        let twap_premium = self.premium.premium(0, twap);
+
+        amount + amount.mul_div_floor(twap_premium, ONE_HUNDRED_PCT).unwrap()
+    }
+
+    /// Sets the bond terms, bond tokens carry no issuance epoch so terms can't be set while they're sold
+    pub fn set_terms(&mut self, terms: BondTerms) -> Result<()> {
+        require!(!self.token_mode || !terms.is_active(), ErrorCode::BondTermsActive);
+
+        self.terms = terms;
+
+        Ok(())
+    }
+
+    /// Switches between bond accounts and bond tokens. Bond tokens don't hold a place in the redemption
+    /// queue and carry no issuance epoch, so the modes can't be mixed and bond terms can't be active
+    pub fn set_token_mode(&mut self, enabled: bool) -> Result<()> {
+        if enabled == self.token_mode {
+            return Ok(());
+        }
+
+        let outstanding_in_mode = if self.token_mode {
+            self.bond_token_supply
+        } else {
+            self.outstanding() - self.bond_token_supply
+        };
+
+        require_eq!(outstanding_in_mode, 0, ErrorCode::BondsOutstanding);
+        require!(!enabled || !self.terms.is_active(), ErrorCode::BondTermsActive);
+
+        self.token_mode = enabled;
+
+        Ok(())
+    }
+
+    pub fn bond_tokens_minted(&mut self, amount: u64) {
+        self.bond_token_supply += amount;
+    }
+
+    pub fn bond_tokens_burned(&mut self, amount: u64) {
+        self.bond_token_supply -= amount;
+    }
+
+    /// Makes up to `amount` of the oldest unredeemable bonds redeemable, returns the amount made redeemable
+    /// which has to be minted into the reserve
+    pub fn fund_redemptions(&mut self, amount: u64) -> u64 {
+        let unfunded = self.total_bonds_purchased - self.redeemable_index;
+        let funded = amount.min(unfunded);
+        self.redeemable_index += funded;
+        self.reserve += funded;
+
+        funded
+    }
+}
+
+/// Configures when bonds can be redeemed
+#[derive(Debug, Default, Clone, Copy, BorshSerialize, BorshDeserialize)]
+pub struct BondTerms {
+    /// The number of epochs after issuance before bonds can be redeemed (8)
+    pub maturity: u64,
+    /// The number of epochs after issuance after which unredeemed bonds are forfeited, zero for never (8)
+    pub expiry: u64,
+    /// The premium earned per epoch held past maturity, scaled by `INTEREST_RATE_PRECISION` (8)
+    pub premium_per_epoch: u64,
+    /// The maximum premium earned by holding, scaled by `INTEREST_RATE_PRECISION` (8)
+    pub max_hold_premium: u64,
+}
+
+impl BondTerms {
+    pub fn init(maturity: u64, expiry: u64, premium_per_epoch: u64, max_hold_premium: u64) -> Result<Self> {
+        require!(expiry == 0 || expiry > maturity, ErrorCode::InvalidBondTerms);
+
+        Ok(Self { maturity, expiry, premium_per_epoch, max_hold_premium })
+    }
+
+    /// Whether redemptions depend on the issuance epoch
+    pub fn is_active(&self) -> bool {
+        self.maturity > 0 || self.expiry > 0 || self.premium_per_epoch > 0
+    }
+
+    pub fn is_mature(&self, issued: u64, epoch: u64) -> bool {
+        epoch >= issued + self.maturity
+    }
+
+    pub fn is_expired(&self, issued: u64, epoch: u64) -> bool {
+        self.expiry > 0 && epoch >= issued + self.expiry
+    }
+
+    /// The premium for holding bonds issued in `issued` until `epoch`, scaled by `INTEREST_RATE_PRECISION`
+    pub fn hold_premium(&self, issued: u64, epoch: u64) -> u64 {
+        let held_past_maturity = epoch.saturating_sub(issued + self.maturity);
+
+        held_past_maturity
+            .saturating_mul(self.premium_per_epoch)
+            .min(self.max_hold_premium)
+    }
+
+}
+
+/**
+ * Redemption premium
+ *
+ * While the TWAP is at or above `threshold`, redeemed bonds pay out an extra percentage of
+ * the interest rate captured at purchase plus the TWAP's premium over peg times `multiplier`,
+ * capped at `max_premium`. Bonds bought deeper below peg, at higher rates, earn more.
+ */
+#[derive(Debug, Default, Clone, Copy, BorshSerialize, BorshDeserialize)]
+pub struct RedemptionPremium {
+    /// The TWAP from which the premium is paid scaled by `PEG_DECIMALS`, zero to disable (8)
+    pub threshold: u64,
+    /// The multiplier of the TWAP's premium over peg, `ONE_HUNDRED_PCT` is 1x (8)
+    pub multiplier: u64,
+    /// The maximum premium, scaled by `INTEREST_RATE_PRECISION` (8)
+    pub max_premium: u64,
+}
+
+impl RedemptionPremium {
+    pub fn init(threshold: u64, multiplier: u64, max_premium: u64) -> Result<Self> {
+        require!(threshold == 0 || threshold >= PEG, ErrorCode::InvalidRedemptionPremium);
+
+        Ok(Self { threshold, multiplier, max_premium })
+    }
+
+    /// The premium for bonds purchased at `interest_rate`, scaled by `INTEREST_RATE_PRECISION`
+    pub fn premium(&self, interest_rate: u64, twap: u64) -> u64 {
+        if self.threshold == 0 || twap < self.threshold {
+            return 0;
+        }
+
+        let twap_premium = twap.saturating_sub(PEG).mul_div_floor(ONE_HUNDRED_PCT, PEG).unwrap_or(u64::MAX);
+        let multiplied = twap_premium.mul_div_floor(self.multiplier, ONE_HUNDRED_PCT).unwrap_or(u64::MAX);
+
+        interest_rate
+            .saturating_add(multiplied)
+            .min(self.max_premium)
+    }
+}
+
+/**
+ * Dutch auction
+ *
+ * When enabled, bonds are not sold at the epoch's rate for the whole epoch. Instead the discount
+ * starts at `start_discount` when the epoch begins and rises linearly to `max_discount` over
+ * `duration` seconds, so that early buyers can't drain `available_bonds` at the best price.
+ */
+#[derive(Debug, Default, Clone, Copy, BorshSerialize, BorshDeserialize)]
+pub struct BondAuction {
+    /// Whether bonds are auctioned (1)
+    pub enabled: bool,
+    /// The discount at the start of the epoch, scaled by `INTEREST_RATE_PRECISION` (8)
+    pub start_discount: u64,
+    /// The discount reached after `duration`, scaled by `INTEREST_RATE_PRECISION` (8)
+    pub max_discount: u64,
+    /// The number of seconds over which the discount rises (8)
+    pub duration: i64,
+}
+
+impl BondAuction {
+    pub fn init(enabled: bool, start_discount: u64, max_discount: u64, duration: i64) -> Result<Self> {
+        require_gte!(max_discount, start_discount, ErrorCode::InvalidBondAuction);
+        require_gt!(duration, 0, ErrorCode::InvalidBondAuction);
+
+        Ok(Self { enabled, start_discount, max_discount, duration })
+    }
+
+    /// The discount `elapsed` seconds into the epoch, scaled by `INTEREST_RATE_PRECISION`
+    pub fn discount(&self, elapsed: i64) -> u64 {
+        let elapsed = elapsed.clamp(0, self.duration) as u64;
+        let increase = (self.max_discount - self.start_discount)
+            .mul_div_floor(elapsed, self.duration as u64)
+            .unwrap();
+
+        self.start_discount + increase
+    }
 }
 
 /// Stores information on user purchased bonds
@@ -30,4 +315,253 @@ impl UserBondStorage {
     pub fn new(amount: u64, epoch: u64, interest_rate: u64) -> Self {
         Self { amount, epoch, interest_rate }
     }
+
+    /// Adds bonds purchased at `interest_rate`, recording the amount weighted average rate
+    pub fn add(&mut self, amount: u64, interest_rate: u64) {
+        let total = self.amount + amount;
+
+        if total > 0 {
+            let weighted = self.amount as u128 * self.interest_rate as u128 + amount as u128 * interest_rate as u128;
+            self.interest_rate = (weighted / total as u128) as u64;
+        }
+
+        self.amount = total;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::bond_account::BondAccount;
+    use test_case::test_case;
+
+    const PCT: u64 = INTEREST_RATE_PRECISION;
+
+    fn terms() -> BondTerms {
+        BondTerms::init(2, 10, PCT, 5 * PCT).unwrap()
+    }
+
+    #[test_case(0, false, false; "issued")]
+    #[test_case(2, true, false; "mature")]
+    #[test_case(9, true, false; "before expiry")]
+    #[test_case(10, true, true; "expired")]
+    fn test_maturity_and_expiry(epoch: u64, mature: bool, expired: bool) {
+        assert_eq!(terms().is_mature(0, epoch), mature);
+        assert_eq!(terms().is_expired(0, epoch), expired);
+    }
+
+    #[test_case(2, 0; "at maturity")]
+    #[test_case(4, 2 * PCT; "held two epochs")]
+    #[test_case(9, 5 * PCT; "premium capped")]
+    fn test_hold_premium(epoch: u64, expected: u64) {
+        assert_eq!(terms().hold_premium(0, epoch), expected);
+    }
+
+    #[test_case(1_050_000, 0; "below threshold")]
+    #[test_case(1_100_000, 15 * PCT; "at threshold")]
+    #[test_case(1_200_000, 20 * PCT; "above threshold")]
+    #[test_case(2_000_000, 30 * PCT; "capped")]
+    fn test_redemption_premium(twap: u64, expected: u64) {
+        let premium = RedemptionPremium::init(1_100_000, 50 * PCT, 30 * PCT).unwrap();
+
+        assert_eq!(premium.premium(10 * PCT, twap), expected);
+    }
+
+    #[test_case(0, 2, PEG, 1_000_000; "at maturity below threshold")]
+    #[test_case(0, 4, PEG, 1_020_000; "held two epochs below threshold")]
+    #[test_case(0, 4, 1_200_000, 1_220_000; "held two epochs above threshold")]
+    #[test_case(3, 4, 1_200_000, 1_200_000; "above threshold without hold premium")]
+    fn test_redemption_value(issued: u64, epoch: u64, twap: u64, expected: u64) {
+        let storage = BondStorage {
+            terms: terms(),
+            premium: RedemptionPremium::init(1_100_000, 50 * PCT, 30 * PCT).unwrap(),
+            ..Default::default()
+        };
+        let bonds = UserBondStorage::new(1_000_000, issued, 10 * PCT);
+
+        assert_eq!(storage.redemption_value(&bonds, 1_000_000, epoch, twap), expected);
+    }
+
+    #[test_case(-10, 2 * PCT; "before epoch start")]
+    #[test_case(0, 2 * PCT; "epoch start")]
+    #[test_case(1800, 6 * PCT; "half way")]
+    #[test_case(3600, 10 * PCT; "end of auction")]
+    #[test_case(7200, 10 * PCT; "after auction")]
+    fn test_auction_discount(elapsed: i64, expected: u64) {
+        let auction = BondAuction::init(true, 2 * PCT, 10 * PCT, 3600).unwrap();
+
+        assert_eq!(auction.discount(elapsed), expected);
+    }
+
+    #[test]
+    fn test_add_records_weighted_rate() {
+        let mut bonds = UserBondStorage::new(100, 1, 2 * PCT);
+        bonds.add(300, 6 * PCT);
+
+        assert_eq!(bonds.amount, 400);
+        assert_eq!(bonds.interest_rate, 5 * PCT);
+    }
+
+    #[test]
+    fn test_no_expiry() {
+        let terms = BondTerms::init(2, 0, 0, 0).unwrap();
+
+        assert!(!terms.is_expired(0, u32::MAX as u64));
+    }
+
+    #[test]
+    fn test_expiry_before_maturity_is_rejected() {
+        assert!(BondTerms::init(5, 5, 0, 0).is_err());
+    }
+
+    #[test]
+    fn test_funded_bonds_are_reserved_until_redeemed() {
+        let mut storage = BondStorage { total_bonds_purchased: 1_000, ..Default::default() };
+
+        assert_eq!(storage.fund_redemptions(1_500), 1_000);
+        assert_eq!(storage.reserve, 1_000);
+
+        storage.bonds_redeemed(400);
+
+        assert_eq!(storage.reserve, 600);
+        assert_eq!(storage.redeemable(), 600);
+    }
+
+    #[test_case(0, false; "zero")]
+    #[test_case(1, true; "minimum")]
+    #[test_case(DEFAULT_DEBT_CEILING, true; "default")]
+    #[test_case(MAX_DEBT_CEILING, true; "maximum")]
+    #[test_case(MAX_DEBT_CEILING + 1, false; "above maximum")]
+    fn test_validate_debt_ceiling(debt_ceiling: u64, valid: bool) {
+        assert_eq!(validate_debt_ceiling(debt_ceiling).is_ok(), valid);
+    }
+
+    #[test_case(1_000, 0, 350; "no outstanding bonds")]
+    #[test_case(1_000, 300, 50; "below ceiling")]
+    #[test_case(1_000, 400, 0; "above ceiling")]
+    fn test_debt_headroom(supply: u64, outstanding: u64, expected: u64) {
+        let storage = BondStorage { total_bonds_purchased: outstanding, ..Default::default() };
+
+        assert_eq!(storage.debt_headroom(supply), expected);
+    }
+
+    fn funded_storage(purchased: u64, funded: u64) -> BondStorage {
+        let mut storage = BondStorage { total_bonds_purchased: purchased, ..Default::default() };
+        storage.fund_redemptions(funded);
+
+        storage
+    }
+
+    #[test_case(100, 0, 100, 100; "fully funded")]
+    #[test_case(60, 0, 60, 100; "unfunded tail skipped")]
+    #[test_case(100, 100, 0, 200; "unfunded")]
+    fn test_forfeit(funded: u64, index: u64, released: u64, redeemable_index: u64) {
+        let mut storage = funded_storage(300, funded);
+
+        assert_eq!(storage.bonds_forfeited(index, 100), released);
+        assert_eq!(storage.redeemable_index, redeemable_index);
+        assert_eq!(storage.reserve, funded - released);
+        assert_eq!(storage.redeemable(), funded - released);
+        assert_eq!(storage.outstanding(), 200);
+    }
+
+    #[test]
+    fn test_funding_after_forfeit_continues_in_queue_order() {
+        let mut storage = funded_storage(300, 60);
+        storage.bonds_forfeited(0, 100);
+
+        assert_eq!(storage.fund_redemptions(500), 200);
+        assert_eq!(storage.redeemable_index, 300);
+        assert_eq!(storage.redeemable(), 200);
+        assert_eq!(storage.reserve, 200);
+    }
+
+    #[test_case(BondTerms::default(), true; "no terms")]
+    #[test_case(terms(), false; "terms active")]
+    #[test_case(BondTerms::init(0, 0, PCT, 5 * PCT).unwrap(), false; "hold premium active")]
+    fn test_token_mode_requires_inactive_terms(terms: BondTerms, allowed: bool) {
+        let mut storage = BondStorage { terms, ..Default::default() };
+
+        assert_eq!(storage.set_token_mode(true).is_ok(), allowed);
+    }
+
+    #[test]
+    fn test_terms_cant_be_activated_in_token_mode() {
+        let mut storage = BondStorage { token_mode: true, ..Default::default() };
+
+        assert!(storage.set_terms(terms()).is_err());
+        assert!(storage.set_terms(BondTerms::default()).is_ok());
+    }
+
+    #[test_case(false, 100, 0, false; "bond accounts outstanding")]
+    #[test_case(false, 100, 100, true; "only bond tokens outstanding")]
+    #[test_case(true, 100, 100, false; "bond tokens outstanding")]
+    #[test_case(true, 100, 0, true; "only bond accounts outstanding")]
+    fn test_token_mode_switch_requires_settled_bonds(token_mode: bool, outstanding: u64, bond_token_supply: u64, allowed: bool) {
+        let mut storage = BondStorage {
+            token_mode,
+            total_bonds_purchased: outstanding,
+            bond_token_supply,
+            ..Default::default()
+        };
+
+        assert_eq!(storage.set_token_mode(!token_mode).is_ok(), allowed);
+    }
+
+    fn bond_account(amount: u64, index: u64) -> BondAccount {
+        BondAccount::init(Pubkey::default(), 0, amount, 1, PCT, index)
+    }
+
+    #[test_case(10, 4, Some(16); "tail")]
+    #[test_case(10, 0, None; "nothing")]
+    #[test_case(10, 10, None; "everything")]
+    fn test_split_off(amount: u64, split: u64, index: Option<u64>) {
+        let mut account = bond_account(amount, 10);
+
+        match index {
+            Some(index) => {
+                assert_eq!(account.split_off(split).unwrap(), index);
+                assert_eq!(account.bonds.amount, amount - split);
+                // The split off bonds follow the remaining bonds directly in the queue
+                assert_eq!(account.index + account.bonds.amount, index);
+            }
+            None => assert!(account.split_off(split).is_err()),
+        }
+    }
+
+    #[test_case(10, 5, 15, 5, Some(10); "source after")]
+    #[test_case(15, 5, 10, 5, Some(10); "source before")]
+    #[test_case(10, 5, 16, 5, None; "gap after")]
+    #[test_case(16, 5, 10, 5, None; "gap before")]
+    #[test_case(10, 5, 12, 5, None; "overlapping")]
+    fn test_merge(index: u64, amount: u64, source_index: u64, source_amount: u64, merged_index: Option<u64>) {
+        let mut account = bond_account(amount, index);
+        let source = bond_account(source_amount, source_index);
+
+        match merged_index {
+            Some(merged_index) => {
+                account.merge(&source).unwrap();
+                assert_eq!(account.index, merged_index);
+                assert_eq!(account.bonds.amount, amount + source_amount);
+            }
+            None => assert!(account.merge(&source).is_err()),
+        }
+    }
+
+    #[test]
+    fn test_merge_rejects_different_terms() {
+        let mut account = bond_account(5, 10);
+        let source = BondAccount::init(Pubkey::default(), 0, 5, 2, PCT, 15);
+
+        assert!(account.merge(&source).is_err());
+    }
+
+    #[test]
+    fn test_split_then_merge_restores_account() {
+        let mut account = bond_account(10, 10);
+        let index = account.split_off(3).unwrap();
+
+        account.merge(&bond_account(3, index)).unwrap();
+        assert_eq!((account.index, account.bonds.amount), (10, 10));
+    }
 }
\ No newline at end of file
@@ -1,13 +1,24 @@
 use anchor_lang::prelude::{*, borsh::{BorshSerialize, BorshDeserialize}};
+use crate::errors::ErrorCode;
 
 #[derive(Debug, Clone, Copy, BorshSerialize, BorshDeserialize)]
 pub struct CustodyAccounts {
     pub unit_custody: Pubkey,
+    /// Holds the position NFT of the protocol-owned liquidity, the default pubkey until the position is opened
     pub unit_usdc_lp_custody: Pubkey,
 }
 
 impl CustodyAccounts {
-    pub fn init(unit_custody: Pubkey, unit_usdc_lp_custody: Pubkey) -> Self {
-        Self { unit_custody, unit_usdc_lp_custody }
+    pub fn init(unit_custody: Pubkey) -> Self {
+        Self { unit_custody, unit_usdc_lp_custody: Pubkey::default() }
+    }
+
+    /// Records the account holding the position NFT, the protocol only ever opens one position
+    pub fn set_unit_usdc_lp_custody(&mut self, unit_usdc_lp_custody: Pubkey) -> Result<()> {
+        require_keys_eq!(self.unit_usdc_lp_custody, Pubkey::default(), ErrorCode::PolPositionOpened);
+
+        self.unit_usdc_lp_custody = unit_usdc_lp_custody;
+
+        Ok(())
     }
 }
\ No newline at end of file
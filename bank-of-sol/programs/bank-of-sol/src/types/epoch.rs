@@ -12,8 +12,12 @@ pub struct Epoch {
     pub slot: u64,
     /// Was the price of Units (U) above peg at the beginning of this epoch
     pub above_peg: bool,
+    /// The TWAP of Units at the beginning of this epoch, scaled by `PEG_DECIMALS`
+    pub twap: u64,
     /// Base interest rate for this epoch
     pub base_rate: u64,
+    /// The rate supply is expanded by this epoch, as a percentage scaled by `INTEREST_RATE_PRECISION`
+    pub expansion_rate: u64,
 }
 
 pub const HOUR: i64 = 60 * 60;
@@ -27,11 +31,13 @@ impl Epoch {
             timestamp: clock.unix_timestamp,
             slot: clock.slot,
             above_peg: true,
+            twap: 0,
             base_rate: 0,
+            expansion_rate: 0,
         }
     }
 
-    pub fn advance(&self, above_peg: bool, base_rate: u64) -> Result<Self> {
+    pub fn advance(&self, above_peg: bool, twap: u64, base_rate: u64, expansion_rate: u64) -> Result<Self> {
         let clock = Clock::get().unwrap();
 
         if clock.unix_timestamp < self.timestamp + HOUR {
@@ -43,7 +49,9 @@ impl Epoch {
             timestamp: clock.unix_timestamp,
             slot: clock.slot,
             above_peg,
+            twap,
             base_rate,
+            expansion_rate,
         })
     }
 }
\ No newline at end of file
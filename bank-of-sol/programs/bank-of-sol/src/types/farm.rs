@@ -0,0 +1,278 @@
+use anchor_lang::prelude::{*, borsh::{BorshSerialize, BorshDeserialize}};
+use raydium_amm_v3::states::{PoolState, PersonalPositionState};
+use crate::types::boardroom::REWARD_PER_SHARE_PRECISION;
+use crate::types::epoch::HOUR;
+use crate::utils::account_deserialize;
+use crate::errors::ErrorCode;
+
+/// Gauge emissions are streamed over the shortest an epoch lasts, so they are paid out before the next
+pub const EMISSION_PERIOD: i64 = HOUR;
+
+/// A reward-per-token accumulator paying `rate` rewards per second until `rate_end` pro rata over the staked tokens
+#[derive(Debug, Default, Clone, Copy, BorshSerialize, BorshDeserialize)]
+pub struct FarmRewards {
+    /// The rewards paid per second (8)
+    pub rate: u64,
+    /// Rewards accrued per staked token, scaled by `REWARD_PER_SHARE_PRECISION` (16)
+    pub reward_per_token: u128,
+    /// The timestamp `reward_per_token` was last updated at (8)
+    pub last_update: i64,
+    /// The total staked (8)
+    pub total_staked: u64,
+    /// The timestamp `rate` is paid until (8)
+    pub rate_end: i64,
+}
+
+impl FarmRewards {
+    pub const LEN: usize = 8 + 16 + 8 + 8 + 8;
+
+    /// Pays `rate` until the rate is changed
+    pub fn init(rate: u64, now: i64) -> Self {
+        Self { rate, last_update: now, rate_end: i64::MAX, ..Default::default() }
+    }
+
+    /// Accrues rewards up to `now`, nothing accrues while nothing is staked
+    pub fn update(&mut self, now: i64) -> u128 {
+        let elapsed = now.min(self.rate_end).saturating_sub(self.last_update).max(0) as u128;
+
+        if self.total_staked > 0 {
+            self.reward_per_token += self.rate as u128 * elapsed * REWARD_PER_SHARE_PRECISION / self.total_staked as u128;
+        }
+
+        self.last_update = self.last_update.max(now);
+
+        self.reward_per_token
+    }
+
+    /// Sets the rate paid until it is changed again, rewards accrued at the previous rate are kept
+    pub fn set_rate(&mut self, rate: u64, now: i64) {
+        self.update(now);
+        self.rate = rate;
+        self.rate_end = i64::MAX;
+    }
+
+    /// Streams `amount` and whatever is left of the current stream over the next `EMISSION_PERIOD`, must
+    /// only be used while `rate` is zero or streamed
+    pub fn notify(&mut self, amount: u64, now: i64) {
+        self.update(now);
+
+        let leftover = self.rate as u128 * self.rate_end.saturating_sub(now).max(0) as u128;
+
+        self.rate = ((amount as u128 + leftover) / EMISSION_PERIOD as u128) as u64;
+        self.rate_end = now + EMISSION_PERIOD;
+    }
+}
+
+/// The rewards earned by `amount` staked since `reward_per_token_paid`
+pub fn earned(amount: u64, reward_per_token: u128, reward_per_token_paid: u128) -> u64 {
+    (amount as u128 * (reward_per_token - reward_per_token_paid) / REWARD_PER_SHARE_PRECISION) as u64
+}
+
+/// Whether a position over `[tick_lower, tick_upper)` is providing liquidity at `tick_current`
+pub fn in_range(tick_current: i32, tick_lower: i32, tick_upper: i32) -> bool {
+    tick_lower <= tick_current && tick_current < tick_upper
+}
+
+/**
+ * Range stake
+ *
+ * A staked concentrated liquidity position only counts towards `FarmRewards::total_staked`, and so only
+ * accrues rewards, while active. A position is active when the pool's current tick was inside its range
+ * at its last sync. Syncs happen on every interaction with the position and can be triggered by anyone.
+ *
+ * Rewards accrued since the last sync are only settled to the owner if the position is still in range,
+ * otherwise there is no telling how much of it was earned in range. Those rewards go to whoever synced
+ * the position instead, so keepers are paid to deactivate positions that left their range, and owners
+ * have to sync, or harvest, while in range to keep what they earned.
+ */
+#[derive(Debug, Default, Clone, Copy, BorshSerialize, BorshDeserialize)]
+pub struct RangeStake {
+    /// The liquidity of the position (8)
+    pub liquidity: u64,
+    /// The lower tick of the position (4)
+    pub tick_lower: i32,
+    /// The upper tick of the position (4)
+    pub tick_upper: i32,
+    /// Whether the liquidity is counted towards the farm's total (1)
+    pub active: bool,
+    /// The farm's reward per token at the last sync (16)
+    pub reward_per_token_paid: u128,
+    /// Rewards settled but not yet harvested (8)
+    pub pending_rewards: u64,
+}
+
+impl RangeStake {
+    pub const LEN: usize = 8 + 4 + 4 + 1 + 16 + 8;
+
+    pub fn init(liquidity: u64, tick_lower: i32, tick_upper: i32) -> Self {
+        Self { liquidity, tick_lower, tick_upper, ..Default::default() }
+    }
+
+    /// Settles rewards up to `now` and (de)activates the stake for the pool's `tick_current`, returns the
+    /// bounty of the syncer when the stake is found out of range
+    pub fn sync(&mut self, rewards: &mut FarmRewards, tick_current: i32, now: i64) -> u64 {
+        let reward_per_token = rewards.update(now);
+        let accrued = if self.active { earned(self.liquidity, reward_per_token, self.reward_per_token_paid) } else { 0 };
+        let active = in_range(tick_current, self.tick_lower, self.tick_upper);
+
+        self.reward_per_token_paid = reward_per_token;
+        self.set_active(rewards, active);
+
+        if active {
+            self.pending_rewards += accrued;
+            0
+        } else {
+            accrued
+        }
+    }
+
+    /// Must only be called after `sync` so that no accrued rewards are lost
+    pub fn set_active(&mut self, rewards: &mut FarmRewards, active: bool) {
+        match (self.active, active) {
+            (false, true) => rewards.total_staked += self.liquidity,
+            (true, false) => rewards.total_staked -= self.liquidity,
+            _ => {}
+        }
+
+        self.active = active;
+    }
+}
+
+/// Gives the current tick of the given pool
+pub fn pool_tick(pool: &AccountInfo) -> Result<i32> {
+    let pool_state: PoolState = account_deserialize(pool)?;
+
+    Ok(pool_state.tick_current)
+}
+
+/// Reads the given personal position of `nft_mint` in `pool` into an inactive stake
+pub fn position_stake(position: &AccountInfo, pool: Pubkey, nft_mint: Pubkey) -> Result<RangeStake> {
+    let position_state: PersonalPositionState = account_deserialize(position)?;
+
+    require_keys_eq!(position_state.pool_id, pool, ErrorCode::InvalidPosition);
+    require_keys_eq!(position_state.nft_mint, nft_mint, ErrorCode::InvalidPosition);
+
+    let liquidity = u64::try_from(position_state.liquidity).map_err(|_| ErrorCode::PositionLiquidityTooLarge)?;
+
+    Ok(RangeStake::init(liquidity, position_state.tick_lower_index, position_state.tick_upper_index))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test]
+    fn test_nothing_staked() {
+        let mut rewards = FarmRewards::init(10, 0);
+
+        assert_eq!(rewards.update(100), 0);
+        assert_eq!(rewards.last_update, 100);
+    }
+
+    #[test]
+    fn test_pro_rata() {
+        let mut rewards = FarmRewards::init(10, 0);
+
+        // A stakes 100 at 0, B stakes 300 at 10, both are paid out at 20
+        rewards.total_staked = 100;
+        let b_paid = rewards.update(10);
+        rewards.total_staked += 300;
+        let rpt = rewards.update(20);
+
+        assert_eq!(earned(100, rpt, 0), 100 + 25);
+        assert_eq!(earned(300, rpt, b_paid), 75);
+    }
+
+    #[test]
+    fn test_update_is_idempotent() {
+        let mut rewards = FarmRewards::init(7, 0);
+        rewards.total_staked = 3;
+
+        let rpt = rewards.update(9);
+
+        assert_eq!(rewards.update(9), rpt);
+        assert_eq!(rewards.update(5), rpt);
+        assert_eq!(earned(3, rpt, 0), 63);
+    }
+
+    #[test]
+    fn test_notify_streams_over_emission_period() {
+        let mut rewards = FarmRewards::init(0, 0);
+        rewards.total_staked = 1;
+
+        rewards.notify(7_200, 0);
+        let rpt = rewards.update(EMISSION_PERIOD / 2);
+
+        assert_eq!(earned(1, rpt, 0), 3_600);
+
+        // The half left is streamed with the new amount, which is paid out entirely by the new end
+        rewards.notify(3_600, EMISSION_PERIOD / 2);
+        assert_eq!(rewards.rate, 2);
+
+        let rpt = rewards.update(10 * EMISSION_PERIOD);
+
+        assert_eq!(earned(1, rpt, 0), 3_600 + 7_200);
+    }
+
+    #[test]
+    fn test_set_rate_keeps_accrued_rewards() {
+        let mut rewards = FarmRewards::init(10, 0);
+        rewards.total_staked = 1;
+
+        rewards.set_rate(20, 10);
+        let rpt = rewards.update(20);
+
+        assert_eq!(earned(1, rpt, 0), 100 + 200);
+        assert_eq!(rewards.rate_end, i64::MAX);
+    }
+
+    #[test_case(-10, false; "below")]
+    #[test_case(0, true; "at lower")]
+    #[test_case(50, true; "inside")]
+    #[test_case(100, false; "at upper")]
+    fn test_in_range(tick_current: i32, expected: bool) {
+        assert_eq!(in_range(tick_current, 0, 100), expected);
+    }
+
+    #[test]
+    fn test_range_stake_accrues_only_in_range() {
+        let mut rewards = FarmRewards::init(10, 0);
+        let mut stake = RangeStake::init(100, 0, 100);
+
+        stake.sync(&mut rewards, 50, 0);
+        assert_eq!(rewards.total_staked, 100);
+        stake.sync(&mut rewards, 50, 10);
+
+        // Is out of range when synced at 20, is back in range at 30 and synced until 40
+        assert_eq!(stake.sync(&mut rewards, 150, 20), 100);
+        assert_eq!(rewards.total_staked, 0);
+        assert_eq!(stake.sync(&mut rewards, 20, 30), 0);
+        assert_eq!(stake.sync(&mut rewards, 20, 40), 0);
+
+        assert_eq!(stake.pending_rewards, 100 + 100);
+        assert!(stake.active);
+    }
+
+    #[test]
+    fn test_range_stakes_share_rewards() {
+        let mut rewards = FarmRewards::init(10, 0);
+        let mut narrow = RangeStake::init(300, 40, 60);
+        let mut wide = RangeStake::init(100, 0, 100);
+
+        narrow.sync(&mut rewards, 50, 0);
+        wide.sync(&mut rewards, 50, 0);
+
+        narrow.sync(&mut rewards, 55, 10);
+        wide.sync(&mut rewards, 55, 10);
+
+        // Narrow left its range some time after 10, its rewards since then are the syncer's bounty
+        assert_eq!(narrow.sync(&mut rewards, 70, 20), 75);
+        wide.sync(&mut rewards, 70, 20);
+        narrow.sync(&mut rewards, 70, 30);
+        wide.sync(&mut rewards, 70, 30);
+
+        assert_eq!(narrow.pending_rewards, 75);
+        assert_eq!(wide.pending_rewards, 25 + 25 + 100);
+    }
+}
\ No newline at end of file
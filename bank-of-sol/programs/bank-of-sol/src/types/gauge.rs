@@ -0,0 +1,235 @@
+use anchor_lang::prelude::{*, borsh::{BorshSerialize, BorshDeserialize}};
+use muldiv::*;
+use crate::types::interest_rate::ONE_HUNDRED_PCT;
+
+/// The weight a voter can allocate across gauges each epoch, in basis points
+pub const MAX_VOTE_WEIGHT: u64 = 10_000;
+
+/// The votes cast with `weight` basis points of `power`
+pub fn weighted_votes(power: u64, weight: u64) -> u64 {
+    power.mul_div_floor(weight, MAX_VOTE_WEIGHT).unwrap()
+}
+
+/// A gauge's share of emissions from its votes, scaled by `INTEREST_RATE_PRECISION`, zero when nobody voted
+pub fn emission_share(votes: u64, total_votes: u64) -> u64 {
+    if total_votes == 0 {
+        return 0;
+    }
+
+    votes.mul_div_floor(ONE_HUNDRED_PCT, total_votes).unwrap()
+}
+
+/// The part of `budget` owed to a gauge with `emission_share`
+pub fn gauge_emissions(budget: u64, emission_share: u64) -> u64 {
+    budget.mul_div_floor(emission_share, ONE_HUNDRED_PCT).unwrap()
+}
+
+/// The kind of reward pool a gauge distributes emissions to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub enum GaugeTarget {
+    /// A `Farm` paying shares, streamed through its reward rate
+    Farm,
+    /// A `ClmmFarm` paying shares, streamed through its reward rate
+    ClmmFarm,
+    /// The boardroom, paid pro rata over boosted shares
+    Boardroom,
+    /// NFT staking, paid pro rata over staked NFTs
+    NftStaking,
+}
+
+/**
+ * Emissions budget
+ *
+ * Emitted shares are added to the budget as they are minted. When an epoch starts, everything emitted
+ * during the last one and whatever gauges didn't take of the last budget becomes the epoch's budget,
+ * which gauges take their share of. Shares in the reward vault beyond `reserved` aren't budgeted.
+ */
+#[derive(Debug, Default, Clone, Copy, BorshSerialize, BorshDeserialize)]
+pub struct EmissionsBudget {
+    /// The shares emitted since the budget was last rolled over (8)
+    pub emitted: u64,
+    /// The epoch the budget is for (8)
+    pub epoch: u64,
+    /// The budget gauges take their share of (8)
+    pub amount: u64,
+    /// The part of `amount` gauges haven't taken yet (8)
+    pub remaining: u64,
+}
+
+impl EmissionsBudget {
+    pub const LEN: usize = 8 + 8 + 8 + 8;
+
+    pub fn add_emissions(&mut self, amount: u64) {
+        self.emitted += amount;
+    }
+
+    /// Starts the budget of `epoch` if it hasn't been started yet
+    pub fn roll_over(&mut self, epoch: u64) {
+        if self.epoch == epoch {
+            return;
+        }
+
+        self.amount = self.remaining + self.emitted;
+        self.remaining = self.amount;
+        self.emitted = 0;
+        self.epoch = epoch;
+    }
+
+    /// Takes the part of the budget owed to a gauge with `emission_share`
+    pub fn take(&mut self, emission_share: u64) -> u64 {
+        let amount = gauge_emissions(self.amount, emission_share).min(self.remaining);
+
+        self.remaining -= amount;
+
+        amount
+    }
+
+    /// Returns emissions a gauge took but couldn't distribute, they join the next epoch's budget
+    pub fn refund(&mut self, amount: u64) {
+        self.remaining += amount;
+    }
+
+    /// The shares in the reward vault that belong to the budget
+    pub fn reserved(&self) -> u64 {
+        self.emitted + self.remaining
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+    use crate::state::gauge::{Gauge, GaugeController};
+    use crate::types::interest_rate::INTEREST_RATE_PRECISION;
+
+    const PCT: u64 = INTEREST_RATE_PRECISION;
+
+    fn controller() -> GaugeController {
+        GaugeController {
+            gauge_count: 2,
+            voting_epoch: 1,
+            total_votes: 0,
+            previous_voting_epoch: 0,
+            previous_total_votes: 0,
+            budget: EmissionsBudget::default(),
+            boardroom_vault: Pubkey::default(),
+            nft_staking_vault: Pubkey::default(),
+            bump: 0,
+        }
+    }
+
+    fn gauge() -> Gauge {
+        Gauge {
+            target: GaugeTarget::Boardroom,
+            pool: Pubkey::default(),
+            target_vault: Pubkey::default(),
+            voting_epoch: 1,
+            votes: 0,
+            emission_share: 0,
+            share_epoch: 1,
+            distributed_epoch: 0,
+            bump: 0,
+        }
+    }
+
+    #[test_case(1_000, 10_000, 1_000; "all weight")]
+    #[test_case(1_000, 2_500, 250; "quarter")]
+    #[test_case(3, 5_000, 1; "rounds down")]
+    fn test_weighted_votes(power: u64, weight: u64, expected: u64) {
+        assert_eq!(weighted_votes(power, weight), expected);
+    }
+
+    #[test_case(0, 0, 0; "no votes")]
+    #[test_case(250, 1_000, 25 * PCT; "quarter")]
+    #[test_case(1_000, 1_000, 100 * PCT; "all")]
+    fn test_emission_share(votes: u64, total_votes: u64, expected: u64) {
+        assert_eq!(emission_share(votes, total_votes), expected);
+    }
+
+    #[test]
+    fn test_gauge_emissions_never_exceed_budget() {
+        let votes = [1, 2, 4];
+        let total = votes.iter().sum();
+
+        let emitted: u64 = votes.iter().map(|v| gauge_emissions(1_000, emission_share(*v, total))).sum();
+
+        assert!(emitted <= 1_000);
+        assert_eq!(emitted, 142 + 285 + 571);
+    }
+
+    #[test]
+    fn test_budget_rolls_over_emissions_and_leftovers() {
+        let mut budget = EmissionsBudget::default();
+        budget.add_emissions(1_000);
+        budget.roll_over(1);
+
+        assert_eq!(budget.take(25 * PCT), 250);
+        assert_eq!(budget.take(25 * PCT), 250);
+
+        budget.add_emissions(300);
+        assert_eq!(budget.reserved(), 800);
+
+        // Rolling over again in the same epoch changes nothing
+        budget.roll_over(1);
+        assert_eq!(budget.amount, 1_000);
+
+        budget.roll_over(2);
+        assert_eq!(budget.amount, 800);
+        assert_eq!(budget.remaining, 800);
+        assert_eq!(budget.emitted, 0);
+    }
+
+    #[test]
+    fn test_budget_never_overspent() {
+        let mut budget = EmissionsBudget::default();
+        budget.add_emissions(100);
+        budget.roll_over(1);
+
+        assert_eq!(budget.take(60 * PCT), 60);
+        assert_eq!(budget.take(60 * PCT), 40);
+        assert_eq!(budget.reserved(), 0);
+    }
+
+    #[test]
+    fn test_refund_joins_next_budget() {
+        let mut budget = EmissionsBudget::default();
+        budget.add_emissions(1_000);
+        budget.roll_over(1);
+
+        let amount = budget.take(50 * PCT);
+        budget.refund(amount);
+
+        // Refunds don't raise the shares other gauges take this epoch
+        assert_eq!(budget.take(50 * PCT), 500);
+
+        budget.roll_over(2);
+        assert_eq!(budget.amount, 500);
+    }
+
+    #[test]
+    fn test_gauges_share_emissions_by_last_epochs_votes() {
+        let mut controller = controller();
+        let (mut a, mut b) = (gauge(), gauge());
+
+        a.add_votes(300, 1);
+        b.add_votes(100, 1);
+        controller.add_votes(400, 1);
+        controller.budget.add_emissions(1_000);
+
+        // Voting for b in epoch 2 before it is distributed keeps its epoch 1 votes
+        b.roll_over(2, controller.previous_total_votes(2));
+        b.add_votes(50, 2);
+        controller.add_votes(50, 2);
+
+        assert_eq!(a.distribute(&mut controller, 2), 750);
+        assert_eq!(b.distribute(&mut controller, 2), 250);
+        assert_eq!(a.distribute(&mut controller, 2), 0);
+
+        controller.budget.add_emissions(1_000);
+
+        // Only b was voted for in epoch 2
+        assert_eq!(a.distribute(&mut controller, 3), 0);
+        assert_eq!(b.distribute(&mut controller, 3), 1_000);
+        assert_eq!(controller.previous_total_votes(4), 0);
+    }
+}
\ No newline at end of file
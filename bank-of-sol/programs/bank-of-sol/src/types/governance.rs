@@ -0,0 +1,83 @@
+use anchor_lang::prelude::{*, borsh::{BorshSerialize, BorshDeserialize}};
+use crate::types::interest_rate::{RateCurve, ONE_HUNDRED_PCT};
+use crate::types::monetary_policy::{MonetaryPolicyKind, PolicyParams};
+use crate::types::pid::PidGains;
+use crate::errors::ErrorCode;
+
+/// The voting rules of governance proposals, percentages scaled by `INTEREST_RATE_PRECISION`
+#[derive(Debug, Default, Clone, Copy, BorshSerialize, BorshDeserialize)]
+pub struct GovernanceParams {
+    /// The share of boardroom deposits at the snapshot that has to vote for a proposal to be valid (8)
+    pub quorum: u64,
+    /// The share of votes cast that has to be exceeded by votes in favour for a proposal to pass (8)
+    pub threshold: u64,
+    /// The time a proposal is open for voting, in seconds (8)
+    pub voting_period: i64,
+}
+
+impl GovernanceParams {
+    pub fn init(quorum: u64, threshold: u64, voting_period: i64) -> Result<Self> {
+        require_gte!(ONE_HUNDRED_PCT, quorum, ErrorCode::InvalidGovernanceParams);
+        require_gte!(ONE_HUNDRED_PCT, threshold, ErrorCode::InvalidGovernanceParams);
+        require_gt!(voting_period, 0, ErrorCode::InvalidGovernanceParams);
+
+        Ok(Self { quorum, threshold, voting_period })
+    }
+
+    /// Whether a vote reached quorum out of `supply` and more than `threshold` of the votes cast are in favour
+    pub fn passed(&self, votes_for: u64, votes_against: u64, supply: u64) -> bool {
+        let votes = votes_for as u128 + votes_against as u128;
+
+        votes > 0
+            && votes * ONE_HUNDRED_PCT as u128 >= supply as u128 * self.quorum as u128
+            && votes_for as u128 * ONE_HUNDRED_PCT as u128 > votes * self.threshold as u128
+    }
+}
+
+/// A change of the Singleton's parameters, applied through the timelock once queued by an admin or a passed proposal
+#[derive(Debug, Clone, Copy, BorshSerialize, BorshDeserialize)]
+pub enum ConfigChange {
+    InterestRate { curve: RateCurve, floor: u64, ceiling: u64 },
+    DebtCeiling { debt_ceiling: u64 },
+    BondTerms { maturity: u64, expiry: u64, premium_per_epoch: u64, max_hold_premium: u64 },
+    BondAuction { enabled: bool, start_discount: u64, max_discount: u64, duration: i64 },
+    RedemptionPremium { threshold: u64, multiplier: u64, max_premium: u64 },
+    BoardroomBoost { boost_per_nft: u64, max_boosted_nfts: u64, max_boost: u64 },
+    MonetaryPolicy { kind: MonetaryPolicyKind, params: PolicyParams },
+    Pid { gains: PidGains, integral_limit: i64, max_expansion_rate: u64, max_bond_discount: u64 },
+    PolShare { seigniorage_share: u64 },
+    Collection { collection: Pubkey },
+    MinimumPeriod { minimum_period: u64 },
+}
+
+impl ConfigChange {
+    /// The size of the largest variant, `MonetaryPolicy`, and the variant index
+    pub const LEN: usize = 1 + 1 + 112;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+    use crate::types::interest_rate::INTEREST_RATE_PRECISION;
+
+    const PCT: u64 = INTEREST_RATE_PRECISION;
+
+    #[test_case(60, 40, 1_000, true; "passes")]
+    #[test_case(100, 0, 1_000, true; "unanimous")]
+    #[test_case(50, 49, 1_000, false; "below quorum")]
+    #[test_case(50, 50, 1_000, false; "tie")]
+    #[test_case(0, 0, 0, false; "no votes")]
+    fn test_passed(votes_for: u64, votes_against: u64, supply: u64, expected: bool) {
+        let params = GovernanceParams::init(10 * PCT, 50 * PCT, 1).unwrap();
+
+        assert_eq!(params.passed(votes_for, votes_against, supply), expected);
+    }
+
+    #[test_case(101 * PCT, 50 * PCT, 1; "quorum")]
+    #[test_case(10 * PCT, 101 * PCT, 1; "threshold")]
+    #[test_case(10 * PCT, 50 * PCT, 0; "voting period")]
+    fn test_invalid_params(quorum: u64, threshold: u64, voting_period: i64) {
+        assert!(GovernanceParams::init(quorum, threshold, voting_period).is_err());
+    }
+}
\ No newline at end of file
@@ -0,0 +1,183 @@
+use anchor_lang::prelude::{*, borsh::{BorshSerialize, BorshDeserialize}};
+use muldiv::*;
+use crate::errors::ErrorCode;
+use crate::types::oracle::PEG;
+
+/// Interest rates are percentages scaled by this factor, i.e. `10 * INTEREST_RATE_PRECISION` is 10%
+pub const INTEREST_RATE_PRECISION: u64 = 1_000_000;
+pub const ONE_HUNDRED_PCT: u64 = 100 * INTEREST_RATE_PRECISION;
+
+/// Upper bound on the exponent of `RateCurve::Exponential` to bound compute usage
+const MAX_EXPONENTIAL_STEPS: u64 = 100;
+
+/// A point on a piecewise-linear rate curve
+#[derive(Debug, Default, Clone, Copy, BorshSerialize, BorshDeserialize)]
+pub struct RatePoint {
+    /// The distance of the TWAP below peg, as a percentage scaled by `INTEREST_RATE_PRECISION` (8)
+    pub deviation: u64,
+    /// The interest rate at `deviation`, as a percentage scaled by `INTEREST_RATE_PRECISION` (8)
+    pub rate: u64,
+}
+
+#[derive(Debug, Clone, Copy, BorshSerialize, BorshDeserialize)]
+pub enum RateCurve {
+    /// Interpolates linearly between points ordered by deviation, flat outside of them
+    PiecewiseLinear { points: [RatePoint; 4] },
+    /// `base_rate * (1 + growth)^n` where `n` is the number of whole percentage points below peg
+    Exponential { base_rate: u64, growth: u64 },
+}
+
+impl RateCurve {
+    pub fn rate(&self, deviation: u64) -> u64 {
+        match self {
+            Self::PiecewiseLinear { points } => piecewise_linear_rate(points, deviation),
+            Self::Exponential { base_rate, growth } => {
+                let steps = (deviation / INTEREST_RATE_PRECISION).min(MAX_EXPONENTIAL_STEPS);
+
+                (0..steps).fold(*base_rate, |rate, _| {
+                    rate.mul_div_floor(ONE_HUNDRED_PCT + growth, ONE_HUNDRED_PCT).unwrap_or(u64::MAX)
+                })
+            },
+        }
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        if let Self::PiecewiseLinear { points } = self {
+            for pair in points.windows(2) {
+                require_gt!(pair[1].deviation, pair[0].deviation, ErrorCode::InvalidRateCurve);
+                require_gte!(pair[1].rate, pair[0].rate, ErrorCode::InvalidRateCurve);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn piecewise_linear_rate(points: &[RatePoint; 4], deviation: u64) -> u64 {
+    if deviation <= points[0].deviation {
+        return points[0].rate;
+    }
+
+    for pair in points.windows(2) {
+        let (start, end) = (pair[0], pair[1]);
+
+        if deviation <= end.deviation {
+            let increase = (end.rate - start.rate)
+                .mul_div_floor(deviation - start.deviation, end.deviation - start.deviation)
+                .unwrap();
+
+            return start.rate + increase;
+        }
+    }
+
+    points[3].rate
+}
+
+/// Configures the bond interest rate as a function of the TWAP's distance below peg
+#[derive(Debug, Clone, Copy, BorshSerialize, BorshDeserialize)]
+pub struct InterestRateConfig {
+    /// The rate curve (65)
+    pub curve: RateCurve,
+    /// The minimum interest rate (8)
+    pub floor: u64,
+    /// The maximum interest rate (8)
+    pub ceiling: u64,
+}
+
+impl Default for InterestRateConfig {
+    fn default() -> Self {
+        Self {
+            curve: RateCurve::Exponential { base_rate: 10 * INTEREST_RATE_PRECISION, growth: 0 },
+            floor: 0,
+            ceiling: ONE_HUNDRED_PCT,
+        }
+    }
+}
+
+impl InterestRateConfig {
+    pub fn init(curve: RateCurve, floor: u64, ceiling: u64) -> Result<Self> {
+        require_gte!(ceiling, floor, ErrorCode::InvalidRateCurve);
+        curve.validate()?;
+
+        Ok(Self { curve, floor, ceiling })
+    }
+
+    pub fn rate(&self, twap: u64) -> u64 {
+        self.curve.rate(peg_deviation(twap)).clamp(self.floor, self.ceiling)
+    }
+}
+
+/// The distance of `twap` below peg as a percentage scaled by `INTEREST_RATE_PRECISION`, zero when at or above peg
+pub fn peg_deviation(twap: u64) -> u64 {
+    if twap >= PEG {
+        return 0;
+    }
+
+    (PEG - twap).mul_div_floor(ONE_HUNDRED_PCT, PEG).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    const PCT: u64 = INTEREST_RATE_PRECISION;
+
+    fn piecewise() -> RateCurve {
+        RateCurve::PiecewiseLinear {
+            points: [
+                RatePoint { deviation: 0, rate: 2 * PCT },
+                RatePoint { deviation: 10 * PCT, rate: 10 * PCT },
+                RatePoint { deviation: 20 * PCT, rate: 30 * PCT },
+                RatePoint { deviation: 50 * PCT, rate: 60 * PCT },
+            ],
+        }
+    }
+
+    #[test_case(PEG, 0; "at peg")]
+    #[test_case(PEG * 2, 0; "above peg")]
+    #[test_case(900_000, 10 * PCT; "ten percent below")]
+    #[test_case(0, 100 * PCT; "zero price")]
+    fn test_peg_deviation(twap: u64, expected: u64) {
+        assert_eq!(peg_deviation(twap), expected);
+    }
+
+    #[test_case(0, 2 * PCT; "first point")]
+    #[test_case(5 * PCT, 6 * PCT; "interpolated first segment")]
+    #[test_case(15 * PCT, 20 * PCT; "interpolated second segment")]
+    #[test_case(50 * PCT, 60 * PCT; "last point")]
+    #[test_case(90 * PCT, 60 * PCT; "beyond last point")]
+    fn test_piecewise_linear_rate(deviation: u64, expected: u64) {
+        assert_eq!(piecewise().rate(deviation), expected);
+    }
+
+    #[test_case(0, 10 * PCT; "at peg")]
+    #[test_case(PCT / 2, 10 * PCT; "partial percentage point")]
+    #[test_case(2 * PCT, 12_100_000; "two percentage points")]
+    fn test_exponential_rate(deviation: u64, expected: u64) {
+        let curve = RateCurve::Exponential { base_rate: 10 * PCT, growth: 10 * PCT };
+        assert_eq!(curve.rate(deviation), expected);
+    }
+
+    #[test]
+    fn test_rate_is_clamped() {
+        let config = InterestRateConfig::init(piecewise(), 5 * PCT, 40 * PCT).unwrap();
+
+        assert_eq!(config.rate(PEG), 5 * PCT);
+        assert_eq!(config.rate(0), 40 * PCT);
+    }
+
+    #[test]
+    fn test_unordered_curve_is_rejected() {
+        let curve = RateCurve::PiecewiseLinear {
+            points: [
+                RatePoint { deviation: 10 * PCT, rate: 2 * PCT },
+                RatePoint { deviation: 0, rate: 10 * PCT },
+                RatePoint { deviation: 20 * PCT, rate: 30 * PCT },
+                RatePoint { deviation: 50 * PCT, rate: 60 * PCT },
+            ],
+        };
+
+        assert!(curve.validate().is_err());
+    }
+}
\ No newline at end of file
@@ -4,4 +4,18 @@ pub mod oracle;
 pub mod bond;
 pub mod nft_staking;
 pub mod boardroom;
-pub mod custody;
\ No newline at end of file
+pub mod custody;
+pub mod interest_rate;
+pub mod pid;
+pub mod monetary_policy;
+pub mod pol;
+pub mod treasury;
+pub mod vesting;
+pub mod share;
+pub mod farm;
+pub mod ve;
+pub mod gauge;
+pub mod governance;
+pub mod timelock;
+pub mod roles;
+pub mod pause;
\ No newline at end of file
@@ -0,0 +1,391 @@
+use anchor_lang::prelude::{*, borsh::{BorshSerialize, BorshDeserialize}};
+use muldiv::*;
+use crate::errors::ErrorCode;
+use crate::types::interest_rate::{InterestRateConfig, peg_deviation, ONE_HUNDRED_PCT};
+use crate::types::oracle::PEG;
+use crate::types::pid::PidController;
+
+/// Upper bound on the number of decay periods applied by `FixedSchedule` to bound compute usage
+const MAX_DECAY_STEPS: u64 = 100;
+
+/// Selects the `MonetaryPolicy` applied when advancing the epoch
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub enum MonetaryPolicyKind {
+    /// Basis Cash style seigniorage above peg and bonds below peg
+    #[default]
+    Basis,
+    /// Tomb style seigniorage capped by supply tiers and bonds below peg
+    Tomb,
+    /// A decaying emission independent of price, with no bonds
+    FixedSchedule,
+    /// Seigniorage and bond discount set by `PidController`
+    Pid,
+}
+
+/// A supply tier of `MonetaryPolicyKind::Tomb`
+#[derive(Debug, Default, Clone, Copy, BorshSerialize, BorshDeserialize)]
+pub struct ExpansionTier {
+    /// The supply from which this tier applies (8)
+    pub supply: u64,
+    /// The maximum expansion rate in this tier (8)
+    pub max_expansion_rate: u64,
+}
+
+/// The emission of `MonetaryPolicyKind::FixedSchedule`
+#[derive(Debug, Default, Clone, Copy, BorshSerialize, BorshDeserialize)]
+pub struct FixedSchedule {
+    /// The Units emitted in the first epoch (8)
+    pub initial_emission: u64,
+    /// The emission decays by this rate every `decay_period` (8)
+    pub decay_rate: u64,
+    /// The number of epochs between decays, zero for a constant emission (8)
+    pub decay_period: u64,
+}
+
+/// The parameters of every policy, so that switching policy doesn't require reconfiguring it.
+/// Rates are percentages scaled by `INTEREST_RATE_PRECISION`
+#[derive(Debug, Default, Clone, Copy, BorshSerialize, BorshDeserialize)]
+pub struct PolicyParams {
+    /// The TWAP above which supply expands scaled by `PEG_DECIMALS`, zero to expand above peg (8)
+    pub expansion_threshold: u64,
+    /// The maximum expansion rate (8)
+    pub max_expansion_rate: u64,
+    /// The maximum share of supply offered as bonds in an epoch (8)
+    pub max_contraction_rate: u64,
+    /// Tomb supply tiers, ordered by supply (64)
+    pub tiers: [ExpansionTier; 4],
+    /// The fixed emission schedule (24)
+    pub schedule: FixedSchedule,
+}
+
+impl PolicyParams {
+    /// Validates the parameters used by `kind`, the others are left unchecked until their policy is selected
+    pub fn validate(&self, kind: MonetaryPolicyKind) -> Result<()> {
+        if kind != MonetaryPolicyKind::FixedSchedule {
+            require!(self.expansion_threshold == 0 || self.expansion_threshold >= PEG, ErrorCode::InvalidPolicyParams);
+            require_gte!(ONE_HUNDRED_PCT, self.max_contraction_rate, ErrorCode::InvalidPolicyParams);
+        }
+
+        match kind {
+            MonetaryPolicyKind::Tomb => {
+                for pair in self.tiers.windows(2) {
+                    require_gt!(pair[1].supply, pair[0].supply, ErrorCode::InvalidPolicyParams);
+                }
+            },
+            MonetaryPolicyKind::FixedSchedule => {
+                require_gte!(ONE_HUNDRED_PCT, self.schedule.decay_rate, ErrorCode::InvalidPolicyParams);
+            },
+            MonetaryPolicyKind::Basis | MonetaryPolicyKind::Pid => {},
+        }
+
+        Ok(())
+    }
+}
+
+/// The state of the protocol a policy decides the next epoch from
+#[derive(Debug, Default, Clone, Copy)]
+pub struct PolicyContext {
+    /// The index of the epoch being decided
+    pub epoch: u64,
+    /// The TWAP, scaled by `PEG_DECIMALS`
+    pub twap: u64,
+    /// The circulating supply of Units
+    pub supply: u64,
+    /// Bonds purchased and not yet redeemed
+    pub outstanding_bonds: u64,
+}
+
+/// The policy levers of the next epoch
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct PolicyDecision {
+    /// The Units to mint as seigniorage
+    pub seigniorage: u64,
+    /// The Units the protocol is willing to borrow as bonds
+    pub available_bonds: u64,
+    /// The bond interest rate
+    pub interest_rate: u64,
+}
+
+impl PolicyDecision {
+    /// The seigniorage as a percentage of `supply`
+    pub fn expansion_rate(&self, supply: u64) -> u64 {
+        if supply == 0 {
+            return 0;
+        }
+
+        self.seigniorage.mul_div_floor(ONE_HUNDRED_PCT, supply).unwrap_or(u64::MAX)
+    }
+}
+
+pub trait MonetaryPolicy {
+    /// The Units to mint as seigniorage
+    fn expansion(&self, ctx: &PolicyContext) -> u64;
+
+    /// The Units the protocol is willing to borrow as bonds
+    fn contraction(&self, ctx: &PolicyContext) -> u64;
+
+    /// The bond interest rate
+    fn interest_rate(&self, ctx: &PolicyContext) -> u64;
+
+    /// Decides the next epoch, policies with internal state commit it here
+    fn decide(&mut self, ctx: &PolicyContext) -> PolicyDecision {
+        PolicyDecision {
+            seigniorage: self.expansion(ctx),
+            available_bonds: self.contraction(ctx),
+            interest_rate: self.interest_rate(ctx),
+        }
+    }
+}
+
+/// The TWAP's premium over peg as a percentage scaled by `INTEREST_RATE_PRECISION`, zero unless above `threshold`
+fn expansion_premium(twap: u64, threshold: u64) -> u64 {
+    if twap <= threshold.max(PEG) {
+        return 0;
+    }
+
+    (twap - PEG).mul_div_floor(ONE_HUNDRED_PCT, PEG).unwrap_or(u64::MAX)
+}
+
+fn percentage_of(amount: u64, rate: u64) -> u64 {
+    amount.mul_div_floor(rate, ONE_HUNDRED_PCT).unwrap_or(u64::MAX)
+}
+
+/// Offers bonds for the supply needed to restore peg, up to `max_contraction_rate` of supply
+fn peg_contraction(params: &PolicyParams, ctx: &PolicyContext) -> u64 {
+    let rate = peg_deviation(ctx.twap).min(params.max_contraction_rate);
+
+    percentage_of(ctx.supply, rate)
+}
+
+pub struct BasisPolicy<'a> {
+    pub params: &'a PolicyParams,
+    pub rates: &'a InterestRateConfig,
+}
+
+impl MonetaryPolicy for BasisPolicy<'_> {
+    fn expansion(&self, ctx: &PolicyContext) -> u64 {
+        let rate = expansion_premium(ctx.twap, self.params.expansion_threshold).min(self.params.max_expansion_rate);
+
+        percentage_of(ctx.supply, rate)
+    }
+
+    fn contraction(&self, ctx: &PolicyContext) -> u64 {
+        peg_contraction(self.params, ctx)
+    }
+
+    fn interest_rate(&self, ctx: &PolicyContext) -> u64 {
+        self.rates.rate(ctx.twap)
+    }
+}
+
+pub struct TombPolicy<'a> {
+    pub params: &'a PolicyParams,
+    pub rates: &'a InterestRateConfig,
+}
+
+impl TombPolicy<'_> {
+    /// The expansion cap of the highest tier `supply` has reached
+    fn max_expansion_rate(&self, supply: u64) -> u64 {
+        self.params.tiers
+            .iter()
+            .rev()
+            .find(|tier| supply >= tier.supply)
+            .map_or(self.params.max_expansion_rate, |tier| tier.max_expansion_rate)
+    }
+}
+
+impl MonetaryPolicy for TombPolicy<'_> {
+    fn expansion(&self, ctx: &PolicyContext) -> u64 {
+        let rate = expansion_premium(ctx.twap, self.params.expansion_threshold).min(self.max_expansion_rate(ctx.supply));
+
+        percentage_of(ctx.supply, rate)
+    }
+
+    fn contraction(&self, ctx: &PolicyContext) -> u64 {
+        peg_contraction(self.params, ctx)
+    }
+
+    fn interest_rate(&self, ctx: &PolicyContext) -> u64 {
+        self.rates.rate(ctx.twap)
+    }
+}
+
+pub struct FixedSchedulePolicy<'a> {
+    pub schedule: &'a FixedSchedule,
+}
+
+impl MonetaryPolicy for FixedSchedulePolicy<'_> {
+    fn expansion(&self, ctx: &PolicyContext) -> u64 {
+        let schedule = self.schedule;
+
+        if schedule.decay_period == 0 {
+            return schedule.initial_emission;
+        }
+
+        let steps = ctx.epoch / schedule.decay_period;
+
+        if steps > MAX_DECAY_STEPS && schedule.decay_rate > 0 {
+            return 0;
+        }
+
+        let retained = ONE_HUNDRED_PCT.saturating_sub(schedule.decay_rate);
+
+        (0..steps).fold(schedule.initial_emission, |emission, _| percentage_of(emission, retained))
+    }
+
+    fn contraction(&self, _ctx: &PolicyContext) -> u64 {
+        0
+    }
+
+    fn interest_rate(&self, _ctx: &PolicyContext) -> u64 {
+        0
+    }
+}
+
+pub struct PidPolicy<'a> {
+    pub params: &'a PolicyParams,
+    pub pid: &'a mut PidController,
+}
+
+impl MonetaryPolicy for PidPolicy<'_> {
+    fn expansion(&self, ctx: &PolicyContext) -> u64 {
+        percentage_of(ctx.supply, self.pid.preview(ctx.twap).expansion_rate)
+    }
+
+    fn contraction(&self, ctx: &PolicyContext) -> u64 {
+        peg_contraction(self.params, ctx)
+    }
+
+    fn interest_rate(&self, ctx: &PolicyContext) -> u64 {
+        self.pid.preview(ctx.twap).bond_discount
+    }
+
+    fn decide(&mut self, ctx: &PolicyContext) -> PolicyDecision {
+        let output = self.pid.update(ctx.twap);
+
+        PolicyDecision {
+            seigniorage: percentage_of(ctx.supply, output.expansion_rate),
+            available_bonds: self.contraction(ctx),
+            interest_rate: output.bond_discount,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::interest_rate::INTEREST_RATE_PRECISION;
+    use crate::types::pid::{PidGains, PID_GAIN_PRECISION};
+    use test_case::test_case;
+
+    const PCT: u64 = INTEREST_RATE_PRECISION;
+    const SUPPLY: u64 = 1_000_000_000;
+
+    fn params() -> PolicyParams {
+        PolicyParams {
+            expansion_threshold: 1_010_000,
+            max_expansion_rate: 10 * PCT,
+            max_contraction_rate: 5 * PCT,
+            tiers: [
+                ExpansionTier { supply: 0, max_expansion_rate: 4 * PCT },
+                ExpansionTier { supply: SUPPLY, max_expansion_rate: 3 * PCT },
+                ExpansionTier { supply: 2 * SUPPLY, max_expansion_rate: 2 * PCT },
+                ExpansionTier { supply: 4 * SUPPLY, max_expansion_rate: PCT },
+            ],
+            schedule: FixedSchedule { initial_emission: 1_000, decay_rate: 10 * PCT, decay_period: 10 },
+        }
+    }
+
+    fn context(twap: u64, supply: u64) -> PolicyContext {
+        PolicyContext { epoch: 1, twap, supply, outstanding_bonds: 0 }
+    }
+
+    #[test_case(PEG, 0, 0; "at peg")]
+    #[test_case(1_005_000, 0, 0; "below threshold")]
+    #[test_case(1_020_000, 20_000_000, 0; "above threshold")]
+    #[test_case(1_500_000, 100_000_000, 0; "expansion capped")]
+    #[test_case(980_000, 0, 20_000_000; "below peg")]
+    #[test_case(500_000, 0, 50_000_000; "contraction capped")]
+    fn test_basis_policy(twap: u64, seigniorage: u64, available_bonds: u64) {
+        let params = params();
+        let rates = InterestRateConfig::default();
+        let mut policy = BasisPolicy { params: &params, rates: &rates };
+
+        let decision = policy.decide(&context(twap, SUPPLY));
+
+        assert_eq!(decision.seigniorage, seigniorage);
+        assert_eq!(decision.available_bonds, available_bonds);
+        assert_eq!(decision.interest_rate, 10 * PCT);
+    }
+
+    #[test_case(SUPPLY / 2, 20_000_000; "first tier")]
+    #[test_case(SUPPLY, 30_000_000; "second tier")]
+    #[test_case(3 * SUPPLY, 60_000_000; "third tier")]
+    fn test_tomb_policy_tiers(supply: u64, seigniorage: u64) {
+        let params = params();
+        let rates = InterestRateConfig::default();
+        let policy = TombPolicy { params: &params, rates: &rates };
+
+        assert_eq!(policy.expansion(&context(1_500_000, supply)), seigniorage);
+    }
+
+    #[test_case(0, 1_000; "first epoch")]
+    #[test_case(9, 1_000; "before first decay")]
+    #[test_case(10, 900; "first decay")]
+    #[test_case(25, 810; "second decay")]
+    #[test_case(10_000, 0; "fully decayed")]
+    fn test_fixed_schedule(epoch: u64, seigniorage: u64) {
+        let params = params();
+        let mut policy = FixedSchedulePolicy { schedule: &params.schedule };
+        let ctx = PolicyContext { epoch, twap: 500_000, supply: SUPPLY, outstanding_bonds: 0 };
+
+        assert_eq!(policy.decide(&ctx), PolicyDecision { seigniorage, available_bonds: 0, interest_rate: 0 });
+    }
+
+    #[test]
+    fn test_pid_policy_commits_state() {
+        let params = params();
+        let gains = PidGains { kp: 0, ki: PID_GAIN_PRECISION as i64, kd: 0 };
+        let mut pid = PidController::init(gains, 100 * PCT as i64, 10 * PCT, 30 * PCT);
+        let mut policy = PidPolicy { params: &params, pid: &mut pid };
+        let ctx = context(900_000, SUPPLY);
+
+        assert_eq!(policy.interest_rate(&ctx), 10 * PCT);
+        assert_eq!(policy.decide(&ctx).interest_rate, 10 * PCT);
+        assert_eq!(policy.decide(&ctx).interest_rate, 20 * PCT);
+        assert_eq!(pid.integral, 20 * PCT as i64);
+    }
+
+    #[test_case(MonetaryPolicyKind::Basis; "basis")]
+    #[test_case(MonetaryPolicyKind::Tomb; "tomb")]
+    #[test_case(MonetaryPolicyKind::FixedSchedule; "fixed schedule")]
+    #[test_case(MonetaryPolicyKind::Pid; "pid")]
+    fn test_valid_params(kind: MonetaryPolicyKind) {
+        assert!(params().validate(kind).is_ok());
+    }
+
+    #[test_case(MonetaryPolicyKind::Basis, true; "basis")]
+    #[test_case(MonetaryPolicyKind::Tomb, false; "tomb")]
+    #[test_case(MonetaryPolicyKind::FixedSchedule, true; "fixed schedule")]
+    #[test_case(MonetaryPolicyKind::Pid, true; "pid")]
+    fn test_default_params_only_need_tiers_under_tomb(kind: MonetaryPolicyKind, valid: bool) {
+        assert_eq!(PolicyParams::default().validate(kind).is_ok(), valid);
+    }
+
+    #[test_case(MonetaryPolicyKind::Basis, false; "basis")]
+    #[test_case(MonetaryPolicyKind::FixedSchedule, true; "fixed schedule")]
+    fn test_threshold_below_peg(kind: MonetaryPolicyKind, valid: bool) {
+        let params = PolicyParams { expansion_threshold: PEG - 1, ..params() };
+
+        assert_eq!(params.validate(kind).is_ok(), valid);
+    }
+
+    #[test]
+    fn test_decay_rate_above_one_hundred_pct_is_rejected() {
+        let mut params = params();
+        params.schedule.decay_rate = ONE_HUNDRED_PCT + 1;
+
+        assert!(params.validate(MonetaryPolicyKind::FixedSchedule).is_err());
+        assert!(params.validate(MonetaryPolicyKind::Basis).is_ok());
+    }
+}
\ No newline at end of file
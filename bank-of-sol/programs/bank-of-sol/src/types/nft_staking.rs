@@ -1,11 +1,12 @@
 use anchor_lang::prelude::{*, borsh::{BorshSerialize, BorshDeserialize}};
+use crate::types::boardroom::REWARD_PER_SHARE_PRECISION;
 
 /// Stores info on the NFT staking system
 #[derive(Debug, Default, Clone, BorshSerialize, BorshDeserialize)]
 pub struct NFTStakingStorage {
     /// The status of the staking (1)
     pub status: bool,
-    /// The staking authority (32)
+    /// Unused, staking is managed by holders of `Role::StakingManager` (32)
     pub authority: Pubkey,
     /// The verified collection address of the NFT (32)
     pub collection: Pubkey,
@@ -15,4 +16,63 @@ pub struct NFTStakingStorage {
     pub bump: u8,
     /// The bump of the NFT authority PDA (1)
     pub nft_auth_bump: u8,
+    /// The number of NFTs staked (8)
+    pub staked_nfts: u64,
+    /// Share rewards accrued per staked NFT, scaled by `REWARD_PER_SHARE_PRECISION` (16)
+    pub share_reward_per_nft: u128,
+}
+
+impl NFTStakingStorage {
+    /// Distributes `amount` of share rewards evenly over staked NFTs, returns false if no NFTs are staked
+    pub fn distribute_share_reward(&mut self, amount: u64) -> bool {
+        if self.staked_nfts == 0 {
+            return false;
+        }
+
+        self.share_reward_per_nft += amount as u128 * REWARD_PER_SHARE_PRECISION / self.staked_nfts as u128;
+
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::nft_stake_record::StakeRecordIndex;
+
+    #[test]
+    fn test_nothing_distributed_without_staked_nfts() {
+        let mut storage = NFTStakingStorage::default();
+
+        assert!(!storage.distribute_share_reward(1_000));
+        assert_eq!(storage.share_reward_per_nft, 0);
+    }
+
+    #[test]
+    fn test_share_rewards_survive_unstaking() {
+        let mut storage = NFTStakingStorage::default();
+        let (mut a, mut b) = (StakeRecordIndex::init(0), StakeRecordIndex::init(0));
+
+        a.settle_share_rewards(storage.share_reward_per_nft);
+        a.increment();
+        storage.staked_nfts += 1;
+        b.settle_share_rewards(storage.share_reward_per_nft);
+        b.increment();
+        b.increment();
+        storage.staked_nfts += 2;
+
+        assert!(storage.distribute_share_reward(300));
+
+        b.settle_share_rewards(storage.share_reward_per_nft);
+        b.pda_closed();
+        storage.staked_nfts -= 1;
+
+        assert!(storage.distribute_share_reward(300));
+
+        a.settle_share_rewards(storage.share_reward_per_nft);
+        b.settle_share_rewards(storage.share_reward_per_nft);
+
+        assert_eq!(a.pending_share_rewards, 100 + 150);
+        assert_eq!(b.pending_share_rewards, 200 + 150);
+    }
 }
\ No newline at end of file
@@ -5,7 +5,15 @@ use std::ops::Div;
 use crate::utils::account_deserialize;
 use crate::utils::math::sqrt_price_to_price;
 use crate::types::price::DatedPrice;
-use crate::errors::ProgramResult;
+use crate::errors::{ErrorCode, ProgramResult};
+
+/// The number of decimals TWAPs are scaled to when compared against peg
+pub const PEG_DECIMALS: u8 = 6;
+/// The target price of one Unit, scaled by `PEG_DECIMALS`
+pub const PEG: u64 = 1_000_000;
+/// The minimum seconds between keeper recorded observations, so a keeper can't fill the storage with
+/// observations of a single manipulated block
+pub const MIN_OBSERVATION_SPACING: u64 = 5 * 60;
 
 /// Gives the price of the given token pair in the given pool
 pub fn get_price(a_to_b: bool, pool: &AccountInfo, clock: &Clock) -> ProgramResult<DatedPrice> {
@@ -29,16 +37,79 @@ pub fn get_price(a_to_b: bool, pool: &AccountInfo, clock: &Clock) -> ProgramResu
     })
 }
 
-/// Stores price captures
+/**
+ * Time weighted price accumulation
+ *
+ * Each observation's price is counted from its timestamp until the next observation is recorded,
+ * so `cumulative` is the sum of price times seconds. The TWAP of a window is the growth of
+ * `cumulative` over the window divided by its length. A price pushed in a single transaction only
+ * counts for the time until the next observation, and the observation closing a window doesn't
+ * count towards that window at all.
+ */
 #[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
 pub struct PriceStorage {
+    /// The latest observations, oldest first (200)
     pub observations: [DatedPrice; 5],
+    /// The sum of every observed price scaled by `PEG_DECIMALS` times the seconds it was the latest (16)
+    pub cumulative: u128,
+    /// `cumulative` at the start of the current TWAP window (16)
+    pub window_cumulative: u128,
+    /// The timestamp of the observation starting the current TWAP window, zero before the first (8)
+    pub window_start: u64,
+}
+
+impl PriceStorage {
+    pub fn latest(&self) -> &DatedPrice {
+        &self.observations[self.observations.len() - 1]
+    }
+
+    /// The time weighted average price since the window started scaled by `PEG_DECIMALS`
+    pub fn twap(&self) -> Result<u64> {
+        let window_end = self.latest().unix_timestamp;
+
+        require!(self.window_start != 0 && window_end > self.window_start, ErrorCode::EmptyTwapWindow);
+
+        let twap = (self.cumulative - self.window_cumulative) / (window_end - self.window_start) as u128;
+
+        Ok(u64::try_from(twap).unwrap_or(u64::MAX))
+    }
+
+    /// Whether an observation at `now` is at least `MIN_OBSERVATION_SPACING` after the latest one
+    pub fn spaced(&self, now: u64) -> bool {
+        let latest = self.latest().unix_timestamp;
+
+        latest == 0 || now >= latest.saturating_add(MIN_OBSERVATION_SPACING)
+    }
+
+    /// Records `update`, accumulating the previous observation's price until `update`
+    pub fn record(&mut self, update: DatedPrice) {
+        let latest = *self.latest();
+
+        if latest.unix_timestamp == 0 {
+            self.window_start = update.unix_timestamp;
+        } else {
+            let elapsed = update.unix_timestamp.saturating_sub(latest.unix_timestamp);
+            self.cumulative += latest.price.to_scaled_value(PEG_DECIMALS) * elapsed as u128;
+        }
+
+        self.observations.rotate_left(1);
+        self.observations[self.observations.len() - 1] = update;
+    }
+
+    /// Starts the next TWAP window from the latest observation
+    pub fn new_window(&mut self) {
+        self.window_cumulative = self.cumulative;
+        self.window_start = self.latest().unix_timestamp;
+    }
 }
 
 impl Default for PriceStorage {
     fn default() -> Self {
         Self {
-            observations: [DatedPrice::default(); 5]
+            observations: [DatedPrice::default(); 5],
+            cumulative: 0,
+            window_cumulative: 0,
+            window_start: 0,
         }
     }
 }
@@ -59,10 +130,77 @@ pub fn calculate_twap(observations: [DatedPrice; 5], exp: u64) -> Twap {
     }
 }
 
-pub fn update_observations(clock: &Clock, a_to_b: bool, raydium_ammv3_pool: &AccountInfo, observations: [DatedPrice; 5]) -> Result<Vec<DatedPrice>> {
+/// Records the pool's current price in `storage`
+pub fn update_observations(clock: &Clock, a_to_b: bool, raydium_ammv3_pool: &AccountInfo, storage: &mut PriceStorage) -> Result<()> {
     let update = get_price(a_to_b, raydium_ammv3_pool, clock)?;
-    let mut observations = Vec::from(observations);
-    observations.push(update);
+    storage.record(update);
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::price::Price;
+    use test_case::test_case;
 
-    Ok(observations)
+    fn observation(value: u64, unix_timestamp: u64) -> DatedPrice {
+        DatedPrice {
+            price: Price { value, exp: PEG_DECIMALS as u64 },
+            unix_timestamp,
+            ..Default::default()
+        }
+    }
+
+    fn storage(observations: &[(u64, u64)]) -> PriceStorage {
+        let mut storage = PriceStorage::default();
+
+        for (value, timestamp) in observations {
+            storage.record(observation(*value, *timestamp));
+        }
+
+        storage
+    }
+
+    #[test_case(&[(1_000_000, 100), (1_000_000, 200)], 1_000_000; "constant price")]
+    #[test_case(&[(900_000, 100), (1_200_000, 400), (1_000_000, 500)], 975_000; "weighted by duration")]
+    #[test_case(&[(900_000, 100), (5_000_000, 499), (1_000_000, 500)], 910_250; "short spike")]
+    fn test_twap(observations: &[(u64, u64)], expected: u64) {
+        assert_eq!(storage(observations).twap().unwrap(), expected);
+    }
+
+    #[test]
+    fn test_closing_observation_is_not_counted() {
+        assert_eq!(storage(&[(1_000_000, 100), (100, 200)]).twap().unwrap(), 1_000_000);
+    }
+
+    #[test]
+    fn test_new_window_only_counts_later_observations() {
+        let mut storage = storage(&[(900_000, 100), (1_100_000, 200)]);
+        storage.new_window();
+        storage.record(observation(1_000_000, 300));
+
+        assert_eq!(storage.twap().unwrap(), 1_100_000);
+    }
+
+    #[test_case(&[]; "no observations")]
+    #[test_case(&[(1_000_000, 100)]; "single observation")]
+    #[test_case(&[(1_000_000, 100), (1_000_000, 100)]; "no elapsed time")]
+    fn test_empty_window_is_rejected(observations: &[(u64, u64)]) {
+        assert!(storage(observations).twap().is_err());
+    }
+
+    #[test_case(&[], 100, true; "no observations")]
+    #[test_case(&[(1_000_000, 100)], 100 + MIN_OBSERVATION_SPACING - 1, false; "too soon")]
+    #[test_case(&[(1_000_000, 100)], 100 + MIN_OBSERVATION_SPACING, true; "spaced")]
+    fn test_observation_spacing(observations: &[(u64, u64)], now: u64, expected: bool) {
+        assert_eq!(storage(observations).spaced(now), expected);
+    }
+
+    #[test]
+    fn test_keeps_latest_observations() {
+        let storage = storage(&[(1, 1), (2, 2), (3, 3), (4, 4), (5, 5), (6, 6)]);
+
+        assert_eq!(storage.observations.map(|x| x.unix_timestamp), [2, 3, 4, 5, 6]);
+    }
 }
\ No newline at end of file
@@ -0,0 +1,114 @@
+use anchor_lang::prelude::{*, borsh::{BorshSerialize, BorshDeserialize}};
+use crate::errors::ErrorCode;
+
+/// The protocol operations that can be paused individually
+#[derive(Debug, Clone, Copy, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub enum Subsystem {
+    BondPurchase,
+    BondRedemption,
+    BoardroomDeposit,
+    BoardroomWithdraw,
+    /// Staking NFTs and depositing into farms
+    Staking,
+    /// Claiming boardroom, staking, farm and vesting rewards
+    Claims,
+    EpochAdvance,
+}
+
+impl Subsystem {
+    pub fn mask(&self) -> u16 {
+        1 << (*self as u16)
+    }
+
+    /// Whether the operation only returns user principal, these can't be paused during an emergency shutdown
+    pub fn withdraws_principal(&self) -> bool {
+        matches!(self, Self::BoardroomWithdraw)
+    }
+}
+
+/// The paused subsystems and the emergency shutdown
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct PauseState {
+    /// The bitmask of paused subsystems (2)
+    pub paused: u16,
+    /// Whether the protocol is shut down, freezing minting and everything but withdrawals of principal (1)
+    pub shutdown: bool,
+}
+
+impl PauseState {
+    /// Whether `subsystem` is paused, during a shutdown everything but withdrawals of principal is
+    pub fn is_paused(&self, subsystem: Subsystem) -> bool {
+        if self.shutdown {
+            return !subsystem.withdraws_principal();
+        }
+
+        self.paused & subsystem.mask() != 0
+    }
+
+    pub fn set_paused(&mut self, subsystem: Subsystem, paused: bool) {
+        if paused {
+            self.paused |= subsystem.mask();
+        } else {
+            self.paused &= !subsystem.mask();
+        }
+    }
+
+    pub fn require_active(&self, subsystem: Subsystem) -> Result<()> {
+        require!(!self.shutdown || subsystem.withdraws_principal(), ErrorCode::EmergencyShutdown);
+        require!(!self.is_paused(subsystem), ErrorCode::SubsystemPaused);
+
+        Ok(())
+    }
+
+    /// Fails during an emergency shutdown, for operations outside of the pausable subsystems that move
+    /// protocol funds, mint or change how the protocol is governed. Withdrawals of principal are never gated
+    pub fn require_not_shutdown(&self) -> Result<()> {
+        require!(!self.shutdown, ErrorCode::EmergencyShutdown);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pause_subsystem() {
+        let mut pause = PauseState::default();
+        assert!(pause.require_active(Subsystem::BondPurchase).is_ok());
+
+        pause.set_paused(Subsystem::BondPurchase, true);
+        pause.set_paused(Subsystem::Claims, true);
+        assert!(pause.is_paused(Subsystem::BondPurchase));
+        assert!(pause.is_paused(Subsystem::Claims));
+        assert!(!pause.is_paused(Subsystem::BondRedemption));
+        assert!(pause.require_active(Subsystem::BondPurchase).is_err());
+
+        pause.set_paused(Subsystem::BondPurchase, false);
+        assert!(!pause.is_paused(Subsystem::BondPurchase));
+        assert!(pause.is_paused(Subsystem::Claims));
+    }
+
+    #[test]
+    fn test_shutdown_only_allows_withdrawals() {
+        let pause = PauseState { paused: 0, shutdown: true };
+
+        assert!(pause.require_active(Subsystem::BoardroomWithdraw).is_ok());
+        assert!(pause.require_active(Subsystem::BoardroomDeposit).is_err());
+        assert!(pause.require_active(Subsystem::EpochAdvance).is_err());
+        assert!(pause.require_not_shutdown().is_err());
+    }
+
+    #[test]
+    fn test_paused_withdrawals_resume_in_shutdown() {
+        let mut pause = PauseState { paused: 0, shutdown: true };
+        pause.set_paused(Subsystem::BoardroomWithdraw, true);
+
+        assert!(!pause.is_paused(Subsystem::BoardroomWithdraw));
+        assert!(pause.require_active(Subsystem::BoardroomWithdraw).is_ok());
+
+        pause.shutdown = false;
+        assert!(pause.require_active(Subsystem::BoardroomWithdraw).is_err());
+    }
+}
\ No newline at end of file
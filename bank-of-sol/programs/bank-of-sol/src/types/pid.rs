@@ -0,0 +1,202 @@
+use anchor_lang::prelude::{*, borsh::{BorshSerialize, BorshDeserialize}};
+use crate::types::interest_rate::ONE_HUNDRED_PCT;
+use crate::types::oracle::PEG;
+
+/// Gains are scaled by this factor, i.e. a gain of `PID_GAIN_PRECISION` is 1.0
+pub const PID_GAIN_PRECISION: i128 = 1_000_000;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct PidGains {
+    /// Proportional gain (8)
+    pub kp: i64,
+    /// Integral gain (8)
+    pub ki: i64,
+    /// Derivative gain (8)
+    pub kd: i64,
+}
+
+/// The policy levers set by the controller for the next epoch, as percentages scaled by `INTEREST_RATE_PRECISION`
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct PidOutput {
+    pub expansion_rate: u64,
+    pub bond_discount: u64,
+}
+
+/**
+ * PID monetary policy
+ *
+ * The error is the distance of the TWAP from `target` as a signed percentage of `target`,
+ * positive when below target. Each epoch the controller's output is split into:
+ *  - a bond discount when positive, to contract supply
+ *  - an expansion rate when negative, to expand supply
+ *
+ * The accumulated integral is clamped to `integral_limit` so that a long excursion from
+ * peg can't wind the controller up past what it can unwind in a reasonable number of epochs.
+ *
+ * The controller only depends on anchor for its Borsh serialization and doesn't read any
+ * accounts or sysvars, so the same controller can be run off-chain to preview its outputs.
+ */
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct PidController {
+    /// The controller gains (24)
+    pub gains: PidGains,
+    /// The target TWAP, scaled by `PEG_DECIMALS` (8)
+    pub target: u64,
+    /// The maximum magnitude of the accumulated integral (8)
+    pub integral_limit: i64,
+    /// The maximum expansion rate (8)
+    pub max_expansion_rate: u64,
+    /// The maximum bond discount (8)
+    pub max_bond_discount: u64,
+    /// The accumulated error (8)
+    pub integral: i64,
+    /// The error of the previous update (8)
+    pub last_error: i64,
+}
+
+impl PidController {
+    pub fn init(gains: PidGains, integral_limit: i64, max_expansion_rate: u64, max_bond_discount: u64) -> Self {
+        Self {
+            gains,
+            target: PEG,
+            integral_limit: integral_limit.saturating_abs(),
+            max_expansion_rate,
+            max_bond_discount,
+            integral: 0,
+            last_error: 0,
+        }
+    }
+
+    pub fn error(&self, twap: u64) -> i64 {
+        if self.target == 0 {
+            return 0;
+        }
+
+        let error = (self.target as i128 - twap as i128) * ONE_HUNDRED_PCT as i128 / self.target as i128;
+
+        saturate(error)
+    }
+
+    /// The output `update` would give for `twap`, without changing the controller state
+    pub fn preview(&self, twap: u64) -> PidOutput {
+        let mut next = *self;
+        next.update(twap)
+    }
+
+    /// Feeds the TWAP of the epoch into the controller, returning the policy for the next epoch
+    pub fn update(&mut self, twap: u64) -> PidOutput {
+        let error = self.error(twap);
+
+        self.integral = self.integral
+            .saturating_add(error)
+            .clamp(-self.integral_limit, self.integral_limit);
+
+        let derivative = error as i128 - self.last_error as i128;
+        self.last_error = error;
+
+        let output = (self.gains.kp as i128 * error as i128
+            + self.gains.ki as i128 * self.integral as i128
+            + self.gains.kd as i128 * derivative)
+            / PID_GAIN_PRECISION;
+
+        if output >= 0 {
+            PidOutput {
+                expansion_rate: 0,
+                bond_discount: (output.min(self.max_bond_discount as i128)) as u64,
+            }
+        } else {
+            PidOutput {
+                expansion_rate: (output.unsigned_abs().min(self.max_expansion_rate as u128)) as u64,
+                bond_discount: 0,
+            }
+        }
+    }
+}
+
+fn saturate(value: i128) -> i64 {
+    value.clamp(i64::MIN as i128, i64::MAX as i128) as i64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::interest_rate::INTEREST_RATE_PRECISION;
+    use proptest::prelude::*;
+    use test_case::test_case;
+
+    const PCT: u64 = INTEREST_RATE_PRECISION;
+    const ONE: i64 = PID_GAIN_PRECISION as i64;
+
+    fn controller(kp: i64, ki: i64, kd: i64) -> PidController {
+        PidController::init(PidGains { kp, ki, kd }, 20 * PCT as i64, 10 * PCT, 30 * PCT)
+    }
+
+    #[test_case(PEG, 0; "at peg")]
+    #[test_case(900_000, 10 * PCT as i64; "below peg")]
+    #[test_case(1_050_000, -5 * PCT as i64; "above peg")]
+    fn test_error(twap: u64, expected: i64) {
+        assert_eq!(controller(ONE, 0, 0).error(twap), expected);
+    }
+
+    #[test]
+    fn test_proportional_below_peg_sets_bond_discount() {
+        let mut pid = controller(ONE, 0, 0);
+
+        assert_eq!(pid.update(900_000), PidOutput { expansion_rate: 0, bond_discount: 10 * PCT });
+    }
+
+    #[test]
+    fn test_proportional_above_peg_sets_expansion_rate() {
+        let mut pid = controller(ONE / 2, 0, 0);
+
+        assert_eq!(pid.update(1_100_000), PidOutput { expansion_rate: 5 * PCT, bond_discount: 0 });
+    }
+
+    #[test]
+    fn test_outputs_are_capped() {
+        let mut pid = controller(10 * ONE, 0, 0);
+
+        assert_eq!(pid.update(500_000).bond_discount, 30 * PCT);
+        assert_eq!(pid.update(2_000_000).expansion_rate, 10 * PCT);
+    }
+
+    #[test]
+    fn test_derivative_opposes_change() {
+        let mut pid = controller(0, 0, ONE);
+
+        pid.update(PEG);
+        assert_eq!(pid.update(950_000).bond_discount, 5 * PCT);
+        assert_eq!(pid.update(950_000), PidOutput::default());
+    }
+
+    #[test]
+    fn test_integral_unwinds_after_long_excursion() {
+        let mut pid = controller(0, ONE, 0);
+
+        for _ in 0..100 {
+            pid.update(500_000);
+        }
+        assert_eq!(pid.integral, 20 * PCT as i64);
+
+        // Without windup protection this would take 100 epochs above peg to unwind
+        for _ in 0..4 {
+            pid.update(1_050_000);
+        }
+        assert_eq!(pid.integral, 0);
+    }
+
+    proptest! {
+        #[test]
+        fn test_integral_is_bounded(twaps in prop::collection::vec(0u64..=10 * PEG, 1..50)) {
+            let mut pid = controller(ONE, ONE, ONE);
+
+            for twap in twaps {
+                let output = pid.update(twap);
+                prop_assert!(pid.integral.abs() <= pid.integral_limit);
+                prop_assert!(output.bond_discount <= pid.max_bond_discount);
+                prop_assert!(output.expansion_rate <= pid.max_expansion_rate);
+                prop_assert!(output.bond_discount == 0 || output.expansion_rate == 0);
+            }
+        }
+    }
+}
\ No newline at end of file
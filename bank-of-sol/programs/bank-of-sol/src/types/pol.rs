@@ -0,0 +1,132 @@
+use anchor_lang::prelude::{*, borsh::{BorshSerialize, BorshDeserialize}};
+use muldiv::*;
+use raydium_amm_v3::states::PoolState;
+use crate::types::interest_rate::ONE_HUNDRED_PCT;
+use crate::utils::account_deserialize;
+use crate::errors::ErrorCode;
+
+/**
+ * Protocol-owned liquidity
+ *
+ * The protocol holds a single Raydium CLMM position in the Unit/USDC pool, opened by `open_pol_position`
+ * which mints the position NFT into token authority's account recorded as `unit_usdc_lp_custody`.
+ * A `seigniorage_share` of the seigniorage left after funding bond redemptions is minted into
+ * `unit_custody` and earmarked for the position as `available_units`. `unit_custody` also
+ * holds boardroom deposits, so Units are only ever added to the position out of `available_units`.
+ * USDC is added from and removed into the protocol's own USDC custody account.
+ */
+#[derive(Debug, Default, Clone, Copy, BorshSerialize, BorshDeserialize)]
+pub struct PolStorage {
+    /// The share of seigniorage routed into POL, scaled by `INTEREST_RATE_PRECISION` (8)
+    pub seigniorage_share: u64,
+    /// Units in `unit_custody` that can be added to the position (8)
+    pub available_units: u64,
+    /// The liquidity of the protocol's position (16)
+    pub liquidity: u128,
+    /// The total number of Units ever added to the position (8)
+    pub units_added: u64,
+    /// The total amount of USDC ever added to the position (8)
+    pub usdc_added: u64,
+    /// The total number of Units ever removed from the position, including fees (8)
+    pub units_removed: u64,
+    /// The total amount of USDC ever removed from the position, including fees (8)
+    pub usdc_removed: u64,
+}
+
+impl PolStorage {
+    pub const LEN: usize = 8 + 8 + 16 + 8 + 8 + 8 + 8;
+
+    pub fn set_seigniorage_share(&mut self, seigniorage_share: u64) -> Result<()> {
+        require_gte!(ONE_HUNDRED_PCT, seigniorage_share, ErrorCode::InvalidPolShare);
+
+        self.seigniorage_share = seigniorage_share;
+
+        Ok(())
+    }
+
+    /// Earmarks POL's share of `seigniorage`, returns the Units to mint into custody for it
+    pub fn allocate_seigniorage(&mut self, seigniorage: u64) -> u64 {
+        let units = seigniorage.mul_div_floor(self.seigniorage_share, ONE_HUNDRED_PCT).unwrap();
+        self.available_units += units;
+
+        units
+    }
+
+    /// Records `liquidity` added to the position for `units` and `usdc`
+    pub fn liquidity_added(&mut self, liquidity: u128, units: u64, usdc: u64) -> Result<()> {
+        require_gte!(self.available_units, units, ErrorCode::InsufficientPolUnits);
+
+        self.available_units -= units;
+        self.liquidity += liquidity;
+        self.units_added += units;
+        self.usdc_added += usdc;
+
+        Ok(())
+    }
+
+    /// Records `liquidity` removed from the position, the Units received can be added again
+    pub fn liquidity_removed(&mut self, liquidity: u128, units: u64, usdc: u64) -> Result<()> {
+        require_gte!(self.liquidity, liquidity, ErrorCode::InsufficientPolLiquidity);
+
+        self.available_units += units;
+        self.liquidity -= liquidity;
+        self.units_removed += units;
+        self.usdc_removed += usdc;
+
+        Ok(())
+    }
+}
+
+/// Whether Units are token 0 of the Unit/USDC `pool`, fails if the pool does not pair `unit_mint` with `usdc_mint`
+pub fn unit_is_token_0(pool: &AccountInfo, unit_mint: Pubkey, usdc_mint: Pubkey) -> Result<bool> {
+    let pool_state: PoolState = account_deserialize(pool)?;
+
+    match (pool_state.token_mint_0, pool_state.token_mint_1) {
+        (mint_0, mint_1) if mint_0 == unit_mint && mint_1 == usdc_mint => Ok(true),
+        (mint_0, mint_1) if mint_0 == usdc_mint && mint_1 == unit_mint => Ok(false),
+        _ => err!(ErrorCode::InvalidRaydiumPool),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+    use crate::types::interest_rate::INTEREST_RATE_PRECISION;
+
+    const PCT: u64 = INTEREST_RATE_PRECISION;
+
+    #[test_case(0, 1_000, 0; "no share")]
+    #[test_case(25 * PCT, 1_000, 250; "quarter")]
+    #[test_case(100 * PCT, 1_000, 1_000; "all")]
+    #[test_case(33 * PCT, 10, 3; "rounds down")]
+    fn test_allocate_seigniorage(share: u64, seigniorage: u64, expected: u64) {
+        let mut pol = PolStorage::default();
+        pol.set_seigniorage_share(share).unwrap();
+
+        assert_eq!(pol.allocate_seigniorage(seigniorage), expected);
+        assert_eq!(pol.available_units, expected);
+    }
+
+    #[test]
+    fn test_invalid_share() {
+        assert!(PolStorage::default().set_seigniorage_share(100 * PCT + 1).is_err());
+    }
+
+    #[test]
+    fn test_liquidity_round_trip() {
+        let mut pol = PolStorage { seigniorage_share: 50 * PCT, ..Default::default() };
+        pol.allocate_seigniorage(1_000);
+
+        assert!(pol.liquidity_added(100, 501, 500).is_err());
+
+        pol.liquidity_added(100, 400, 380).unwrap();
+        assert_eq!((pol.available_units, pol.liquidity), (100, 100));
+
+        assert!(pol.liquidity_removed(101, 0, 0).is_err());
+
+        pol.liquidity_removed(100, 410, 390).unwrap();
+        assert_eq!((pol.available_units, pol.liquidity), (510, 0));
+        assert_eq!((pol.units_removed, pol.usdc_removed), (410, 390));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,84 @@
+use anchor_lang::prelude::{*, borsh::{BorshSerialize, BorshDeserialize}};
+
+/// The roles privileged instructions are restricted to, granted by the Singleton's authority
+#[derive(Debug, Clone, Copy, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub enum Role {
+    /// Configures protocol parameters, farms, gauges and POL
+    Admin,
+    /// Pauses subsystems and triggers the emergency shutdown
+    Pauser,
+    /// Records price observations between epoch advances
+    OracleKeeper,
+    /// Spends treasury funds and manages vesting schedules paid from them
+    TreasurySpender,
+    /// Configures NFT staking and the boardroom boost
+    StakingManager,
+}
+
+impl Role {
+    pub fn mask(&self) -> u8 {
+        1 << (*self as u8)
+    }
+}
+
+/// A set of roles stored as a bitmask
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct Roles {
+    /// The bitmask of granted roles (1)
+    pub bits: u8,
+}
+
+impl Roles {
+    pub fn has(&self, role: Role) -> bool {
+        self.bits & role.mask() != 0
+    }
+
+    pub fn grant(&mut self, role: Role) {
+        self.bits |= role.mask();
+    }
+
+    pub fn revoke(&mut self, role: Role) {
+        self.bits &= !role.mask();
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bits == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ROLES: [Role; 5] = [Role::Admin, Role::Pauser, Role::OracleKeeper, Role::TreasurySpender, Role::StakingManager];
+
+    #[test]
+    fn test_masks_are_distinct() {
+        let bits = ROLES.iter().fold(0u8, |bits, role| {
+            assert_eq!(bits & role.mask(), 0);
+            bits | role.mask()
+        });
+
+        assert_eq!(bits, 0b11111);
+    }
+
+    #[test]
+    fn test_grant_and_revoke() {
+        let mut roles = Roles::default();
+        assert!(roles.is_empty());
+
+        roles.grant(Role::Pauser);
+        roles.grant(Role::TreasurySpender);
+        assert!(roles.has(Role::Pauser));
+        assert!(roles.has(Role::TreasurySpender));
+        assert!(!roles.has(Role::Admin));
+
+        roles.revoke(Role::Pauser);
+        assert!(!roles.has(Role::Pauser));
+        assert!(roles.has(Role::TreasurySpender));
+
+        roles.revoke(Role::Pauser);
+        roles.revoke(Role::TreasurySpender);
+        assert!(roles.is_empty());
+    }
+}
\ No newline at end of file
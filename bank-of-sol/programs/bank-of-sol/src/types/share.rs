@@ -0,0 +1,151 @@
+use anchor_lang::prelude::{*, borsh::{BorshSerialize, BorshDeserialize}};
+use crate::errors::ErrorCode;
+
+/// Emits shares linearly at `rate` per second between `start` and `end`
+#[derive(Debug, Default, Clone, Copy, BorshSerialize, BorshDeserialize)]
+pub struct ShareEmission {
+    /// The timestamp emission starts at (8)
+    pub start: i64,
+    /// The timestamp emission ends at (8)
+    pub end: i64,
+    /// The shares emitted per second (8)
+    pub rate: u64,
+    /// The timestamp shares were last emitted up to (8)
+    pub last_emitted: i64,
+}
+
+impl ShareEmission {
+    pub fn init(start: i64, end: i64, rate: u64, now: i64) -> Result<Self> {
+        require_gte!(end, start, ErrorCode::InvalidShareEmission);
+
+        Ok(Self { start, end, rate, last_emitted: now })
+    }
+
+    /// The shares emitted between `last_emitted` and `now`
+    pub fn pending(&self, now: i64) -> u64 {
+        let from = self.last_emitted.max(self.start);
+        let to = now.min(self.end);
+
+        if to <= from {
+            0
+        } else {
+            self.rate.saturating_mul((to - from) as u64)
+        }
+    }
+}
+
+/// Stores share token data
+#[derive(Debug, Default, Clone, Copy, BorshSerialize, BorshDeserialize)]
+pub struct ShareStorage {
+    /// The share token mint (32)
+    pub mint: Pubkey,
+    /// The maximum supply of shares, fixed at initialization (8)
+    pub max_supply: u64,
+    /// The total number of shares ever minted (8)
+    pub minted: u64,
+    /// The emission of shares to reward pools (32)
+    pub emission: ShareEmission,
+    /// The token account emitted shares are minted into, reward pools pay out of it (32)
+    pub reward_vault: Pubkey,
+}
+
+impl ShareStorage {
+    pub const LEN: usize = 32 + 8 + 8 + 32 + 32;
+
+    pub fn init(mint: Pubkey, max_supply: u64, reward_vault: Pubkey) -> Self {
+        Self { mint, max_supply, reward_vault, ..Default::default() }
+    }
+
+    /// Emits the shares pending at `now`, capped by the max supply, returns the amount to mint
+    pub fn emit(&mut self, now: i64) -> u64 {
+        let amount = self.emission.pending(now).min(self.max_supply - self.minted);
+
+        self.emission.last_emitted = self.emission.last_emitted.max(now);
+        self.minted += amount;
+
+        amount
+    }
+
+    /// Mints `amount` shares outside of emission, such as for vesting, within the max supply
+    pub fn allocate(&mut self, amount: u64) -> Result<()> {
+        require_gte!(self.max_supply - self.minted, amount, ErrorCode::ShareMaxSupplyExceeded);
+
+        self.minted += amount;
+
+        Ok(())
+    }
+
+    /// Returns `amount` burned shares to the supply left to mint
+    pub fn burned(&mut self, amount: u64) {
+        self.minted -= amount;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    fn shares() -> ShareStorage {
+        ShareStorage {
+            max_supply: 1_000,
+            emission: ShareEmission::init(100, 200, 5, 0).unwrap(),
+            ..Default::default()
+        }
+    }
+
+    #[test_case(50, 0; "before start")]
+    #[test_case(150, 250; "halfway")]
+    #[test_case(200, 500; "at end")]
+    #[test_case(500, 500; "after end")]
+    fn test_emit(now: i64, expected: u64) {
+        let mut shares = shares();
+
+        assert_eq!(shares.emit(now), expected);
+        assert_eq!(shares.emit(now), 0);
+        assert_eq!(shares.minted, expected);
+    }
+
+    #[test]
+    fn test_emit_in_steps() {
+        let mut shares = shares();
+
+        assert_eq!(shares.emit(120) + shares.emit(170) + shares.emit(300), 500);
+    }
+
+    #[test]
+    fn test_max_supply() {
+        let mut shares = shares();
+        shares.minted = 900;
+
+        assert_eq!(shares.emit(200), 100);
+        assert_eq!(shares.emit(300), 0);
+    }
+
+    #[test]
+    fn test_invalid_emission() {
+        assert!(ShareEmission::init(200, 100, 5, 0).is_err());
+    }
+
+    #[test_case(900, 100, true; "up to max supply")]
+    #[test_case(900, 101, false; "above max supply")]
+    fn test_allocate(minted: u64, amount: u64, allowed: bool) {
+        let mut shares = shares();
+        shares.minted = minted;
+
+        assert_eq!(shares.allocate(amount).is_ok(), allowed);
+        assert_eq!(shares.minted, if allowed { minted + amount } else { minted });
+    }
+
+    #[test]
+    fn test_burned_shares_can_be_emitted_again() {
+        let mut shares = shares();
+        shares.allocate(1_000).unwrap();
+
+        assert_eq!(shares.emit(300), 0);
+
+        shares.burned(200);
+        shares.emission.last_emitted = 0;
+        assert_eq!(shares.emit(300), 200);
+    }
+}
\ No newline at end of file
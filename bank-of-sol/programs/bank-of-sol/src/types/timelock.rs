@@ -0,0 +1,126 @@
+use anchor_lang::prelude::{*, borsh::{BorshSerialize, BorshDeserialize}};
+use crate::types::epoch::HOUR;
+use crate::types::governance::{ConfigChange, GovernanceParams};
+use crate::types::boardroom::BoardroomDepositToken;
+use crate::types::treasury::{FundKind, SeigniorageSplit};
+use crate::errors::ErrorCode;
+
+/// The shortest delay the timelock can be set to, so that queued actions can always be reviewed
+pub const MIN_DELAY: i64 = HOUR;
+/// The longest delay the timelock can be set to, so that it can't lock out changes indefinitely
+pub const MAX_DELAY: i64 = 30 * 24 * HOUR;
+/// The time after its ETA an action can be executed in, after which it can only be cancelled
+pub const GRACE_PERIOD: i64 = 14 * 24 * HOUR;
+
+pub fn validate_delay(delay: i64) -> Result<()> {
+    require!((MIN_DELAY..=MAX_DELAY).contains(&delay), ErrorCode::InvalidTimelockDelay);
+
+    Ok(())
+}
+
+/// An admin action queued in the timelock, every parameter of the protocol is changed through one
+#[derive(Debug, Clone, Copy, BorshSerialize, BorshDeserialize)]
+pub enum TimelockAction {
+    /// Changes the Singleton's parameters
+    Config { change: ConfigChange },
+    /// Changes the timelock's delay
+    SetDelay { delay: i64 },
+    /// Switches the boardroom between Unit and share deposits, only while nothing is deposited
+    BoardroomDepositToken { deposit_token: BoardroomDepositToken },
+    /// Switches new bond purchases between bond tokens and bond accounts
+    BondTokenMode { enabled: bool },
+    /// Sets the voting rules of new proposals
+    Governance { quorum: u64, threshold: u64, voting_period: i64 },
+    /// Replaces the share emission schedule
+    ShareEmission { start: i64, end: i64, rate: u64 },
+    /// Sets the maximum amount that can be spent from a treasury fund in one epoch
+    TreasurySpendLimit { fund: FundKind, epoch_limit: u64 },
+    /// Sets the split of seigniorage between the treasury funds and the boardroom
+    TreasurySplit { dao_fund: u64, dev_fund: u64, boardroom: u64 },
+    /// Sets the Units paid per second by a farm
+    FarmRate { farm: Pubkey, rate: u64 },
+}
+
+impl TimelockAction {
+    pub const LEN: usize = 1 + ConfigChange::LEN;
+
+    /// Rejects actions that could never be applied, the rest is checked against the state they apply to
+    pub fn validate(&self) -> Result<()> {
+        match *self {
+            Self::SetDelay { delay } => validate_delay(delay),
+            Self::Governance { quorum, threshold, voting_period } => GovernanceParams::init(quorum, threshold, voting_period).map(|_| ()),
+            Self::ShareEmission { start, end, .. } => {
+                require_gte!(end, start, ErrorCode::InvalidShareEmission);
+
+                Ok(())
+            },
+            Self::TreasurySplit { dao_fund, dev_fund, boardroom } => SeigniorageSplit::init(dao_fund, dev_fund, boardroom).map(|_| ()),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::timelock::{Timelock, PendingAction};
+    use test_case::test_case;
+
+    #[test_case(MIN_DELAY, true; "min")]
+    #[test_case(MAX_DELAY, true; "max")]
+    #[test_case(MIN_DELAY - 1, false; "too short")]
+    #[test_case(MAX_DELAY + 1, false; "too long")]
+    #[test_case(-HOUR, false; "negative")]
+    fn test_validate_delay(delay: i64, expected: bool) {
+        assert_eq!(validate_delay(delay).is_ok(), expected);
+        assert_eq!(TimelockAction::SetDelay { delay }.validate().is_ok(), expected);
+    }
+
+    #[test]
+    fn test_validate_rejects_unappliable_actions() {
+        assert!(TimelockAction::ShareEmission { start: 10, end: 5, rate: 1 }.validate().is_err());
+        assert!(TimelockAction::TreasurySplit { dao_fund: 1, dev_fund: 1, boardroom: 1 }.validate().is_err());
+        assert!(TimelockAction::Governance { quorum: 0, threshold: 0, voting_period: 0 }.validate().is_err());
+        assert!(TimelockAction::BondTokenMode { enabled: true }.validate().is_ok());
+    }
+
+    #[test_case(99, false, true; "before eta")]
+    #[test_case(100, true, false; "at eta")]
+    #[test_case(100 + GRACE_PERIOD, true, false; "end of grace period")]
+    #[test_case(101 + GRACE_PERIOD, false, true; "expired")]
+    fn test_pending_action_window(now: i64, executable: bool, cancellable: bool) {
+        let timelock = timelock(1);
+        let action = pending_action(0);
+
+        assert_eq!(action.ready(&timelock, now).is_ok(), executable);
+        assert_eq!(action.cancellable(&timelock, now).is_ok(), cancellable);
+    }
+
+    #[test_case(0, false; "queued before")]
+    #[test_case(1, true; "queued after")]
+    fn test_invalidated_queue(index: u64, executable: bool) {
+        let mut timelock = timelock(1);
+        timelock.invalidate_queue();
+        timelock.action_count += 1;
+
+        let action = pending_action(index);
+
+        assert_eq!(action.ready(&timelock, 100).is_ok(), executable);
+        assert_eq!(action.closable(&timelock, 100), !executable);
+        assert_eq!(action.cancellable(&timelock, 100).is_ok(), !executable);
+    }
+
+    fn timelock(action_count: u64) -> Timelock {
+        Timelock { delay: MIN_DELAY, action_count, bump: 0, valid_from: 0 }
+    }
+
+    fn pending_action(index: u64) -> PendingAction {
+        PendingAction {
+            index,
+            action: TimelockAction::BondTokenMode { enabled: true },
+            eta: 100,
+            queued_by: Pubkey::default(),
+            bump: 0,
+        }
+    }
+}
\ No newline at end of file
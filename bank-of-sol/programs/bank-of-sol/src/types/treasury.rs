@@ -0,0 +1,128 @@
+use anchor_lang::prelude::{*, borsh::{BorshSerialize, BorshDeserialize}};
+use muldiv::*;
+use crate::types::interest_rate::ONE_HUNDRED_PCT;
+use crate::errors::ErrorCode;
+
+/// The treasury funds seigniorage is split into besides the boardroom
+#[derive(Debug, Clone, Copy, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub enum FundKind {
+    Dao,
+    Dev,
+}
+
+/// Splits seigniorage between the treasury funds and the boardroom, each share scaled by `INTEREST_RATE_PRECISION`
+#[derive(Debug, Clone, Copy, BorshSerialize, BorshDeserialize)]
+pub struct SeigniorageSplit {
+    /// The share minted into the DAO fund (8)
+    pub dao_fund: u64,
+    /// The share minted into the dev fund (8)
+    pub dev_fund: u64,
+    /// The share distributed to the boardroom (8)
+    pub boardroom: u64,
+}
+
+impl Default for SeigniorageSplit {
+    fn default() -> Self {
+        Self { dao_fund: 0, dev_fund: 0, boardroom: ONE_HUNDRED_PCT }
+    }
+}
+
+impl SeigniorageSplit {
+    pub fn init(dao_fund: u64, dev_fund: u64, boardroom: u64) -> Result<Self> {
+        require_eq!(dao_fund as u128 + dev_fund as u128 + boardroom as u128, ONE_HUNDRED_PCT as u128, ErrorCode::InvalidTreasurySplit);
+
+        Ok(Self { dao_fund, dev_fund, boardroom })
+    }
+
+    /// Splits `seigniorage` into the DAO fund, dev fund and boardroom amounts, rounding in favour of the boardroom
+    pub fn split(&self, seigniorage: u64) -> (u64, u64, u64) {
+        let dao_fund = seigniorage.mul_div_floor(self.dao_fund, ONE_HUNDRED_PCT).unwrap();
+        let dev_fund = seigniorage.mul_div_floor(self.dev_fund, ONE_HUNDRED_PCT).unwrap();
+
+        (dao_fund, dev_fund, seigniorage - dao_fund - dev_fund)
+    }
+}
+
+/// A treasury fund's vault and spending limit
+#[derive(Debug, Default, Clone, Copy, BorshSerialize, BorshDeserialize)]
+pub struct TreasuryFund {
+    /// The token account holding the fund's Units (32)
+    pub vault: Pubkey,
+    /// The maximum amount that can be spent in one epoch (8)
+    pub epoch_limit: u64,
+    /// The amount spent in `spent_epoch` (8)
+    pub spent: u64,
+    /// The epoch of the last spend (8)
+    pub spent_epoch: u64,
+    /// The total amount ever spent (8)
+    pub total_spent: u64,
+}
+
+impl TreasuryFund {
+    pub fn init(vault: Pubkey) -> Self {
+        Self { vault, ..Default::default() }
+    }
+
+    /// The amount that can still be spent in `epoch`
+    pub fn remaining(&self, epoch: u64) -> u64 {
+        if self.spent_epoch == epoch {
+            self.epoch_limit.saturating_sub(self.spent)
+        } else {
+            self.epoch_limit
+        }
+    }
+
+    /// Records `amount` spent in `epoch`, failing if it exceeds the epoch's limit
+    pub fn spend(&mut self, amount: u64, epoch: u64) -> Result<()> {
+        require_gte!(self.remaining(epoch), amount, ErrorCode::TreasurySpendLimitExceeded);
+
+        if self.spent_epoch != epoch {
+            self.spent_epoch = epoch;
+            self.spent = 0;
+        }
+
+        self.spent += amount;
+        self.total_spent += amount;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+    use crate::types::interest_rate::INTEREST_RATE_PRECISION;
+
+    const PCT: u64 = INTEREST_RATE_PRECISION;
+
+    #[test_case(0, 0, 1_000, (0, 0, 1_000); "all to boardroom")]
+    #[test_case(10 * PCT, 5 * PCT, 1_000, (100, 50, 850); "funds")]
+    #[test_case(33 * PCT, 33 * PCT, 10, (3, 3, 4); "rounds in favour of boardroom")]
+    fn test_split(dao_fund: u64, dev_fund: u64, seigniorage: u64, expected: (u64, u64, u64)) {
+        let split = SeigniorageSplit::init(dao_fund, dev_fund, 100 * PCT - dao_fund - dev_fund).unwrap();
+
+        assert_eq!(split.split(seigniorage), expected);
+    }
+
+    #[test_case(10 * PCT, 10 * PCT, 10 * PCT; "under")]
+    #[test_case(50 * PCT, 50 * PCT, 10 * PCT; "over")]
+    #[test_case(u64::MAX, 1, 0; "overflow")]
+    fn test_invalid_split(dao_fund: u64, dev_fund: u64, boardroom: u64) {
+        assert!(SeigniorageSplit::init(dao_fund, dev_fund, boardroom).is_err());
+    }
+
+    #[test]
+    fn test_epoch_limit() {
+        let mut fund = TreasuryFund { epoch_limit: 100, ..Default::default() };
+
+        fund.spend(60, 1).unwrap();
+        assert!(fund.spend(41, 1).is_err());
+        fund.spend(40, 1).unwrap();
+        assert_eq!(fund.remaining(1), 0);
+
+        assert_eq!(fund.remaining(2), 100);
+        fund.spend(100, 2).unwrap();
+        assert_eq!(fund.total_spent, 200);
+    }
+}
\ No newline at end of file
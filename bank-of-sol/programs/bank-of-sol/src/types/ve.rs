@@ -0,0 +1,296 @@
+use anchor_lang::prelude::{*, borsh::{BorshSerialize, BorshDeserialize}};
+use crate::errors::ErrorCode;
+
+pub const WEEK: i64 = 7 * 24 * 60 * 60;
+
+/// The longest lock in weeks
+pub const MAX_LOCK_WEEKS: i64 = 208;
+
+pub const MAX_LOCK: i64 = MAX_LOCK_WEEKS * WEEK;
+
+/// Voting power slopes are scaled by `SLOPE_PRECISION`
+pub const SLOPE_PRECISION: u128 = 1_000_000_000_000;
+
+/// Enough slots for a slope change at the end of every week a lock can end in
+pub const SLOPE_CHANGE_SLOTS: usize = MAX_LOCK_WEEKS as usize + 1;
+
+/// The number of changes a lock remembers
+pub const LOCK_HISTORY_LEN: usize = 8;
+
+/// The end of a lock of `weeks` from `now`, rounded down to a whole week
+pub fn lock_end(now: i64, weeks: i64) -> Result<i64> {
+    require!(weeks >= 1 && weeks <= MAX_LOCK_WEEKS, ErrorCode::InvalidLockDuration);
+
+    let end = (now + weeks * WEEK) / WEEK * WEEK;
+    require_gt!(end, now, ErrorCode::InvalidLockDuration);
+
+    Ok(end)
+}
+
+/// Shares locked until `end`, worth `amount` votes when locked for `MAX_LOCK` and decaying linearly to zero at `end`
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct VePoint {
+    /// The shares locked (8)
+    pub amount: u64,
+    /// The timestamp the lock ends at (8)
+    pub end: i64,
+}
+
+impl VePoint {
+    /// The decay of voting power per second, scaled by `SLOPE_PRECISION`
+    pub fn slope(&self) -> u128 {
+        self.amount as u128 * SLOPE_PRECISION / MAX_LOCK as u128
+    }
+
+    /// The voting power at `t`, scaled by `SLOPE_PRECISION`
+    fn scaled_power_at(&self, t: i64) -> u128 {
+        self.slope() * self.end.saturating_sub(t).max(0) as u128
+    }
+
+    pub fn power_at(&self, t: i64) -> u64 {
+        (self.scaled_power_at(t) / SLOPE_PRECISION) as u64
+    }
+}
+
+/// A lock's point from the timestamp it was set at
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct LockPoint {
+    /// The lock from `from` (16)
+    pub point: VePoint,
+    /// The timestamp the point was set at (8)
+    pub from: i64,
+}
+
+/**
+ * Lock history
+ *
+ * A lock keeps its last `LOCK_HISTORY_LEN` points, so its power at a checkpoint is known however it
+ * changed afterwards. A point set at a checkpoint's timestamp counts as set after it, as the supply may
+ * have been recorded first. Once a lock has changed more than `LOCK_HISTORY_LEN` times since a
+ * checkpoint, its power at that checkpoint is no longer known.
+ */
+#[derive(Debug, Default, Clone, Copy, BorshSerialize, BorshDeserialize)]
+pub struct LockHistory {
+    /// The points, indexed by their number modulo `LOCK_HISTORY_LEN` (192)
+    pub points: [LockPoint; LOCK_HISTORY_LEN],
+    /// The number of points ever set (8)
+    pub count: u64,
+}
+
+impl LockHistory {
+    pub const LEN: usize = 24 * LOCK_HISTORY_LEN + 8;
+
+    /// Sets the lock to `point` from `now`, replacing a point set at the same time
+    pub fn push(&mut self, point: VePoint, now: i64) {
+        let latest = self.count.checked_sub(1).map(|n| n as usize % LOCK_HISTORY_LEN);
+
+        match latest {
+            Some(slot) if self.points[slot].from == now => self.points[slot].point = point,
+            _ => {
+                self.points[self.count as usize % LOCK_HISTORY_LEN] = LockPoint { point, from: now };
+                self.count += 1;
+            }
+        }
+    }
+
+    /// The lock as it was at `t`, empty if it didn't exist yet
+    pub fn point_at(&self, t: i64) -> Result<VePoint> {
+        let remembered = self.count.min(LOCK_HISTORY_LEN as u64);
+
+        for n in (self.count - remembered..self.count).rev() {
+            let point = &self.points[n as usize % LOCK_HISTORY_LEN];
+
+            if point.from < t {
+                return Ok(point.point);
+            }
+        }
+
+        require_gte!(LOCK_HISTORY_LEN as u64, self.count, ErrorCode::LockHistoryExhausted);
+
+        Ok(VePoint::default())
+    }
+}
+
+/**
+ * Checkpointed voting power supply
+ *
+ * The total voting power is kept as a `bias` at `last_checkpoint` that decays by `slope` per second.
+ * As locks only end at whole weeks, the slope of every lock ending in a week is removed together when
+ * the checkpoint passes that week's end. Those changes are kept in a ring of `SLOPE_CHANGE_SLOTS`
+ * weeks, which covers every week a lock can currently end in.
+ */
+#[derive(Debug, Clone, Copy, BorshSerialize, BorshDeserialize)]
+pub struct SupplyCurve {
+    /// The total voting power at `last_checkpoint`, scaled by `SLOPE_PRECISION` (16)
+    pub bias: u128,
+    /// The total decay per second (16)
+    pub slope: u128,
+    /// The timestamp of the last checkpoint (8)
+    pub last_checkpoint: i64,
+    /// The slope removed at the end of each week, indexed by week modulo `SLOPE_CHANGE_SLOTS` (3344)
+    pub slope_changes: [u128; SLOPE_CHANGE_SLOTS],
+}
+
+impl SupplyCurve {
+    pub const LEN: usize = 16 + 16 + 8 + 16 * SLOPE_CHANGE_SLOTS;
+
+    pub fn init(now: i64) -> Self {
+        Self { bias: 0, slope: 0, last_checkpoint: now, slope_changes: [0; SLOPE_CHANGE_SLOTS] }
+    }
+
+    fn slot(week_end: i64) -> usize {
+        (week_end / WEEK) as usize % SLOPE_CHANGE_SLOTS
+    }
+
+    /// Decays the supply up to `now`, applying the slope changes of every week end passed
+    pub fn checkpoint(&mut self, now: i64) {
+        let mut t = self.last_checkpoint;
+
+        while t < now && self.slope > 0 {
+            let week_end = (t / WEEK + 1) * WEEK;
+            let to = week_end.min(now);
+
+            self.bias -= self.slope * (to - t) as u128;
+
+            if to == week_end {
+                let slot = Self::slot(week_end);
+                self.slope -= self.slope_changes[slot];
+                self.slope_changes[slot] = 0;
+            }
+
+            t = to;
+        }
+
+        self.last_checkpoint = self.last_checkpoint.max(now);
+    }
+
+    /// Replaces `old` with `new` in the supply, must only be called right after `checkpoint(now)`
+    pub fn update(&mut self, old: &VePoint, new: &VePoint, now: i64) {
+        if old.end > now {
+            self.bias -= old.scaled_power_at(now);
+            self.slope -= old.slope();
+            self.slope_changes[Self::slot(old.end)] -= old.slope();
+        }
+
+        if new.end > now {
+            self.bias += new.scaled_power_at(now);
+            self.slope += new.slope();
+            self.slope_changes[Self::slot(new.end)] += new.slope();
+        }
+    }
+
+    /// The total voting power at the last checkpoint
+    pub fn total_power(&self) -> u64 {
+        (self.bias / SLOPE_PRECISION) as u64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    const NOW: i64 = 1_000 * WEEK + 3_600;
+
+    #[test_case(1, 1_001 * WEEK; "one week")]
+    #[test_case(208, 1_208 * WEEK; "max")]
+    fn test_lock_end(weeks: i64, expected: i64) {
+        assert_eq!(lock_end(NOW, weeks).unwrap(), expected);
+    }
+
+    #[test_case(0; "zero")]
+    #[test_case(209; "too long")]
+    fn test_invalid_lock_end(weeks: i64) {
+        assert!(lock_end(NOW, weeks).is_err());
+    }
+
+    #[test]
+    fn test_power_decays_linearly() {
+        let amount = MAX_LOCK as u64;
+        let point = VePoint { amount, end: MAX_LOCK };
+
+        assert_eq!(point.power_at(0), amount);
+        assert_eq!(point.power_at(MAX_LOCK / 2), amount / 2);
+        assert_eq!(point.power_at(MAX_LOCK), 0);
+        assert_eq!(point.power_at(MAX_LOCK + 1), 0);
+    }
+
+    #[test]
+    fn test_lock_history() {
+        let mut history = LockHistory::default();
+        let first = VePoint { amount: 100, end: 10 * WEEK };
+        let second = VePoint { amount: 300, end: 10 * WEEK };
+
+        history.push(first, 100);
+        history.push(second, 200);
+
+        assert_eq!(history.point_at(100).unwrap(), VePoint::default());
+        assert_eq!(history.point_at(101).unwrap(), first);
+        assert_eq!(history.point_at(200).unwrap(), first);
+        assert_eq!(history.point_at(201).unwrap(), second);
+    }
+
+    #[test]
+    fn test_lock_history_replaces_same_timestamp() {
+        let mut history = LockHistory::default();
+        let point = VePoint { amount: 300, end: 10 * WEEK };
+
+        history.push(VePoint { amount: 100, end: 10 * WEEK }, 100);
+        history.push(point, 100);
+
+        assert_eq!(history.count, 1);
+        assert_eq!(history.point_at(101).unwrap(), point);
+    }
+
+    #[test]
+    fn test_lock_history_exhausted() {
+        let mut history = LockHistory::default();
+
+        for n in 0..=LOCK_HISTORY_LEN as i64 {
+            history.push(VePoint { amount: n as u64, end: 10 * WEEK }, 100 + n);
+        }
+
+        // The point set at 100 was overwritten, the one from 101 is still known
+        assert!(history.point_at(101).is_err());
+        assert_eq!(history.point_at(102).unwrap().amount, 1);
+    }
+
+    #[test]
+    fn test_supply_tracks_locks() {
+        let mut curve = SupplyCurve::init(NOW);
+        let short = VePoint { amount: 208_000, end: lock_end(NOW, 4).unwrap() };
+        let long = VePoint { amount: 104_000, end: lock_end(NOW, 10).unwrap() };
+
+        curve.update(&VePoint::default(), &short, NOW);
+        curve.update(&VePoint::default(), &long, NOW);
+
+        for t in [NOW + WEEK, short.end, short.end + 1, long.end - 1, long.end, long.end + 20 * WEEK] {
+            curve.checkpoint(t);
+
+            let expected = (short.scaled_power_at(t) + long.scaled_power_at(t)) / SLOPE_PRECISION;
+            assert_eq!(curve.total_power() as u128, expected);
+        }
+
+        assert_eq!(curve.slope, 0);
+        assert!(curve.slope_changes.iter().all(|change| *change == 0));
+    }
+
+    #[test]
+    fn test_supply_extend_and_withdraw() {
+        let mut curve = SupplyCurve::init(NOW);
+        let lock = VePoint { amount: 1_000_000, end: lock_end(NOW, 2).unwrap() };
+        curve.update(&VePoint::default(), &lock, NOW);
+
+        let later = NOW + WEEK;
+        curve.checkpoint(later);
+        let extended = VePoint { amount: 3_000_000, end: lock_end(later, 52).unwrap() };
+        curve.update(&lock, &extended, later);
+
+        assert_eq!(curve.total_power(), extended.power_at(later));
+
+        curve.checkpoint(extended.end);
+        curve.update(&extended, &VePoint::default(), extended.end);
+
+        assert_eq!((curve.bias, curve.slope), (0, 0));
+    }
+}
\ No newline at end of file
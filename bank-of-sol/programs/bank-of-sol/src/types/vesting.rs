@@ -0,0 +1,109 @@
+use anchor_lang::prelude::{*, borsh::{BorshSerialize, BorshDeserialize}};
+use muldiv::*;
+use crate::errors::ErrorCode;
+
+/// Linear vesting from `start` over `duration` seconds, nothing can be claimed until `cliff` seconds after `start`
+#[derive(Debug, Default, Clone, Copy, BorshSerialize, BorshDeserialize)]
+pub struct VestingTerms {
+    /// The timestamp vesting starts at (8)
+    pub start: i64,
+    /// The number of seconds after `start` before anything vests (8)
+    pub cliff: i64,
+    /// The number of seconds after `start` until everything is vested (8)
+    pub duration: i64,
+}
+
+impl VestingTerms {
+    pub fn init(start: i64, cliff: i64, duration: i64) -> Result<Self> {
+        require_gt!(duration, 0, ErrorCode::InvalidVestingTerms);
+        require!(cliff >= 0 && cliff <= duration, ErrorCode::InvalidVestingTerms);
+
+        Ok(Self { start, cliff, duration })
+    }
+
+    /// The amount of `total` vested at `now`
+    pub fn vested(&self, total: u64, now: i64) -> u64 {
+        let elapsed = now.saturating_sub(self.start);
+
+        if elapsed < self.cliff {
+            0
+        } else if elapsed >= self.duration {
+            total
+        } else {
+            total.mul_div_floor(elapsed as u64, self.duration as u64).unwrap()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::vesting_schedule::VestingSchedule;
+    use crate::types::treasury::FundKind;
+    use test_case::test_case;
+
+    fn terms() -> VestingTerms {
+        VestingTerms::init(1_000, 100, 400).unwrap()
+    }
+
+    #[test_case(0, 0; "before start")]
+    #[test_case(1_099, 0; "before cliff")]
+    #[test_case(1_100, 250; "at cliff")]
+    #[test_case(1_200, 500; "half vested")]
+    #[test_case(1_400, 1_000; "fully vested")]
+    #[test_case(i64::MAX, 1_000; "long after")]
+    fn test_vested(now: i64, expected: u64) {
+        assert_eq!(terms().vested(1_000, now), expected);
+    }
+
+    #[test_case(0, 0, 0; "no duration")]
+    #[test_case(0, -1, 10; "negative cliff")]
+    #[test_case(0, 11, 10; "cliff after end")]
+    fn test_invalid_terms(start: i64, cliff: i64, duration: i64) {
+        assert!(VestingTerms::init(start, cliff, duration).is_err());
+    }
+
+    fn schedule(claimed: u64) -> VestingSchedule {
+        VestingSchedule {
+            seed: Pubkey::default(),
+            beneficiary: Pubkey::default(),
+            mint: Pubkey::default(),
+            vault: Pubkey::default(),
+            fund: FundKind::Dao,
+            total: 1_000,
+            claimed,
+            terms: terms(),
+            revoked: false,
+            bump: 0,
+        }
+    }
+
+    #[test_case(0, 1_200, 500; "nothing claimed")]
+    #[test_case(300, 1_200, 200; "partly claimed")]
+    #[test_case(500, 1_200, 0; "all vested claimed")]
+    #[test_case(500, 1_400, 500; "rest after end")]
+    fn test_claimable(claimed: u64, now: i64, expected: u64) {
+        assert_eq!(schedule(claimed).claimable(now), expected);
+    }
+
+    #[test_case(1_050, 1_000, 0; "before cliff")]
+    #[test_case(1_200, 500, 500; "half vested")]
+    #[test_case(1_500, 0, 1_000; "fully vested")]
+    fn test_revoke(now: i64, unvested: u64, vested: u64) {
+        let mut schedule = schedule(0);
+
+        assert_eq!(schedule.revoke(now), unvested);
+        assert!(schedule.revoked);
+        assert_eq!(schedule.total, vested);
+    }
+
+    #[test]
+    fn test_revoked_schedule_stops_vesting() {
+        let mut schedule = schedule(100);
+        schedule.revoke(1_200);
+
+        // What vested before revoking stays claimable, nothing more vests afterwards
+        assert_eq!(schedule.claimable(1_200), 400);
+        assert_eq!(schedule.claimable(i64::MAX), 400);
+    }
+}
\ No newline at end of file
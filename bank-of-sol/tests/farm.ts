@@ -0,0 +1,235 @@
+import { BN } from "@coral-xyz/anchor";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { getAssociatedTokenAddressSync } from "@solana/spl-token";
+import { expect } from "chai";
+
+import { Bank, DECIMALS, fails, pda, units } from "./utils/bank";
+
+// One Unit per second
+const RATE = units(1);
+
+describe("farm", () => {
+  let bank: Bank;
+  let lpMint: Keypair;
+  let farm: PublicKey;
+  let stakeVault: PublicKey;
+  let rewardVault: PublicKey;
+  let creatorUnits: PublicKey;
+
+  class Staker {
+    readonly keypair: Keypair;
+    readonly position: PublicKey;
+    lp: PublicKey;
+    rewards: PublicKey;
+
+    constructor() {
+      this.keypair = bank.user();
+      this.position = pda(
+        Buffer.from("farm-position"),
+        farm.toBuffer(),
+        this.keypair.publicKey.toBuffer()
+      );
+    }
+
+    async setup(lpAmount: BN) {
+      this.lp = await bank.fund(lpMint.publicKey, this.keypair.publicKey, lpAmount);
+      this.rewards = await bank.fund(bank.unitMint.publicKey, this.keypair.publicKey);
+
+      await bank.program.methods
+        .createFarmPosition()
+        .accounts({ farmPosition: this.position, farm, signer: this.keypair.publicKey })
+        .signers([this.keypair])
+        .rpc();
+    }
+
+    deposit(amount: BN) {
+      return bank.program.methods
+        .farmDeposit(amount)
+        .accounts({
+          singleton: bank.singleton,
+          farm,
+          farmPosition: this.position,
+          stakeVault,
+          payerAccount: this.lp,
+          tokenAuthority: bank.tokenAuthority,
+          signer: this.keypair.publicKey,
+        })
+        .signers([this.keypair])
+        .rpc();
+    }
+
+    withdraw(amount: BN) {
+      return bank.program.methods
+        .farmWithdraw(amount)
+        .accounts({
+          singleton: bank.singleton,
+          farm,
+          farmPosition: this.position,
+          stakeVault,
+          receiverAccount: this.lp,
+          tokenAuthority: bank.tokenAuthority,
+          signer: this.keypair.publicKey,
+        })
+        .signers([this.keypair])
+        .rpc();
+    }
+
+    emergencyWithdraw() {
+      return bank.program.methods
+        .farmEmergencyWithdraw()
+        .accounts({
+          singleton: bank.singleton,
+          farm,
+          farmPosition: this.position,
+          stakeVault,
+          receiverAccount: this.lp,
+          tokenAuthority: bank.tokenAuthority,
+          signer: this.keypair.publicKey,
+        })
+        .signers([this.keypair])
+        .rpc();
+    }
+
+    /** Harvests and returns the Units received */
+    async harvest(): Promise<bigint> {
+      const before = await bank.tokenBalance(this.rewards);
+
+      await bank.program.methods
+        .farmHarvest()
+        .accounts({
+          singleton: bank.singleton,
+          farm,
+          farmPosition: this.position,
+          rewardVault,
+          receiverAccount: this.rewards,
+          tokenAuthority: bank.tokenAuthority,
+          signer: this.keypair.publicKey,
+        })
+        .signers([this.keypair])
+        .rpc();
+
+      return (await bank.tokenBalance(this.rewards)) - before;
+    }
+
+    async pending(): Promise<string> {
+      return (await bank.program.account.farmPosition.fetch(this.position)).pendingRewards.toString();
+    }
+  }
+
+  function createFarm(stakeMint: PublicKey, authority: Keypair | null = null) {
+    const farm = pda(Buffer.from("farm"), stakeMint.toBuffer());
+    const signer = authority ? authority.publicKey : bank.creator;
+
+    return bank.program.methods
+      .createFarm(RATE)
+      .accounts({
+        singleton: bank.singleton,
+        farm,
+        stakeMint,
+        rewardMint: bank.unitMint.publicKey,
+        stakeVault: pda(Buffer.from("farm-stake"), farm.toBuffer()),
+        rewardVault: pda(Buffer.from("farm-rewards"), farm.toBuffer()),
+        tokenAuthority: bank.tokenAuthority,
+        roleMember: bank.roleMember(signer),
+        authority: signer,
+      })
+      .signers(authority ? [authority] : [])
+      .rpc();
+  }
+
+  function fundFarm(amount: BN) {
+    return bank.program.methods
+      .fundFarm(amount)
+      .accounts({ farm, rewardVault, payerAccount: creatorUnits, signer: bank.creator })
+      .rpc();
+  }
+
+  before(async () => {
+    bank = await Bank.start(1);
+    // The creator holds the Units the pool's position didn't take
+    creatorUnits = getAssociatedTokenAddressSync(bank.unitMint.publicKey, bank.creator);
+
+    await bank.grantRole(bank.creator, { admin: {} });
+
+    // Stands in for the LP token of a constant product Unit/USDC pool
+    lpMint = Keypair.generate();
+    await bank.createMint(lpMint, DECIMALS);
+
+    farm = pda(Buffer.from("farm"), lpMint.publicKey.toBuffer());
+    stakeVault = pda(Buffer.from("farm-stake"), farm.toBuffer());
+    rewardVault = pda(Buffer.from("farm-rewards"), farm.toBuffer());
+  });
+
+  it("Rejects farms from non-admins", async () => {
+    const stranger = bank.user();
+
+    expect(await fails(createFarm(lpMint.publicKey, stranger))).to.be.true;
+  });
+
+  it("Rejects farms staking non-fungible tokens", async () => {
+    const nftMint = Keypair.generate();
+    await bank.createMint(nftMint, 0);
+
+    expect(await fails(createFarm(nftMint.publicKey))).to.be.true;
+  });
+
+  let alice: Staker;
+  let bob: Staker;
+
+  it("Pays stakers pro rata to their stake", async () => {
+    await createFarm(lpMint.publicKey);
+
+    alice = new Staker();
+    bob = new Staker();
+    await alice.setup(units(100));
+    await bob.setup(units(300));
+
+    // Alice is alone for 10 seconds, then shares the next 10 with Bob's three times larger stake
+    await alice.deposit(units(100));
+    await bank.warp(10);
+    await bob.deposit(units(300));
+    await bank.warp(10);
+
+    await fundFarm(units(20));
+
+    expect((await alice.harvest()).toString()).to.equal(units(10).add(units(10).divn(4)).toString());
+    expect((await bob.harvest()).toString()).to.equal(units(10).muln(3).divn(4).toString());
+    expect(await bank.tokenBalance(rewardVault)).to.equal(0n);
+  });
+
+  it("Harvests up to the reward vault's balance and keeps the rest pending", async () => {
+    await bank.warp(10);
+
+    // The vault is empty, so Bob's 7.5 Units stay pending
+    expect(await bob.harvest()).to.equal(0n);
+    expect(await bob.pending()).to.equal(units(30).divn(4).toString());
+
+    await fundFarm(units(5));
+    await bank.warp(1);
+
+    // Bob earned another 0.75 Units in the last second
+    expect((await bob.harvest()).toString()).to.equal(units(5).toString());
+    expect(await bob.pending()).to.equal(units(33).divn(4).sub(units(5)).toString());
+  });
+
+  it("Returns the stake on withdrawal and keeps rewards pending", async () => {
+    await alice.withdraw(units(100));
+
+    const position = await bank.program.account.farmPosition.fetch(alice.position);
+
+    expect(await bank.tokenBalance(alice.lp)).to.equal(BigInt(units(100).toString()));
+    expect(position.amount.toString()).to.equal("0");
+    expect(position.pendingRewards.toString()).to.equal(units(11).divn(4).toString());
+    expect(await fails(alice.withdraw(units(1)))).to.be.true;
+  });
+
+  it("Forfeits pending rewards on emergency withdrawal", async () => {
+    await bob.emergencyWithdraw();
+
+    const state = await bank.program.account.farm.fetch(farm);
+
+    expect(await bank.tokenBalance(bob.lp)).to.equal(BigInt(units(300).toString()));
+    expect(await bob.pending()).to.equal("0");
+    expect(state.rewards.totalStaked.toString()).to.equal("0");
+  });
+});
@@ -0,0 +1,102 @@
+import { Keypair } from "@solana/web3.js";
+import { expect } from "chai";
+
+import { Bank, fails } from "./utils/bank";
+
+// Mirrors `MIN_OBSERVATION_SPACING`
+const MIN_OBSERVATION_SPACING = 5 * 60;
+
+describe("roles", () => {
+  let bank: Bank;
+  let keeper: Keypair;
+
+  function recordPriceObservation(signer: Keypair) {
+    return bank.program.methods
+      .recordPriceObservation()
+      .accounts({
+        singleton: bank.singleton,
+        raydiumPool: bank.pool.poolState,
+        roleMember: bank.roleMember(signer.publicKey),
+        authority: signer.publicKey,
+      })
+      .signers([signer])
+      .rpc();
+  }
+
+  function revokeRole(member: Keypair, role: any) {
+    return bank.program.methods
+      .revokeRole(role)
+      .accounts({
+        singleton: bank.singleton,
+        roleMember: bank.roleMember(member.publicKey),
+        authority: bank.creator,
+      })
+      .rpc();
+  }
+
+  before(async () => {
+    bank = await Bank.start(1);
+    keeper = bank.user();
+  });
+
+  it("Only lets the authority grant roles", async () => {
+    const stranger = bank.user();
+
+    const grant = bank.program.methods
+      .grantRole({ oracleKeeper: {} })
+      .accounts({
+        singleton: bank.singleton,
+        roleMember: bank.roleMember(stranger.publicKey),
+        member: stranger.publicKey,
+        authority: stranger.publicKey,
+      })
+      .signers([stranger])
+      .rpc();
+
+    expect(await fails(grant)).to.be.true;
+  });
+
+  it("Rejects signers without the required role", async () => {
+    await bank.warp(MIN_OBSERVATION_SPACING);
+
+    // Without a role member account, then holding another role
+    expect(await fails(recordPriceObservation(keeper))).to.be.true;
+
+    await bank.grantRole(keeper.publicKey, { pauser: {} });
+
+    expect(await fails(recordPriceObservation(keeper))).to.be.true;
+  });
+
+  it("Accepts holders of the required role", async () => {
+    await bank.grantRole(keeper.publicKey, { oracleKeeper: {} });
+    await recordPriceObservation(keeper);
+
+    const singleton = await bank.program.account.singleton.fetch(bank.singleton);
+    const clock = await bank.context.banksClient.getClock();
+
+    expect(singleton.priceObservations.observations[4].unixTimestamp.toString()).to.equal(
+      clock.unixTimestamp.toString()
+    );
+  });
+
+  it("Spaces out observations", async () => {
+    await bank.warp(MIN_OBSERVATION_SPACING - 1);
+
+    expect(await fails(recordPriceObservation(keeper))).to.be.true;
+
+    await bank.warp(1);
+    await recordPriceObservation(keeper);
+  });
+
+  it("Rejects holders once the role is revoked", async () => {
+    await revokeRole(keeper, { oracleKeeper: {} });
+    await bank.warp(MIN_OBSERVATION_SPACING);
+
+    expect(await fails(recordPriceObservation(keeper))).to.be.true;
+
+    const member = await bank.program.account.roleMember.fetch(bank.roleMember(keeper.publicKey));
+
+    // Revoking one role keeps the others
+    expect(member.roles.bits).to.not.equal(0);
+  });
+});
@@ -0,0 +1,255 @@
+import * as anchor from "@coral-xyz/anchor";
+import { BN, Program } from "@coral-xyz/anchor";
+import {
+  Keypair,
+  PublicKey,
+  SystemProgram,
+  Transaction,
+  TransactionInstruction,
+} from "@solana/web3.js";
+import {
+  AccountLayout,
+  MINT_SIZE,
+  MintLayout,
+  TOKEN_PROGRAM_ID,
+  createAssociatedTokenAccountInstruction,
+  createInitializeMintInstruction,
+  createMintToInstruction,
+  getAssociatedTokenAddressSync,
+} from "@solana/spl-token";
+import { Clock, startAnchor, ProgramTestContext } from "solana-bankrun";
+import { BankrunProvider } from "anchor-bankrun";
+
+import { BankOfSol, IDL } from "../../target/types/bank_of_sol";
+import {
+  ClmmPool,
+  RAYDIUM_CLMM_PROGRAM_ID,
+  TICK_ARRAY_SIZE,
+  TICK_SPACING,
+  ammConfigData,
+  priceToTick,
+  tickArrayStartIndex,
+} from "./clmm";
+
+export const PROGRAM_ID = new PublicKey("5Xi2uL4MB1RDnDPFHEiec6beWxFqyzFhgYpd2ACjr17m");
+export const DECIMALS = 6;
+
+export function pda(...seeds: Buffer[]): PublicKey {
+  return PublicKey.findProgramAddressSync(seeds, PROGRAM_ID)[0];
+}
+
+export function units(amount: number): BN {
+  return new BN(amount).mul(new BN(10 ** DECIMALS));
+}
+
+/**
+ * A bank initialized on a local Raydium CLMM pool of Units and USDC, the creator is left holding the
+ * Units and USDC the pool's position didn't take
+ */
+export class Bank {
+  readonly ammConfig = Keypair.generate().publicKey;
+  readonly unitMint = Keypair.generate();
+  readonly usdcMint = Keypair.generate();
+  readonly collection = Keypair.generate();
+  readonly singleton = pda(Buffer.from("singleton"));
+  readonly tokenAuthority = pda(Buffer.from("token-authority"));
+
+  context: ProgramTestContext;
+  provider: BankrunProvider;
+  program: Program<BankOfSol>;
+  creator: PublicKey;
+  pool: ClmmPool;
+  tickArray: PublicKey;
+
+  get unitCustody(): PublicKey {
+    return getAssociatedTokenAddressSync(this.unitMint.publicKey, this.tokenAuthority, true);
+  }
+
+  /** Starts a bank with Units priced at `unitPrice` USDC */
+  static async start(unitPrice: number): Promise<Bank> {
+    const bank = new Bank();
+    await bank.setup(unitPrice);
+    return bank;
+  }
+
+  private async setup(unitPrice: number) {
+    this.context = await startAnchor(
+      "",
+      [{ name: "raydium_amm_v3", programId: RAYDIUM_CLMM_PROGRAM_ID }],
+      []
+    );
+    this.provider = new BankrunProvider(this.context);
+    anchor.setProvider(this.provider);
+    this.program = new Program<BankOfSol>(IDL, PROGRAM_ID, this.provider);
+    this.creator = this.provider.wallet.publicKey;
+
+    this.context.setAccount(this.ammConfig, {
+      lamports: 1_000_000_000,
+      data: ammConfigData(this.creator),
+      owner: RAYDIUM_CLMM_PROGRAM_ID,
+      executable: false,
+    });
+
+    await this.createMint(this.unitMint, DECIMALS);
+    await this.createMint(this.usdcMint, DECIMALS);
+    await this.createMint(this.collection, 0);
+
+    const liquidity = units(1_000_000);
+    await this.fund(this.unitMint.publicKey, this.creator, liquidity);
+    await this.fund(this.usdcMint.publicKey, this.creator, liquidity);
+
+    // Raydium prices token 1 in token 0, the position covers the current price and lies in one tick array
+    this.pool = ClmmPool.forMints(this.ammConfig, this.unitMint.publicKey, this.usdcMint.publicKey);
+    const unitIsToken0 = this.pool.mint0.equals(this.unitMint.publicKey);
+    const price = unitIsToken0 ? unitPrice : 1 / unitPrice;
+    const startIndex = tickArrayStartIndex(priceToTick(price));
+    this.tickArray = this.pool.tickArray(startIndex);
+
+    await this.send([this.pool.createPoolIx(this.creator, price)]);
+
+    const positionMint = Keypair.generate();
+    await this.send(
+      [
+        this.pool.openPositionIx(
+          this.creator,
+          positionMint.publicKey,
+          startIndex + TICK_SPACING,
+          startIndex + TICK_SPACING * (TICK_ARRAY_SIZE - 1),
+          unitIsToken0,
+          units(100_000),
+          liquidity
+        ),
+      ],
+      [positionMint]
+    );
+
+    const shareMint = pda(Buffer.from("share-mint"));
+
+    await this.program.methods
+      .initialize(new BN(0), units(1_000_000))
+      .accounts({
+        singleton: this.singleton,
+        collection: this.collection.publicKey,
+        raydiumPool: this.pool.poolState,
+        unitMint: this.unitMint.publicKey,
+        tokenAuthority: this.tokenAuthority,
+        unitCustody: this.unitCustody,
+        shareMint,
+        shareCustody: getAssociatedTokenAddressSync(shareMint, this.tokenAuthority, true),
+        shareRewardVault: pda(Buffer.from("share-rewards")),
+        treasury: pda(Buffer.from("treasury")),
+        daoFundVault: pda(Buffer.from("dao-fund")),
+        devFundVault: pda(Buffer.from("dev-fund")),
+        creator: this.creator,
+        nftAuthority: this.creator,
+      })
+      .rpc();
+  }
+
+  async send(ixs: TransactionInstruction[], signers: Keypair[] = []) {
+    await this.provider.sendAndConfirm(new Transaction().add(...ixs), signers);
+  }
+
+  /** A new keypair holding enough SOL to pay for its accounts */
+  user(): Keypair {
+    const user = Keypair.generate();
+
+    this.context.setAccount(user.publicKey, {
+      lamports: 10_000_000_000,
+      data: Buffer.alloc(0),
+      owner: SystemProgram.programId,
+      executable: false,
+    });
+
+    return user;
+  }
+
+  roleMember(member: PublicKey): PublicKey {
+    return pda(Buffer.from("role-member"), member.toBuffer());
+  }
+
+  async grantRole(member: PublicKey, role: any) {
+    await this.program.methods
+      .grantRole(role)
+      .accounts({
+        singleton: this.singleton,
+        roleMember: this.roleMember(member),
+        member,
+        authority: this.creator,
+      })
+      .rpc();
+  }
+
+  async createMint(mint: Keypair, decimals: number) {
+    const rent = await this.context.banksClient.getRent();
+
+    await this.send(
+      [
+        SystemProgram.createAccount({
+          fromPubkey: this.creator,
+          newAccountPubkey: mint.publicKey,
+          lamports: Number(rent.minimumBalance(BigInt(MINT_SIZE))),
+          space: MINT_SIZE,
+          programId: TOKEN_PROGRAM_ID,
+        }),
+        createInitializeMintInstruction(mint.publicKey, decimals, this.creator, null),
+      ],
+      [mint]
+    );
+  }
+
+  /** Creates the owner's associated token account, minting `amount` into it when the creator can */
+  async fund(mint: PublicKey, owner: PublicKey, amount = new BN(0)): Promise<PublicKey> {
+    const account = getAssociatedTokenAddressSync(mint, owner);
+    const ixs = [createAssociatedTokenAccountInstruction(this.creator, account, owner, mint)];
+
+    if (!amount.isZero()) {
+      ixs.push(createMintToInstruction(mint, account, this.creator, BigInt(amount.toString())));
+    }
+
+    await this.send(ixs);
+
+    return account;
+  }
+
+  async tokenBalance(account: PublicKey): Promise<bigint> {
+    const info = await this.context.banksClient.getAccount(account);
+
+    return AccountLayout.decode(info.data).amount;
+  }
+
+  async mintSupply(mint: PublicKey): Promise<bigint> {
+    const info = await this.context.banksClient.getAccount(mint);
+
+    return MintLayout.decode(info.data).supply;
+  }
+
+  /** Moves to the next slot, with a new blockhash, and the clock forward by `seconds` */
+  async warp(seconds: number) {
+    const { slot } = await this.context.banksClient.getClock();
+    this.context.warpToSlot(slot + 1n);
+
+    const clock = await this.context.banksClient.getClock();
+
+    this.context.setClock(
+      new Clock(
+        clock.slot,
+        clock.epochStartTimestamp,
+        clock.epoch,
+        clock.leaderScheduleEpoch,
+        clock.unixTimestamp + BigInt(seconds)
+      )
+    );
+  }
+}
+
+/** Whether `promise` rejects */
+export async function fails(promise: Promise<unknown>): Promise<boolean> {
+  try {
+    await promise;
+  } catch {
+    return true;
+  }
+
+  return false;
+}
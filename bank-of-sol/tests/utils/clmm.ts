@@ -0,0 +1,236 @@
+import { BN } from "@coral-xyz/anchor";
+import {
+  PublicKey,
+  SystemProgram,
+  SYSVAR_RENT_PUBKEY,
+  TransactionInstruction,
+} from "@solana/web3.js";
+import {
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  TOKEN_2022_PROGRAM_ID,
+  TOKEN_PROGRAM_ID,
+  getAssociatedTokenAddressSync,
+} from "@solana/spl-token";
+import { createHash } from "crypto";
+
+// Loaded from tests/fixtures, run `yarn fixtures` to dump it from mainnet
+export const RAYDIUM_CLMM_PROGRAM_ID = new PublicKey(
+  "CAMMCzo5YL8w4VFF8KVHrK22GGUsp5VTaW7grrKgrWqK"
+);
+
+export const TICK_SPACING = 10;
+export const TICK_ARRAY_SIZE = 60;
+
+const AMM_CONFIG_LEN = 117;
+
+function discriminator(preimage: string): Buffer {
+  return createHash("sha256").update(preimage).digest().subarray(0, 8);
+}
+
+function i32(value: number, endianness: "be" | "le" = "be"): Buffer {
+  const buffer = Buffer.alloc(4);
+  endianness === "be" ? buffer.writeInt32BE(value) : buffer.writeInt32LE(value);
+  return buffer;
+}
+
+function pda(seeds: Buffer[]): PublicKey {
+  return PublicKey.findProgramAddressSync(seeds, RAYDIUM_CLMM_PROGRAM_ID)[0];
+}
+
+/**
+ * Raydium only lets its admin create configs, so tests inject one with this data instead
+ */
+export function ammConfigData(owner: PublicKey): Buffer {
+  const data = Buffer.alloc(AMM_CONFIG_LEN);
+  let offset = discriminator("account:AmmConfig").copy(data, 0);
+
+  offset = data.writeUInt8(255, offset); // bump
+  offset = data.writeUInt16LE(0, offset); // index
+  offset += owner.toBuffer().copy(data, offset);
+  offset = data.writeUInt32LE(120_000, offset); // protocol_fee_rate
+  offset = data.writeUInt32LE(2_500, offset); // trade_fee_rate
+  offset = data.writeUInt16LE(TICK_SPACING, offset);
+  offset = data.writeUInt32LE(40_000, offset); // fund_fee_rate
+  offset += 4; // padding_u32
+  owner.toBuffer().copy(data, offset); // fund_owner
+
+  return data;
+}
+
+export function sqrtPriceX64(price: number): BN {
+  return new BN(BigInt(Math.floor(Math.sqrt(price) * 2 ** 64)).toString());
+}
+
+export function priceToTick(price: number): number {
+  return Math.floor(Math.log(price) / Math.log(1.0001));
+}
+
+export function tickArrayStartIndex(tick: number): number {
+  const span = TICK_SPACING * TICK_ARRAY_SIZE;
+
+  return Math.floor(tick / span) * span;
+}
+
+export class ClmmPool {
+  readonly poolState: PublicKey;
+  readonly vault0: PublicKey;
+  readonly vault1: PublicKey;
+  readonly observationState: PublicKey;
+  readonly tickArrayBitmap: PublicKey;
+
+  constructor(
+    readonly ammConfig: PublicKey,
+    readonly mint0: PublicKey,
+    readonly mint1: PublicKey
+  ) {
+    this.poolState = pda([
+      Buffer.from("pool"),
+      ammConfig.toBuffer(),
+      mint0.toBuffer(),
+      mint1.toBuffer(),
+    ]);
+    this.vault0 = pda([
+      Buffer.from("pool_vault"),
+      this.poolState.toBuffer(),
+      mint0.toBuffer(),
+    ]);
+    this.vault1 = pda([
+      Buffer.from("pool_vault"),
+      this.poolState.toBuffer(),
+      mint1.toBuffer(),
+    ]);
+    this.observationState = pda([
+      Buffer.from("observation"),
+      this.poolState.toBuffer(),
+    ]);
+    this.tickArrayBitmap = pda([
+      Buffer.from("pool_tick_array_bitmap_extension"),
+      this.poolState.toBuffer(),
+    ]);
+  }
+
+  /** The pool for two mints, which Raydium orders by key */
+  static forMints(ammConfig: PublicKey, a: PublicKey, b: PublicKey): ClmmPool {
+    return Buffer.compare(a.toBuffer(), b.toBuffer()) < 0
+      ? new ClmmPool(ammConfig, a, b)
+      : new ClmmPool(ammConfig, b, a);
+  }
+
+  tickArray(startIndex: number): PublicKey {
+    return pda([
+      Buffer.from("tick_array"),
+      this.poolState.toBuffer(),
+      i32(startIndex),
+    ]);
+  }
+
+  createPoolIx(creator: PublicKey, price: number): TransactionInstruction {
+    const data = Buffer.concat([
+      discriminator("global:create_pool"),
+      sqrtPriceX64(price).toArrayLike(Buffer, "le", 16),
+      new BN(0).toArrayLike(Buffer, "le", 8), // open_time
+    ]);
+
+    return new TransactionInstruction({
+      programId: RAYDIUM_CLMM_PROGRAM_ID,
+      keys: [
+        { pubkey: creator, isSigner: true, isWritable: true },
+        { pubkey: this.ammConfig, isSigner: false, isWritable: false },
+        { pubkey: this.poolState, isSigner: false, isWritable: true },
+        { pubkey: this.mint0, isSigner: false, isWritable: false },
+        { pubkey: this.mint1, isSigner: false, isWritable: false },
+        { pubkey: this.vault0, isSigner: false, isWritable: true },
+        { pubkey: this.vault1, isSigner: false, isWritable: true },
+        { pubkey: this.observationState, isSigner: false, isWritable: true },
+        { pubkey: this.tickArrayBitmap, isSigner: false, isWritable: true },
+        { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+        { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+        { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+        { pubkey: SYSVAR_RENT_PUBKEY, isSigner: false, isWritable: false },
+      ],
+      data,
+    });
+  }
+
+  /**
+   * Opens a position on `[tickLower, tickUpper)` within a single tick array, with `baseAmount` of
+   * token 0 if `base0` and of token 1 otherwise
+   */
+  openPositionIx(
+    owner: PublicKey,
+    positionMint: PublicKey,
+    tickLower: number,
+    tickUpper: number,
+    base0: boolean,
+    baseAmount: BN,
+    otherMax: BN
+  ): TransactionInstruction {
+    const startIndex = tickArrayStartIndex(tickLower);
+    const tickArray = this.tickArray(startIndex);
+    const [amount0Max, amount1Max] = base0
+      ? [baseAmount, otherMax]
+      : [otherMax, baseAmount];
+
+    const data = Buffer.concat([
+      discriminator("global:open_position_with_token22_nft"),
+      ...[tickLower, tickUpper, startIndex, startIndex].map((tick) => i32(tick, "le")),
+      new BN(0).toArrayLike(Buffer, "le", 16), // liquidity, derived from the base amount
+      amount0Max.toArrayLike(Buffer, "le", 8),
+      amount1Max.toArrayLike(Buffer, "le", 8),
+      Buffer.from([0]), // with_metadata
+      Buffer.from([1, base0 ? 1 : 0]), // base_flag
+    ]);
+
+    return new TransactionInstruction({
+      programId: RAYDIUM_CLMM_PROGRAM_ID,
+      keys: [
+        { pubkey: owner, isSigner: true, isWritable: true },
+        { pubkey: owner, isSigner: false, isWritable: false },
+        { pubkey: positionMint, isSigner: true, isWritable: true },
+        {
+          pubkey: getAssociatedTokenAddressSync(positionMint, owner, false, TOKEN_2022_PROGRAM_ID),
+          isSigner: false,
+          isWritable: true,
+        },
+        { pubkey: this.poolState, isSigner: false, isWritable: true },
+        {
+          pubkey: pda([
+            Buffer.from("position"),
+            this.poolState.toBuffer(),
+            i32(tickLower),
+            i32(tickUpper),
+          ]),
+          isSigner: false,
+          isWritable: true,
+        },
+        { pubkey: tickArray, isSigner: false, isWritable: true },
+        { pubkey: tickArray, isSigner: false, isWritable: true },
+        {
+          pubkey: pda([Buffer.from("position"), positionMint.toBuffer()]),
+          isSigner: false,
+          isWritable: true,
+        },
+        {
+          pubkey: getAssociatedTokenAddressSync(this.mint0, owner),
+          isSigner: false,
+          isWritable: true,
+        },
+        {
+          pubkey: getAssociatedTokenAddressSync(this.mint1, owner),
+          isSigner: false,
+          isWritable: true,
+        },
+        { pubkey: this.vault0, isSigner: false, isWritable: true },
+        { pubkey: this.vault1, isSigner: false, isWritable: true },
+        { pubkey: SYSVAR_RENT_PUBKEY, isSigner: false, isWritable: false },
+        { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+        { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+        { pubkey: ASSOCIATED_TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+        { pubkey: TOKEN_2022_PROGRAM_ID, isSigner: false, isWritable: false },
+        { pubkey: this.mint0, isSigner: false, isWritable: false },
+        { pubkey: this.mint1, isSigner: false, isWritable: false },
+      ],
+      data,
+    });
+  }
+}
@@ -0,0 +1,137 @@
+import { BN } from "@coral-xyz/anchor";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { expect } from "chai";
+
+import { RAYDIUM_CLMM_PROGRAM_ID } from "./utils/clmm";
+import { Bank, fails, pda, units } from "./utils/bank";
+
+const ONE_HUNDRED_PCT = new BN(100_000_000);
+// Units trade below peg so that the protocol is selling bonds
+const UNIT_PRICE = 0.95;
+
+describe("zap-purchase-bonds", () => {
+  let bank: Bank;
+  let user: Keypair;
+  let bondAccountsStore: PublicKey;
+  let userUnits: PublicKey;
+  let userUsdc: PublicKey;
+
+  // Opening the bond market takes a full epoch below peg, so the allowance is written directly instead
+  async function setAvailableBonds(availableBonds: BN) {
+    const account = await bank.context.banksClient.getAccount(bank.singleton);
+    const state = await bank.program.account.singleton.fetch(bank.singleton);
+    const data = Buffer.from(account.data);
+
+    state.bonds.availableBonds = availableBonds;
+    (bank.program.coder.accounts as any).accountLayouts.get("Singleton").encode(state, data, 8);
+
+    bank.context.setAccount(bank.singleton, { ...account, data });
+  }
+
+  async function bondAccountAddress(): Promise<[PublicKey, BN]> {
+    const store = await bank.program.account.bondAccountsStore.fetch(bondAccountsStore);
+    const seedIndex = store.offset.add(store.numberOfAccounts);
+
+    return [
+      pda(
+        Buffer.from("bond-account"),
+        user.publicKey.toBuffer(),
+        seedIndex.toArrayLike(Buffer, "be", 8)
+      ),
+      seedIndex,
+    ];
+  }
+
+  function zap(seedIndex: BN, bondAccount: PublicKey, usdcAmount: BN, minimumUnitsOut: BN) {
+    const { pool, unitMint, usdcMint } = bank;
+
+    return bank.program.methods
+      .zapPurchaseBonds(seedIndex, usdcAmount, minimumUnitsOut, new BN(0))
+      .accounts({
+        bondAccount,
+        bondAccountsStore,
+        singleton: bank.singleton,
+        unitMint: unitMint.publicKey,
+        unitPayerAccount: userUnits,
+        unitCustodyAccount: bank.unitCustody,
+        usdcPayerAccount: userUsdc,
+        tokenAuthority: bank.tokenAuthority,
+        ammConfig: bank.ammConfig,
+        poolState: pool.poolState,
+        inputVault: pool.mint0.equals(usdcMint.publicKey) ? pool.vault0 : pool.vault1,
+        outputVault: pool.mint0.equals(unitMint.publicKey) ? pool.vault0 : pool.vault1,
+        observationState: pool.observationState,
+        tickArray: bank.tickArray,
+        signer: user.publicKey,
+        clmmProgram: RAYDIUM_CLMM_PROGRAM_ID,
+      })
+      .signers([user])
+      .rpc();
+  }
+
+  before(async () => {
+    bank = await Bank.start(UNIT_PRICE);
+    user = bank.user();
+    bondAccountsStore = pda(Buffer.from("bond-accounts-store"), user.publicKey.toBuffer());
+    userUnits = await bank.fund(bank.unitMint.publicKey, user.publicKey);
+    userUsdc = await bank.fund(bank.usdcMint.publicKey, user.publicKey, units(1_000));
+
+    await bank.program.methods
+      .createBondAccountsStore()
+      .accounts({ bondAccountsStore, signer: user.publicKey })
+      .signers([user])
+      .rpc();
+
+    const [bondAccount] = await bondAccountAddress();
+    await bank.program.methods
+      .createBondAccount()
+      .accounts({ bondAccount, bondAccountsStore, singleton: bank.singleton, signer: user.publicKey })
+      .signers([user])
+      .rpc();
+
+    await setAvailableBonds(units(1_000_000));
+  });
+
+  it("Swaps USDC for Units and purchases bonds with them", async () => {
+    const [bondAccount, seedIndex] = await bondAccountAddress();
+    const usdcAmount = units(100);
+    const minimumUnitsOut = units(100);
+
+    const usdcBefore = await bank.tokenBalance(userUsdc);
+    const unitsBefore = await bank.tokenBalance(userUnits);
+    const supplyBefore = await bank.mintSupply(bank.unitMint.publicKey);
+
+    await zap(seedIndex, bondAccount, usdcAmount, minimumUnitsOut);
+
+    const burned = supplyBefore - (await bank.mintSupply(bank.unitMint.publicKey));
+    const bonds = (await bank.program.account.bondAccount.fetch(bondAccount)).bonds;
+    const state = await bank.program.account.singleton.fetch(bank.singleton);
+
+    // All of the USDC is swapped and every Unit received is burned for bonds
+    expect((usdcBefore - (await bank.tokenBalance(userUsdc))).toString()).to.equal(usdcAmount.toString());
+    expect(await bank.tokenBalance(userUnits)).to.equal(unitsBefore);
+    expect(burned >= BigInt(minimumUnitsOut.toString())).to.be.true;
+
+    const expectedBonds = new BN(burned.toString())
+      .mul(ONE_HUNDRED_PCT.add(bonds.interestRate))
+      .div(ONE_HUNDRED_PCT);
+
+    expect(bonds.amount.toString()).to.equal(expectedBonds.toString());
+    expect(state.bonds.totalBondsPurchased.toString()).to.equal(expectedBonds.toString());
+  });
+
+  it("Rejects a zap receiving less than the minimum Units out", async () => {
+    const [bondAccount, seedIndex] = await bondAccountAddress();
+    const bondsBefore = (await bank.program.account.bondAccount.fetch(bondAccount)).bonds.amount;
+    const usdcBefore = await bank.tokenBalance(userUsdc);
+    const supplyBefore = await bank.mintSupply(bank.unitMint.publicKey);
+
+    // 100 USDC can't buy 200 Units below peg
+    expect(await fails(zap(seedIndex, bondAccount, units(100), units(200)))).to.be.true;
+    expect(await bank.tokenBalance(userUsdc)).to.equal(usdcBefore);
+    expect(await bank.mintSupply(bank.unitMint.publicKey)).to.equal(supplyBefore);
+    expect(
+      (await bank.program.account.bondAccount.fetch(bondAccount)).bonds.amount.toString()
+    ).to.equal(bondsBefore.toString());
+  });
+});